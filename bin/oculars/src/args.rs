@@ -1,4 +1,5 @@
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
 
 /// Utility for getting EVM instruction information.
 #[derive(Parser)]
@@ -18,4 +19,30 @@ pub enum Command {
         /// Name of the mnemonic.
         name: String,
     },
+    /// Export the control-flow graph of bytecode.
+    Cfg {
+        /// File containing the bytecode, hex encoded or raw.
+        input: PathBuf,
+        /// File to write the graph to.
+        #[arg(short, long)]
+        output: PathBuf,
+        /// Language of the written graph.
+        #[arg(short, long, value_enum, default_value_t = GraphFormat::Dot)]
+        format: GraphFormat,
+        /// Cluster blocks by the function they belong to.
+        #[arg(long)]
+        functions: bool,
+        /// Draw every edge in black.
+        #[arg(long)]
+        monochrome: bool,
+    },
+}
+
+/// Languages a control-flow graph can be exported to.
+#[derive(Clone, Copy, ValueEnum)]
+pub enum GraphFormat {
+    /// Graphviz DOT.
+    Dot,
+    /// Mermaid flowchart.
+    Mermaid,
 }
@@ -0,0 +1,46 @@
+use analysis::{
+    cfg::ControlFlowGraph,
+    export::{Dot, ExportOptions, GraphFormat as _, Mermaid, Palette},
+    functions::recover_functions,
+};
+use anyhow::Context as _;
+use dasm::disassembler::Disassembler;
+use std::{fs::File, path::Path};
+use upgrades::forks::prague::Prague;
+
+use crate::args::GraphFormat;
+
+/// Disassemble the bytecode in `input` and write its control-flow graph to `output`.
+pub fn export(
+    input: &Path,
+    output: &Path,
+    format: GraphFormat,
+    functions: bool,
+    monochrome: bool,
+) -> anyhow::Result<()> {
+    let file =
+        File::open(input).with_context(|| format!("failed to open \"{}\"", input.display()))?;
+    let assembly = Disassembler::<Prague>::new()
+        .disassemble_from_source(file)
+        .context("failed to disassemble bytecode")?;
+
+    let cfg = ControlFlowGraph::with_stack_analysis(&assembly);
+    let functions = functions.then(|| recover_functions(&cfg));
+    let options = ExportOptions {
+        functions: functions.as_deref(),
+        palette: if monochrome {
+            Palette::MONOCHROME
+        } else {
+            Palette::COLORED
+        },
+    };
+
+    let graph = match format {
+        GraphFormat::Dot => Dot.display(&cfg, &options).to_string(),
+        GraphFormat::Mermaid => Mermaid.display(&cfg, &options).to_string(),
+    };
+    std::fs::write(output, graph)
+        .with_context(|| format!("failed to write \"{}\"", output.display()))?;
+
+    Ok(())
+}
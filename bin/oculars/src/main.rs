@@ -2,6 +2,8 @@
 
 /// CLI arguments.
 mod args;
+/// Control-flow graph export.
+mod cfg;
 /// Mnemonic utilities.
 mod mnemonics;
 
@@ -22,6 +24,13 @@ fn main() -> anyhow::Result<()> {
     match cli.command {
         Command::Mnemonics => list_mnemonics()?,
         Command::Mnemonic { name } => display_mnemonic_info_by_name(&name)?,
+        Command::Cfg {
+            input,
+            output,
+            format,
+            functions,
+            monochrome,
+        } => cfg::export(&input, &output, format, functions, monochrome)?,
     }
 
     Ok(())
@@ -3,37 +3,39 @@ use std::fmt::{Binary, Debug, Display, LowerHex, Octal};
 use anyhow::Context as _;
 use asm::opcode::Mnemonic;
 use colored::Colorize as _;
+use upgrades::introduction::Introduction;
 
 /// A human readable description for a mnemonic.
 pub trait MnemonicDescription {
     /// Return description of this mnemonic.
     fn description(&self) -> &'static str;
 
+    /// Return the mainnet fork and EIP that introduced this mnemonic.
+    fn introduction(&self) -> Option<Introduction>;
+
     /// Format mnemonic in a human way.
     fn format_human(&self) -> String
     where
         Self: LowerHex + Display + Octal + Binary + Debug,
     {
+        let introduction = self
+            .introduction()
+            .map(|introduction| format!("\n  - introduced in {introduction}"))
+            .unwrap_or_default();
+
         format!(
-            "* {name} - {}\n  - opcode: 0x{self:x}, 0b{self:b}, 0o{self:o}",
+            "* {name} - {}\n  - opcode: 0x{self:x}, 0b{self:b}, 0o{self:o}{introduction}",
             self.description(),
             name = format!("{self}").yellow().bold(),
         )
     }
 }
 
-/// Try to get a mnemonic by its name.
+/// Try to get a mnemonic by its (case-insensitive) name or one of its historical names.
 pub fn get_mnemonic_by_name(name: &str) -> anyhow::Result<Mnemonic> {
-    let uppercase_name = name.to_uppercase();
-
-    Mnemonic::variants()
-        .iter()
-        .position(|mnemonic_name| mnemonic_name == &uppercase_name)
-        .map(|index| {
-            Mnemonic::iter()
-                .nth(index)
-                .expect("Mnemonic::VARIANTS array length matches Mnemonic::iter length")
-        })
+    name.parse::<asm2::Mnemonic>()
+        .ok()
+        .and_then(|mnemonic| Mnemonic::try_from(mnemonic).ok())
         .context(format!("failed to find mnemonic by the name \"{name}\""))
 }
 
@@ -337,4 +339,8 @@ impl MnemonicDescription for Mnemonic {
             _ => "Unknown operation",
         }
     }
+
+    fn introduction(&self) -> Option<Introduction> {
+        Introduction::of(asm2::Mnemonic::from(*self))
+    }
 }
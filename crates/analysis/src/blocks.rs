@@ -0,0 +1,172 @@
+//! Basic blocks.
+//!
+//! A basic block is a straight sequence of instructions that is only entered at its first
+//! instruction and only left after its last one. Blocks start (their first instruction is a
+//! _leader_) at offset 0, at every `JUMPDEST` and after every jump or terminator.
+//!
+//! ```
+//! # use oculars_analysis::blocks::basic_blocks;
+//! # use dasm::disassembler::Disassembler;
+//! # use upgrades::forks::shanghai::Shanghai;
+//! // PUSH1 0x05 JUMPI PUSH0 STOP JUMPDEST STOP
+//! let assembly = Disassembler::<Shanghai>::new().disassemble_hex("0x6005575f005b00").unwrap();
+//! let blocks = basic_blocks(&assembly);
+//!
+//! assert_eq!(blocks.len(), 3);
+//! assert_eq!(blocks[0].range, 0..3);
+//! assert_eq!(blocks[0].fall_through, Some(3));
+//! assert_eq!(blocks[1].range, 3..5);
+//! assert_eq!(blocks[1].fall_through, None);
+//! assert_eq!(blocks[2].range, 5..7);
+//! ```
+
+use asm2::{AssemblyInstruction, Mnemonic, OpCode};
+use core::ops::Range;
+use dasm::assembly::{Assembly, PositionedInstruction};
+
+/// A basic block of an [`Assembly`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BasicBlock<'a> {
+    /// The range of bytecode offsets the block covers, including the immediate value of its last
+    /// instruction.
+    pub range: Range<usize>,
+
+    /// The instructions of the block, never empty.
+    pub instructions: &'a [PositionedInstruction],
+
+    /// Offset of the block that execution continues at after the last instruction of this
+    /// block, if it does not unconditionally jump or halt.
+    pub fall_through: Option<usize>,
+}
+
+impl<'a> BasicBlock<'a> {
+    /// Returns the offset of the first instruction of the block.
+    #[must_use]
+    pub const fn start(&self) -> usize {
+        self.range.start
+    }
+
+    /// Returns the first instruction of the block.
+    #[must_use]
+    pub fn first(&self) -> &'a PositionedInstruction {
+        &self.instructions[0]
+    }
+
+    /// Returns the last instruction of the block, the one that ends the block.
+    #[must_use]
+    pub fn last(&self) -> &'a PositionedInstruction {
+        &self.instructions[self.instructions.len() - 1]
+    }
+
+    /// Returns [`true`] if the block starts with a `JUMPDEST` and can therefore be jumped to.
+    #[must_use]
+    pub fn is_jumpdest(&self) -> bool {
+        self.first().instruction.opcode() == Mnemonic::JUMPDEST
+    }
+}
+
+/// Returns [`true`] if no instruction of the same block can follow `instruction`.
+fn ends_block(instruction: &PositionedInstruction) -> bool {
+    let instruction = &instruction.instruction;
+
+    instruction.is_terminator()
+        || (instruction.is_control_flow() && instruction.opcode() != Mnemonic::JUMPDEST)
+}
+
+/// Returns [`true`] if execution can continue at the instruction following `instruction`.
+fn falls_through(instruction: &PositionedInstruction) -> bool {
+    match instruction.instruction.opcode() {
+        OpCode::Known(Mnemonic::JUMP) => false,
+        #[cfg(feature = "eof")]
+        OpCode::Known(Mnemonic::RJUMP | Mnemonic::RETF | Mnemonic::JUMPF) => false,
+        _ => !instruction.instruction.is_terminator(),
+    }
+}
+
+/// Splits an assembly into its basic blocks, ordered by their offset.
+#[must_use]
+pub fn basic_blocks(assembly: &Assembly) -> Vec<BasicBlock<'_>> {
+    let instructions = assembly.instructions();
+    let mut blocks: Vec<BasicBlock<'_>> = Vec::new();
+    let mut start = 0;
+
+    for (index, instruction) in instructions.iter().enumerate() {
+        let next = instructions.get(index + 1);
+        let next_is_leader =
+            next.is_some_and(|next| next.instruction.opcode() == Mnemonic::JUMPDEST);
+
+        if !ends_block(instruction) && !next_is_leader && next.is_some() {
+            continue;
+        }
+
+        blocks.push(BasicBlock {
+            range: instructions[start].position
+                ..instruction.position + instruction.instruction.size(),
+            instructions: &instructions[start..=index],
+            fall_through: next
+                .filter(|_| falls_through(instruction))
+                .map(|next| next.position),
+        });
+        start = index + 1;
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assembly;
+
+    /// Returns the ranges and fall-through successors of the blocks of `assembly`.
+    fn shape(assembly: &Assembly) -> Vec<(Range<usize>, Option<usize>)> {
+        basic_blocks(assembly)
+            .into_iter()
+            .map(|block| (block.range, block.fall_through))
+            .collect()
+    }
+
+    #[test]
+    fn leaders() {
+        // PUSH1 0x80 PUSH1 0x40 MSTORE CALLVALUE DUP1 ISZERO PUSH2 0x000f JUMPI PUSH0 DUP1 REVERT
+        // JUMPDEST POP PUSH1 0x15 JUMP STOP JUMPDEST STOP
+        let assembly = assembly("0x608060405234801561000f575f80fd5b50601556005b00");
+        assert_eq!(
+            shape(&assembly),
+            [
+                (0..12, Some(12)),
+                (12..15, None),
+                (15..20, None),
+                (20..21, None),
+                (21..23, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn jumpdest_splits_straight_code() {
+        // PUSH0 JUMPDEST JUMPDEST ADD
+        let assembly = assembly("0x5f5b5b01");
+        let blocks = basic_blocks(&assembly);
+
+        assert_eq!(
+            shape(&assembly),
+            [(0..1, Some(1)), (1..2, Some(2)), (2..4, None)]
+        );
+        assert!(!blocks[0].is_jumpdest());
+        assert!(blocks[2].is_jumpdest());
+        assert_eq!(blocks[2].first().position, 2);
+        assert_eq!(blocks[2].last().position, 3);
+    }
+
+    #[test]
+    fn unknown_opcodes_terminate() {
+        // CALLER 0x0c CALLER
+        assert_eq!(shape(&assembly("0x330c33")), [(0..2, None), (2..3, None)]);
+    }
+
+    #[test]
+    fn empty_assembly() {
+        assert!(basic_blocks(&Assembly::default()).is_empty());
+    }
+}
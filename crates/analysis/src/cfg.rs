@@ -0,0 +1,392 @@
+//! Control-flow graph.
+//!
+//! The nodes of the graph are [basic blocks](crate::blocks), identified by their start offset.
+//! Edges come from falling through into the next block, from the two branches of a `JUMPI` and
+//! from jumps. The target of a jump is resolved statically if it is pushed immediately before the
+//! jump (`PUSHx <target> JUMP`), otherwise the edge is [unresolved](Target::Unresolved).
+//! [`ControlFlowGraph::with_stack_analysis`] resolves more targets by [interpreting the
+//! stack](crate::stack).
+//!
+//! ```
+//! # use oculars_analysis::cfg::{ControlFlowGraph, EdgeKind, Target};
+//! # use dasm::disassembler::Disassembler;
+//! # use upgrades::forks::shanghai::Shanghai;
+//! // PUSH1 0x05 JUMPI PUSH0 STOP JUMPDEST STOP
+//! let assembly = Disassembler::<Shanghai>::new().disassemble_hex("0x6005575f005b00").unwrap();
+//! let cfg = ControlFlowGraph::new(&assembly);
+//!
+//! let successors: Vec<_> = cfg.successors(0).map(|edge| (edge.kind, edge.target)).collect();
+//! assert_eq!(
+//!     successors,
+//!     [
+//!         (EdgeKind::ConditionalFalse, Target::Block(3)),
+//!         (EdgeKind::ConditionalTrue, Target::Block(5))
+//!     ]
+//! );
+//! ```
+
+use asm2::{AssemblyInstruction, Mnemonic, OpCode, U256};
+use dasm::assembly::{Assembly, PositionedInstruction};
+use std::fmt::Display;
+
+use crate::blocks::{BasicBlock, basic_blocks};
+use crate::stack::JumpTargets;
+
+/// The way control flows along an [`Edge`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EdgeKind {
+    /// Execution continues with the next block without jumping.
+    FallThrough,
+    /// The condition of a `JUMPI` is zero and execution continues with the next block.
+    ConditionalFalse,
+    /// The condition of a `JUMPI` is not zero and the jump is taken.
+    ConditionalTrue,
+    /// An unconditional `JUMP`.
+    Jump,
+}
+
+impl EdgeKind {
+    /// Returns [`true`] if the edge is taken by jumping.
+    #[must_use]
+    pub const fn is_jump(&self) -> bool {
+        matches!(self, Self::ConditionalTrue | Self::Jump)
+    }
+}
+
+/// Why a jump target is invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvalidTarget {
+    /// The target is an instruction other than a `JUMPDEST`.
+    NotJumpdest,
+    /// The target lies inside the immediate value of a `PUSHx`.
+    PushData,
+    /// The target lies past the end of the code.
+    OutOfBounds,
+}
+
+impl Display for InvalidTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotJumpdest => write!(f, "not a JUMPDEST"),
+            Self::PushData => write!(f, "inside PUSH data"),
+            Self::OutOfBounds => write!(f, "out of bounds"),
+        }
+    }
+}
+
+/// Where an [`Edge`] leads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Target {
+    /// The block starting at this offset.
+    Block(usize),
+    /// The target of the jump is not known statically.
+    Unresolved,
+    /// The jump target is known, but jumping to it fails.
+    Invalid {
+        /// The target of the jump.
+        target: U256,
+        /// Why jumping to the target fails.
+        reason: InvalidTarget,
+    },
+}
+
+impl Target {
+    /// Returns the start offset of the target block, if the target is a valid block.
+    #[must_use]
+    pub const fn block(&self) -> Option<usize> {
+        match self {
+            Self::Block(start) => Some(*start),
+            Self::Unresolved | Self::Invalid { .. } => None,
+        }
+    }
+}
+
+/// An edge of a [`ControlFlowGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge {
+    /// Start offset of the block the edge leaves.
+    pub from: usize,
+    /// Where the edge leads.
+    pub target: Target,
+    /// The way control flows along the edge.
+    pub kind: EdgeKind,
+}
+
+/// A control-flow graph of an [`Assembly`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ControlFlowGraph<'a> {
+    /// Basic blocks ordered by their start offset.
+    blocks: Vec<BasicBlock<'a>>,
+    /// Edges ordered by the block they leave.
+    edges: Vec<Edge>,
+    /// Offset right after the last instruction.
+    code_size: usize,
+}
+
+impl<'a> ControlFlowGraph<'a> {
+    /// Builds the control-flow graph of an assembly, resolving the targets of jumps that are
+    /// pushed immediately before the jump.
+    #[must_use]
+    pub fn new(assembly: &'a Assembly) -> Self {
+        let blocks = basic_blocks(assembly);
+        let code_size = blocks.last().map_or(0, |block| block.range.end);
+
+        let mut cfg = Self {
+            blocks,
+            edges: Vec::new(),
+            code_size,
+        };
+
+        let mut edges = Vec::new();
+        for block in &cfg.blocks {
+            let jump_kind = match block.last().instruction.opcode() {
+                OpCode::Known(Mnemonic::JUMP) => Some(EdgeKind::Jump),
+                OpCode::Known(Mnemonic::JUMPI) => Some(EdgeKind::ConditionalTrue),
+                _ => None,
+            };
+
+            if let Some(next) = block.fall_through {
+                edges.push(Edge {
+                    from: block.start(),
+                    target: Target::Block(next),
+                    kind: if jump_kind.is_some() {
+                        EdgeKind::ConditionalFalse
+                    } else {
+                        EdgeKind::FallThrough
+                    },
+                });
+            }
+
+            if let Some(kind) = jump_kind {
+                edges.push(Edge {
+                    from: block.start(),
+                    target: Self::pushed_target(block)
+                        .map_or(Target::Unresolved, |target| cfg.resolve_target(target)),
+                    kind,
+                });
+            }
+        }
+
+        cfg.edges = edges;
+        cfg
+    }
+
+    /// Builds the control-flow graph of an assembly, resolving the targets of jumps with an
+    /// [abstract stack interpretation](crate::stack).
+    ///
+    /// A jump gets one edge per target found. Jumps whose target is not known in some context keep
+    /// an [unresolved](Target::Unresolved) edge next to the resolved ones.
+    #[must_use]
+    pub fn with_stack_analysis(assembly: &'a Assembly) -> Self {
+        let mut cfg = Self::new(assembly);
+        let targets = JumpTargets::analyze(&cfg);
+
+        let mut edges = Vec::with_capacity(cfg.edges.len());
+        for edge in cfg.edges.drain(..) {
+            match targets.of(edge.from) {
+                Some(found) if edge.kind.is_jump() => {
+                    edges.extend(found.iter().map(|&target| Edge { target, ..edge }));
+                }
+                _ => edges.push(edge),
+            }
+        }
+
+        cfg.edges = edges;
+        cfg
+    }
+
+    /// Returns the jump target pushed immediately before the jump ending `block`.
+    fn pushed_target(block: &BasicBlock<'_>) -> Option<U256> {
+        let [.., push, _] = block.instructions else {
+            return None;
+        };
+
+        push.instruction
+            .is_push()
+            .then(|| U256::from_be_slice(push.instruction.immediate_bytes()))
+            .flatten()
+    }
+
+    /// Checks that jumping to `target` lands on a `JUMPDEST`.
+    #[must_use]
+    pub fn resolve_target(&self, target: U256) -> Target {
+        let invalid = |reason| Target::Invalid { target, reason };
+
+        let Some(offset) = target
+            .to_u64()
+            .and_then(|target| usize::try_from(target).ok())
+            .filter(|&offset| offset < self.code_size)
+        else {
+            return invalid(InvalidTarget::OutOfBounds);
+        };
+
+        match self.instruction_at(offset) {
+            Some(instruction) if instruction.instruction.opcode() == Mnemonic::JUMPDEST => {
+                Target::Block(offset)
+            }
+            Some(_) => invalid(InvalidTarget::NotJumpdest),
+            None => invalid(InvalidTarget::PushData),
+        }
+    }
+
+    /// Returns the instruction starting at `offset`, if any.
+    fn instruction_at(&self, offset: usize) -> Option<&'a PositionedInstruction> {
+        let block = self.block_containing(offset)?;
+        let index = block
+            .instructions
+            .binary_search_by_key(&offset, |instruction| instruction.position)
+            .ok()?;
+
+        Some(&block.instructions[index])
+    }
+
+    /// Returns the block whose range contains `offset`.
+    #[must_use]
+    pub fn block_containing(&self, offset: usize) -> Option<&BasicBlock<'a>> {
+        let index = self
+            .blocks
+            .partition_point(|block| block.start() <= offset)
+            .checked_sub(1)?;
+
+        self.blocks
+            .get(index)
+            .filter(|block| block.range.contains(&offset))
+    }
+
+    /// Returns the basic blocks, ordered by their start offset.
+    #[must_use]
+    pub fn blocks(&self) -> &[BasicBlock<'a>] {
+        &self.blocks
+    }
+
+    /// Returns the block starting at `start`.
+    #[must_use]
+    pub fn block(&self, start: usize) -> Option<&BasicBlock<'a>> {
+        self.blocks
+            .binary_search_by_key(&start, BasicBlock::start)
+            .ok()
+            .map(|index| &self.blocks[index])
+    }
+
+    /// Returns all edges, ordered by the block they leave.
+    #[must_use]
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    /// Returns the edges leaving the block starting at `start`.
+    pub fn successors(&self, start: usize) -> impl Iterator<Item = &Edge> {
+        let first = self.edges.partition_point(|edge| edge.from < start);
+
+        self.edges[first..]
+            .iter()
+            .take_while(move |edge| edge.from == start)
+    }
+
+    /// Returns the edges leading to the block starting at `start`.
+    pub fn predecessors(&self, start: usize) -> impl Iterator<Item = &Edge> {
+        self.edges
+            .iter()
+            .filter(move |edge| edge.target == Target::Block(start))
+    }
+
+    /// Returns the jumps whose target is not known statically.
+    pub fn unresolved(&self) -> impl Iterator<Item = &Edge> {
+        self.edges
+            .iter()
+            .filter(|edge| edge.target == Target::Unresolved)
+    }
+
+    /// Returns the jumps whose target is known but invalid.
+    pub fn invalid(&self) -> impl Iterator<Item = &Edge> {
+        self.edges
+            .iter()
+            .filter(|edge| matches!(edge.target, Target::Invalid { .. }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assembly;
+
+    /// Returns the edges of `cfg` as tuples.
+    fn edges(cfg: &ControlFlowGraph<'_>) -> Vec<(usize, Target, EdgeKind)> {
+        cfg.edges()
+            .iter()
+            .map(|edge| (edge.from, edge.target, edge.kind))
+            .collect()
+    }
+
+    #[test]
+    fn static_jumps() {
+        // 0: PUSH1 0x80 PUSH1 0x40 MSTORE CALLVALUE DUP1 ISZERO PUSH2 0x000f JUMPI
+        // 12: PUSH0 DUP1 REVERT
+        // 15: JUMPDEST POP PUSH1 0x15 JUMP
+        // 20: STOP
+        // 21: JUMPDEST STOP
+        let assembly = assembly("0x608060405234801561000f575f80fd5b50601556005b00");
+        let cfg = ControlFlowGraph::new(&assembly);
+
+        assert_eq!(
+            edges(&cfg),
+            [
+                (0, Target::Block(12), EdgeKind::ConditionalFalse),
+                (0, Target::Block(15), EdgeKind::ConditionalTrue),
+                (15, Target::Block(21), EdgeKind::Jump),
+            ]
+        );
+        assert_eq!(cfg.predecessors(21).count(), 1);
+        assert_eq!(cfg.successors(12).count(), 0);
+        assert_eq!(cfg.unresolved().count(), 0);
+        assert_eq!(cfg.block_containing(10).map(BasicBlock::start), Some(0));
+        assert_eq!(cfg.block(20).map(|block| block.range.clone()), Some(20..21));
+    }
+
+    #[test]
+    fn fall_through_into_jumpdest() {
+        // PUSH0 JUMPDEST STOP
+        let assembly = assembly("0x5f5b00");
+        let cfg = ControlFlowGraph::new(&assembly);
+
+        assert_eq!(edges(&cfg), [(0, Target::Block(1), EdgeKind::FallThrough)]);
+    }
+
+    #[test]
+    fn unresolved_jumps() {
+        // CALLDATASIZE JUMP, PUSH1 0x00 CALLDATALOAD JUMP
+        let assembly = assembly("0x3656600035565b");
+        let cfg = ControlFlowGraph::new(&assembly);
+
+        assert_eq!(
+            cfg.unresolved().map(|edge| edge.from).collect::<Vec<_>>(),
+            [0, 2]
+        );
+    }
+
+    #[test]
+    fn invalid_jumps() {
+        // 0: PUSH1 0x09 JUMP
+        // 3: PUSH1 0x0a JUMP
+        // 6: PUSH1 0x0b JUMP
+        // 9: PUSH1 0x5b JUMP
+        // 12: PUSH2 0xffff JUMP
+        let assembly = assembly("0x600956600a56600b56605b5661ffff56");
+        let cfg = ControlFlowGraph::new(&assembly);
+
+        let invalid = |target: u64, reason| Target::Invalid {
+            target: U256::from(target),
+            reason,
+        };
+        assert_eq!(
+            cfg.invalid().map(|edge| edge.target).collect::<Vec<_>>(),
+            [
+                invalid(0x09, InvalidTarget::NotJumpdest),
+                invalid(0x0A, InvalidTarget::PushData),
+                invalid(0x0B, InvalidTarget::NotJumpdest),
+                invalid(0x5B, InvalidTarget::OutOfBounds),
+                invalid(0xFFFF, InvalidTarget::OutOfBounds),
+            ]
+        );
+    }
+}
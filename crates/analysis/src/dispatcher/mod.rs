@@ -0,0 +1,405 @@
+//! Recovery of external functions from the dispatcher.
+//!
+//! Contracts start with a dispatcher that loads the 4-byte function selector from the calldata and
+//! jumps to the entry of the external function with that selector. solc compares the selector
+//! with every known selector, Vyper looks up the selector in jump tables stored in the code. The
+//! dispatcher analyses find the selectors of the external functions and their entries.
+//!
+//! Both analyses interpret the blocks reachable from the entry of the code symbolically, tracking
+//! the selector through the stack instead of matching instruction sequences literally. Every
+//! `JUMPI` conditioned on the selector being equal to a constant dispatches to an external
+//! function, every other branch is followed.
+//!
+//! ```
+//! # use oculars_analysis::{cfg::ControlFlowGraph, dispatcher::{ExternalFunction, solc_selectors}};
+//! # use dasm::disassembler::Disassembler;
+//! # use upgrades::forks::shanghai::Shanghai;
+//! // 0: PUSH0 CALLDATALOAD PUSH1 0xe0 SHR
+//! // 5: DUP1 PUSH4 0xa9059cbb EQ PUSH1 0x10 JUMPI
+//! // 15: STOP
+//! // 16: JUMPDEST STOP
+//! let assembly = Disassembler::<Shanghai>::new()
+//!     .disassemble_hex("0x5f3560e01c8063a9059cbb14601057005b00")
+//!     .unwrap();
+//!
+//! assert_eq!(
+//!     solc_selectors(&ControlFlowGraph::new(&assembly)),
+//!     [ExternalFunction {
+//!         selector: [0xa9, 0x05, 0x9c, 0xbb],
+//!         entry: 16
+//!     }]
+//! );
+//! ```
+
+mod solc;
+mod vyper;
+
+use asm2::{AssemblyInstruction, Mnemonic, OpCode, U256};
+use dasm::assembly::PositionedInstruction;
+use std::collections::{BTreeMap, HashSet, VecDeque};
+
+use crate::cfg::ControlFlowGraph;
+use crate::stack::{AbstractStack, StackValue};
+
+pub use solc::solc_selectors;
+pub use vyper::vyper_selectors;
+
+/// An external function of a contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExternalFunction {
+    /// The 4-byte selector the function is called with.
+    pub selector: [u8; 4],
+
+    /// Offset of the block execution continues at when the contract is called with the selector.
+    pub entry: usize,
+}
+
+/// A table in the code, indexed by the selector modulo the amount of its entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Table {
+    /// Offset of the first entry in the code.
+    offset: usize,
+    /// Amount of entries.
+    entries: usize,
+    /// Distance between two entries, in bytes.
+    stride: usize,
+}
+
+/// The entry of a [`Table`] selected by the selector, copied into the end of the first word of
+/// memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct CopiedEntry {
+    /// The table the entry was copied from.
+    table: Table,
+    /// Amount of copied bytes.
+    size: usize,
+}
+
+/// A value on the stack of a dispatcher.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Symbol {
+    /// A constant.
+    Constant(U256),
+    /// The first word of the calldata.
+    CallData,
+    /// The function selector, the first four bytes of the calldata.
+    Selector,
+    /// Whether the selector equals the contained selector.
+    IsSelector([u8; 4]),
+    /// Whether the selector differs from the contained selector.
+    IsNotSelector([u8; 4]),
+    /// The selector modulo the contained amount of buckets.
+    Bucket(usize),
+    /// A [bucket](Symbol::Bucket) multiplied by `stride`.
+    ScaledBucket {
+        /// Amount of buckets.
+        buckets: usize,
+        /// The factor of the bucket.
+        stride: usize,
+    },
+    /// Offset of the entry of a table selected by the selector.
+    TableEntry(Table),
+    /// The first word of memory, holding a [copied entry](CopiedEntry).
+    LoadedEntry(CopiedEntry),
+    /// Any other value.
+    Unknown,
+}
+
+impl StackValue for Symbol {
+    const UNKNOWN: Self = Self::Unknown;
+}
+
+/// Returns `value` as a selector, if it fits into four bytes.
+fn selector(value: U256) -> Option<[u8; 4]> {
+    value
+        .to_u64()
+        .and_then(|value| u32::try_from(value).ok())
+        .map(u32::to_be_bytes)
+}
+
+/// Returns `value` as an amount of buckets or a factor, if it is a small positive number.
+fn small(value: U256) -> Option<usize> {
+    value
+        .to_u64()
+        .and_then(|value| u16::try_from(value).ok())
+        .filter(|&value| value > 0)
+        .map(usize::from)
+}
+
+/// Returns the value an instruction pushes, given its inputs, topmost first, and the entry copied
+/// into memory.
+fn evaluate(
+    instruction: &PositionedInstruction,
+    inputs: &[Symbol],
+    memory: Option<CopiedEntry>,
+) -> Symbol {
+    use Symbol::{
+        Bucket, CallData, Constant, IsNotSelector, IsSelector, LoadedEntry, ScaledBucket, Selector,
+        TableEntry,
+    };
+
+    let opcode = instruction.instruction.opcode();
+    if opcode.is_push() {
+        return U256::from_be_slice(instruction.instruction.immediate_bytes())
+            .map_or(Symbol::Unknown, Constant);
+    }
+
+    let OpCode::Known(mnemonic) = opcode else {
+        return Symbol::Unknown;
+    };
+
+    let symbol = match (mnemonic, inputs) {
+        (Mnemonic::CALLDATALOAD, [Constant(offset)]) if offset.is_zero() => Some(CallData),
+        (Mnemonic::SHR, [Constant(shift), CallData]) if *shift == U256::from(224_u64) => {
+            Some(Selector)
+        }
+        (Mnemonic::DIV, [CallData, Constant(divisor)]) if *divisor == U256::from(1_u64) << 224 => {
+            Some(Selector)
+        }
+        (Mnemonic::AND, [Constant(mask), Selector] | [Selector, Constant(mask)]) => {
+            if *mask == U256::from(u64::from(u32::MAX)) {
+                Some(Selector)
+            } else {
+                // `mod` by a power of two is optimized into `and`.
+                small(*mask)
+                    .map(|mask| mask + 1)
+                    .filter(|buckets| buckets.is_power_of_two())
+                    .map(Bucket)
+            }
+        }
+        (Mnemonic::EQ, [Constant(value), Selector] | [Selector, Constant(value)]) => {
+            selector(*value).map(IsSelector)
+        }
+        (Mnemonic::AND, [IsSelector(selector), _] | [_, IsSelector(selector)]) => {
+            Some(IsSelector(*selector))
+        }
+        (Mnemonic::ISZERO, [Selector]) => Some(IsSelector([0; 4])),
+        (Mnemonic::ISZERO, [IsSelector(selector)]) => Some(IsNotSelector(*selector)),
+        (Mnemonic::ISZERO, [IsNotSelector(selector)]) => Some(IsSelector(*selector)),
+        (Mnemonic::MOD, [Selector, Constant(buckets)]) => small(*buckets).map(Bucket),
+        (
+            Mnemonic::MUL,
+            [Constant(stride), Bucket(buckets)] | [Bucket(buckets), Constant(stride)],
+        ) => small(*stride).map(|stride| ScaledBucket {
+            buckets: *buckets,
+            stride,
+        }),
+        // `mul` by a power of two is optimized into `shl`.
+        (Mnemonic::SHL, [Constant(shift), Bucket(buckets)]) => small(*shift)
+            .filter(|&shift| shift < 16)
+            .map(|shift| ScaledBucket {
+                buckets: *buckets,
+                stride: 1 << shift,
+            }),
+        (
+            Mnemonic::ADD,
+            [Constant(offset), ScaledBucket { buckets, stride }]
+            | [ScaledBucket { buckets, stride }, Constant(offset)],
+        ) => small(*offset).map(|offset| {
+            TableEntry(Table {
+                offset,
+                entries: *buckets,
+                stride: *stride,
+            })
+        }),
+        (Mnemonic::MLOAD, [Constant(offset)]) if offset.is_zero() => memory.map(LoadedEntry),
+        _ => None,
+    };
+
+    symbol.unwrap_or(Symbol::Unknown)
+}
+
+/// A block queued for interpretation, with the stack and memory on entry.
+type Queued = (usize, AbstractStack<Symbol>, Option<CopiedEntry>);
+
+/// Symbolic interpreter of a dispatcher.
+struct Interpreter<'c, 'a> {
+    /// Graph of the interpreted code.
+    cfg: &'c ControlFlowGraph<'a>,
+    /// The code Vyper's jump tables are read from, [`None`] if jump tables are not decoded.
+    tables: Option<&'c [u8]>,
+    /// The entries of the recovered functions, by their selector.
+    functions: BTreeMap<[u8; 4], usize>,
+    /// Start offsets of the blocks that have been interpreted.
+    visited: HashSet<usize>,
+    /// Blocks left to interpret.
+    queue: VecDeque<Queued>,
+}
+
+impl<'c, 'a> Interpreter<'c, 'a> {
+    /// Creates an interpreter that starts at the entry of the code and follows comparisons of
+    /// the selector only.
+    fn new(cfg: &'c ControlFlowGraph<'a>) -> Self {
+        Self::with_tables(cfg, None)
+    }
+
+    /// Creates an interpreter that starts at the entry of the code and also decodes Vyper's jump
+    /// tables stored in `code`, the bytecode `cfg` was built from.
+    fn vyper(cfg: &'c ControlFlowGraph<'a>, code: &'c [u8]) -> Self {
+        Self::with_tables(cfg, Some(code))
+    }
+
+    /// Creates an interpreter that starts at the entry of the code.
+    fn with_tables(cfg: &'c ControlFlowGraph<'a>, tables: Option<&'c [u8]>) -> Self {
+        let mut queue = VecDeque::new();
+        if let Some(entry) = cfg.blocks().first() {
+            queue.push_back((entry.start(), AbstractStack::default(), None));
+        }
+
+        Self {
+            cfg,
+            tables,
+            functions: BTreeMap::new(),
+            visited: HashSet::new(),
+            queue,
+        }
+    }
+
+    /// Interprets blocks until no block is left and returns the recovered functions, ordered by
+    /// their selector.
+    ///
+    /// If the same selector dispatches more than once, the first dispatch found wins.
+    fn run(mut self) -> Vec<ExternalFunction> {
+        while let Some((start, stack, memory)) = self.queue.pop_front() {
+            if self.visited.insert(start) {
+                self.interpret(start, stack, memory);
+            }
+        }
+
+        self.functions
+            .into_iter()
+            .map(|(selector, entry)| ExternalFunction { selector, entry })
+            .collect()
+    }
+
+    /// Records that `selector` dispatches to the block starting at `entry`.
+    fn dispatch(&mut self, selector: [u8; 4], entry: usize) {
+        self.functions.entry(selector).or_insert(entry);
+    }
+
+    /// Queues the block starting at `next`, if there is such a block.
+    fn enqueue(
+        &mut self,
+        next: Option<usize>,
+        stack: AbstractStack<Symbol>,
+        memory: Option<CopiedEntry>,
+    ) {
+        if let Some(next) = next {
+            self.queue.push_back((next, stack, memory));
+        }
+    }
+
+    /// Returns the start of the block a jump to `target` leads to.
+    fn resolve(&self, target: U256) -> Option<usize> {
+        self.cfg.resolve_target(target).block()
+    }
+
+    /// Interprets the block starting at `start` and queues its successors.
+    fn interpret(
+        &mut self,
+        start: usize,
+        mut stack: AbstractStack<Symbol>,
+        mut memory: Option<CopiedEntry>,
+    ) {
+        let Some(block) = self.cfg.block(start) else {
+            return;
+        };
+        let Some((last, body)) = block.instructions.split_last() else {
+            return;
+        };
+        let fall_through = block.fall_through;
+
+        for instruction in body {
+            if let Some(code) = self.tables
+                && instruction.instruction.opcode() == Mnemonic::CODECOPY
+            {
+                memory = self.copy(code, &stack, memory);
+            }
+            stack.execute_with(instruction, |instruction, inputs| {
+                evaluate(instruction, inputs, memory)
+            });
+        }
+
+        let target = |symbol| match symbol {
+            Symbol::Constant(target) => self.resolve(target),
+            _ => None,
+        };
+
+        match last.instruction.opcode() {
+            OpCode::Known(Mnemonic::JUMP) => match (stack.pop(), self.tables) {
+                (Symbol::LoadedEntry(entry), Some(code)) => {
+                    for bucket in vyper::sparse_buckets(code, entry) {
+                        let bucket = self.resolve(U256::from(bucket as u64));
+                        self.enqueue(bucket, stack.clone(), None);
+                    }
+                }
+                (symbol, _) => {
+                    let target = target(symbol);
+                    self.enqueue(target, stack, memory);
+                }
+            },
+            OpCode::Known(Mnemonic::JUMPI) => {
+                let target = target(stack.pop());
+                let condition = stack.pop();
+
+                match (condition, target, fall_through) {
+                    (Symbol::IsSelector(selector), Some(entry), _) => {
+                        self.dispatch(selector, entry);
+                        self.enqueue(fall_through, stack, memory);
+                    }
+                    (Symbol::IsNotSelector(selector), _, Some(entry)) => {
+                        self.dispatch(selector, entry);
+                        self.enqueue(target, stack, memory);
+                    }
+                    _ => {
+                        self.enqueue(target, stack.clone(), memory);
+                        self.enqueue(fall_through, stack, memory);
+                    }
+                }
+            }
+            _ => {
+                stack.execute_with(last, |instruction, inputs| {
+                    evaluate(instruction, inputs, memory)
+                });
+                self.enqueue(fall_through, stack, memory);
+            }
+        }
+    }
+
+    /// Applies a `CODECOPY` from `code` with the inputs on top of `stack` to `memory` and returns
+    /// the new memory.
+    ///
+    /// Copying the function info after copying the header of a bucket of a dense Vyper jump table
+    /// dispatches to every function in the table.
+    fn copy(
+        &mut self,
+        code: &[u8],
+        stack: &AbstractStack<Symbol>,
+        memory: Option<CopiedEntry>,
+    ) -> Option<CopiedEntry> {
+        let destination = stack.peek(0);
+        let source = stack.peek(1);
+        let Symbol::Constant(size) = stack.peek(2) else {
+            return None;
+        };
+        let size = small(size)?;
+
+        if let Some(header) = memory.filter(|memory| memory.size == vyper::DENSE_HEADER_SIZE) {
+            for (selector, label) in vyper::dense_functions(code, header, size) {
+                if let Some(entry) = self.resolve(U256::from(u64::from(label))) {
+                    self.dispatch(selector, entry);
+                }
+            }
+            return None;
+        }
+
+        match (destination, source) {
+            (Symbol::Constant(destination), Symbol::TableEntry(table))
+                if small(destination).is_some_and(|destination| destination + size == 32) =>
+            {
+                Some(CopiedEntry { table, size })
+            }
+            _ => None,
+        }
+    }
+}
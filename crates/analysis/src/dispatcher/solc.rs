@@ -0,0 +1,109 @@
+//! Dispatchers generated by solc.
+//!
+//! solc loads the selector with `PUSH0 CALLDATALOAD PUSH1 0xe0 SHR` (`PUSH1 0x00` before
+//! Shanghai, `DIV` by `2^224` and `AND` with `0xffffffff` before Constantinople) and compares it
+//! with every selector in turn:
+//!
+//! ```text
+//! DUP1 PUSH4 <selector> EQ PUSH2 <entry> JUMPI
+//! ```
+//!
+//! Contracts with many functions first split the selectors with a binary search over
+//! `DUP1 PUSH4 <pivot> GT PUSH2 <upper half> JUMPI` comparisons. Selectors with leading zero bytes
+//! are pushed with shorter `PUSHx` instructions or compared with `ISZERO`.
+//!
+//! The sequences are not matched literally, the dispatcher is [interpreted](super) instead.
+
+use super::{ExternalFunction, Interpreter};
+use crate::cfg::ControlFlowGraph;
+
+/// Recovers the external functions of a contract compiled by solc, ordered by their selector.
+///
+/// If the dispatcher compares the selector with the same constant more than once, the first
+/// comparison found wins.
+#[must_use]
+pub fn solc_selectors(cfg: &ControlFlowGraph<'_>) -> Vec<ExternalFunction> {
+    Interpreter::new(cfg).run()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assembly;
+
+    /// Returns the selectors and entries recovered from hex encoded bytecode.
+    fn selectors(hex: &str) -> Vec<(u32, usize)> {
+        solc_selectors(&ControlFlowGraph::new(&assembly(hex)))
+            .into_iter()
+            .map(|function| (u32::from_be_bytes(function.selector), function.entry))
+            .collect()
+    }
+
+    #[test]
+    fn binary_search() {
+        // 0: PUSH1 0x04 CALLDATASIZE LT PUSH1 0x42 JUMPI
+        // 7: PUSH0 CALLDATALOAD PUSH1 0xe0 SHR DUP1 PUSH4 0x313ce567 GT PUSH1 0x2d JUMPI
+        // 22: DUP1 PUSH4 0x06fdde03 EQ PUSH1 0x46 JUMPI
+        // 32: DUP1 PUSH4 0x095ea7b3 EQ PUSH1 0x48 JUMPI
+        // 42: PUSH1 0x42 JUMP
+        // 45: JUMPDEST DUP1 PUSH4 0x70a08231 EQ PUSH1 0x4a JUMPI
+        // 56: PUSH4 0xa9059cbb DUP2 EQ PUSH1 0x4c JUMPI
+        // 66: JUMPDEST PUSH0 DUP1 REVERT
+        // 70: JUMPDEST STOP JUMPDEST STOP JUMPDEST STOP JUMPDEST STOP
+        let hex = concat!(
+            "0x600436106042575f3560e01c8063313ce56711602d57806306fdde0314604657",
+            "8063095ea7b3146048576042565b806370a0823114604a5763a9059cbb8114604c",
+            "575b5f80fd5b005b005b005b00",
+        );
+
+        assert_eq!(
+            selectors(hex),
+            [
+                (0x06FD_DE03, 70),
+                (0x095E_A7B3, 72),
+                (0x70A0_8231, 74),
+                (0xA905_9CBB, 76),
+            ]
+        );
+    }
+
+    #[test]
+    fn pre_constantinople() {
+        // 0: PUSH1 0x00 CALLDATALOAD PUSH29 0x01000000... SWAP1 DIV PUSH4 0xffffffff AND
+        // 41: DUP1 PUSH4 0xa9059cbb EQ PUSH2 0x0038 JUMPI
+        // 52: PUSH1 0x00 DUP1 REVERT
+        // 56: JUMPDEST STOP
+        let hex = concat!(
+            "0x6000357c0100000000000000000000000000000000000000000000000000000000",
+            "900463ffffffff168063a9059cbb1461003857600080fd5b00",
+        );
+
+        assert_eq!(selectors(hex), [(0xA905_9CBB, 56)]);
+    }
+
+    #[test]
+    fn zero_selector() {
+        // 0: PUSH0 CALLDATALOAD PUSH1 0xe0 SHR DUP1 ISZERO PUSH1 0x0c JUMPI
+        // 10: PUSH0 REVERT
+        // 12: JUMPDEST STOP
+        assert_eq!(selectors("0x5f3560e01c8015600c575ffd5b00"), [(0, 12)]);
+    }
+
+    #[test]
+    fn negated_comparison() {
+        // 0: PUSH0 CALLDATALOAD PUSH1 0xe0 SHR PUSH4 0xa9059cbb EQ ISZERO PUSH1 0x11 JUMPI
+        // 15: STOP
+        // 16: INVALID
+        // 17: JUMPDEST PUSH0 DUP1 REVERT
+        assert_eq!(
+            selectors("0x5f3560e01c63a9059cbb141560115700fe5b5f80fd"),
+            [(0xA905_9CBB, 15)]
+        );
+    }
+
+    #[test]
+    fn other_comparisons_are_ignored() {
+        // PUSH0 CALLDATALOAD PUSH4 0xa9059cbb EQ PUSH1 0x0c JUMPI STOP JUMPDEST STOP
+        assert!(selectors("0x5f3563a9059cbb14600c57005b00").is_empty());
+    }
+}
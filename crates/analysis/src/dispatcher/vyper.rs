@@ -0,0 +1,206 @@
+//! Dispatchers generated by Vyper 0.3.10 and later.
+//!
+//! Vyper hashes selectors into buckets by taking them modulo the amount of buckets and copies the
+//! header of the bucket of the called selector from a table in the code into memory with
+//! `CODECOPY`. The sparse dispatcher, optimized for gas, stores the 2-byte location of the code of
+//! each bucket and jumps to it. The code of a bucket compares the selector with the selectors of
+//! the bucket in turn:
+//!
+//! ```text
+//! PUSH1 <buckets> DUP2 MOD PUSH1 0x01 SHL PUSH2 <table> ADD PUSH1 0x02 SWAP1 PUSH1 0x1e CODECOPY
+//! PUSH0 MLOAD JUMP
+//! ```
+//!
+//! The dense dispatcher, optimized for code size, stores a 5-byte header per bucket: a 2-byte
+//! magic number, the 2-byte location of the function infos of the bucket and the amount of
+//! functions in the bucket. Every function info starts with the 4-byte selector and the 2-byte
+//! label of the function, followed by the packed minimum calldata size and payability. The
+//! dispatcher copies the function info selected by the magic number into memory with a second
+//! `CODECOPY` of the size of a function info and jumps to its label.
+//!
+//! The tables are found by [interpreting](super) the dispatcher, which resolves the `CODECOPY`
+//! arguments. All functions of a dense table are read from the code directly.
+
+use super::{CopiedEntry, ExternalFunction, Interpreter};
+use crate::cfg::ControlFlowGraph;
+
+/// Size of the header of a bucket of a dense jump table.
+pub(super) const DENSE_HEADER_SIZE: usize = 5;
+
+/// Size of the header of a bucket of a sparse jump table.
+const SPARSE_HEADER_SIZE: usize = 2;
+
+/// Returns the big endian number stored in `size` bytes of `code` at `offset`, if in bounds.
+fn read(code: &[u8], offset: usize, size: usize) -> Option<usize> {
+    code.get(offset..offset.checked_add(size)?).map(|bytes| {
+        bytes
+            .iter()
+            .fold(0, |value, &byte| (value << 8) | usize::from(byte))
+    })
+}
+
+/// Returns the locations of the buckets of a sparse jump table, if `entry` is copied from one.
+pub(super) fn sparse_buckets(code: &[u8], entry: CopiedEntry) -> Vec<usize> {
+    if entry.size != SPARSE_HEADER_SIZE {
+        return Vec::new();
+    }
+
+    let table = entry.table;
+    (0..table.entries)
+        .map_while(|bucket| {
+            read(
+                code,
+                table.offset + bucket * table.stride,
+                SPARSE_HEADER_SIZE,
+            )
+        })
+        .collect()
+}
+
+/// Returns the selectors and labels of the functions of a dense jump table, whose function infos
+/// are `info_size` bytes long.
+pub(super) fn dense_functions(
+    code: &[u8],
+    header: CopiedEntry,
+    info_size: usize,
+) -> Vec<([u8; 4], u16)> {
+    let table = header.table;
+    let mut functions = Vec::new();
+
+    for bucket in 0..table.entries {
+        let header = table.offset + bucket * table.stride;
+        let (Some(location), Some(size)) = (read(code, header + 2, 2), read(code, header + 4, 1))
+        else {
+            break;
+        };
+
+        for function in 0..size {
+            let info = location + function * info_size;
+            let (Some(selector), Some(label)) = (code.get(info..info + 4), read(code, info + 4, 2))
+            else {
+                break;
+            };
+
+            functions.push((
+                selector.try_into().expect("selectors are four bytes long"),
+                u16::try_from(label).expect("labels are two bytes long"),
+            ));
+        }
+    }
+
+    functions
+}
+
+/// Recovers the external functions of a contract compiled by Vyper, ordered by their selector.
+///
+/// The jump tables are read from `code`, the bytecode `cfg` was built from. Besides jump tables,
+/// selectors compared in turn like solc does are recovered too, which covers the dispatchers of
+/// Vyper before 0.3.10 and the buckets of sparse tables.
+#[must_use]
+pub fn vyper_selectors(cfg: &ControlFlowGraph<'_>, code: &[u8]) -> Vec<ExternalFunction> {
+    Interpreter::vyper(cfg, code).run()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dispatcher::solc_selectors, tests::assembly};
+    use dasm::source::BytecodeSource;
+
+    /// Returns the selectors and entries recovered from hex encoded bytecode.
+    fn selectors(hex: &str) -> Vec<(u32, usize)> {
+        let code = hex.extract().unwrap();
+
+        vyper_selectors(&ControlFlowGraph::new(&assembly(hex)), code.as_ref())
+            .into_iter()
+            .map(|function| (u32::from_be_bytes(function.selector), function.entry))
+            .collect()
+    }
+
+    #[test]
+    fn sparse() {
+        // 0: PUSH0 CALLDATALOAD PUSH1 0xe0 SHR
+        // 5: PUSH1 0x02 DUP2 MOD PUSH1 0x01 SHL PUSH2 0x0047 ADD
+        // 16: PUSH1 0x02 SWAP1 PUSH1 0x1e CODECOPY PUSH0 MLOAD JUMP
+        // 25: JUMPDEST PUSH4 0xdd62ed3e DUP2 EQ ISZERO PUSH2 0x0043 JUMPI
+        // 38: STOP
+        // 39: JUMPDEST PUSH4 0xa9059cbb DUP2 EQ ISZERO PUSH2 0x0035 JUMPI
+        // 52: STOP
+        // 53: JUMPDEST PUSH4 0x70a08231 DUP2 EQ ISZERO PUSH2 0x0043 JUMPI
+        // 66: STOP
+        // 67: JUMPDEST PUSH0 DUP1 REVERT
+        // 71: 0x0019 0x0027
+        let hex = concat!(
+            "0x5f3560e01c6002810660011b61004701600290601e395f51565b63dd62ed3e8114156100",
+            "4357005b63a9059cbb81141561003557005b6370a0823181141561004357005b5f80fd00",
+            "190027",
+        );
+
+        assert_eq!(
+            selectors(hex),
+            [(0x70A0_8231, 66), (0xA905_9CBB, 52), (0xDD62_ED3E, 38)]
+        );
+        assert!(solc_selectors(&ControlFlowGraph::new(&assembly(hex))).is_empty());
+    }
+
+    #[test]
+    fn dense() {
+        // 0: PUSH0 CALLDATALOAD PUSH1 0xe0 SHR
+        // 5: PUSH1 0x03 DUP2 MOD PUSH1 0x05 MUL PUSH2 0x004a ADD
+        // 16: PUSH1 0x05 SWAP1 PUSH1 0x1b CODECOPY
+        // 22: PUSH0 MLOAD DUP1 PUSH1 0xff AND DUP2 PUSH1 0x18 SHR DUP4 MUL PUSH1 0x18 SHR MOD
+        // 38: PUSH1 0x07 MUL SWAP1 PUSH1 0x08 SHR PUSH2 0xffff AND ADD
+        // 50: PUSH1 0x07 SWAP1 PUSH1 0x19 CODECOPY
+        // 56: PUSH0 MLOAD PUSH1 0x08 SHR PUSH2 0xffff AND JUMP
+        // 66: JUMPDEST STOP JUMPDEST STOP JUMPDEST STOP JUMPDEST STOP
+        // 74: headers 0x0000 0x0059 0x01, 0x0001 0x0060 0x02, 0x0000 0x006e 0x01
+        // 89: 0xdd62ed3e 0x0046 0x05
+        // 96: 0x70a08231 0x0044 0x05, 0xa9059cbb 0x0042 0x05
+        // 110: 0x095ea7b3 0x0048 0x05
+        let hex = concat!(
+            "0x5f3560e01c6003810660050261004a01600590601b395f518060ff168160181c830260",
+            "181c066007029060081c61ffff16016007906019395f5160081c61ffff16565b005b005b",
+            "005b00000000590100010060020000006e01dd62ed3e00460570a08231004405a9059cbb",
+            "004205095ea7b3004805",
+        );
+
+        assert_eq!(
+            selectors(hex),
+            [
+                (0x095E_A7B3, 72),
+                (0x70A0_8231, 68),
+                (0xA905_9CBB, 66),
+                (0xDD62_ED3E, 70),
+            ]
+        );
+    }
+
+    #[test]
+    fn same_mapping_as_solc() {
+        // 0: PUSH0 CALLDATALOAD PUSH1 0xe0 SHR DUP1 PUSH4 0xa9059cbb EQ PUSH1 0x10 JUMPI
+        // 15: STOP
+        // 16: JUMPDEST STOP
+        let hex = "0x5f3560e01c8063a9059cbb14601057005b00";
+        let cfg_assembly = assembly(hex);
+        let cfg = ControlFlowGraph::new(&cfg_assembly);
+
+        assert_eq!(
+            vyper_selectors(&cfg, hex.extract().unwrap().as_ref()),
+            solc_selectors(&cfg)
+        );
+    }
+
+    #[test]
+    fn tables_out_of_bounds() {
+        let code = [0x00, 0x19, 0x00];
+        let table = crate::dispatcher::Table {
+            offset: 0,
+            entries: 2,
+            stride: 2,
+        };
+        let entry = CopiedEntry { table, size: 2 };
+
+        assert_eq!(sparse_buckets(&code, entry), [0x19]);
+        assert!(dense_functions(&code, CopiedEntry { table, size: 5 }, 7).is_empty());
+    }
+}
@@ -0,0 +1,136 @@
+//! Graphviz DOT format.
+
+use std::fmt::{self, Write};
+
+use super::{
+    EdgeStyle, ExportOptions, GraphFormat, Line, block_id, block_label, clusters, function_label,
+    target_id, target_node,
+};
+use crate::blocks::BasicBlock;
+use crate::cfg::ControlFlowGraph;
+
+/// The DOT language of Graphviz.
+///
+/// Blocks are boxes with a left aligned instruction listing, functions are clusters:
+///
+/// ```text
+/// digraph cfg {
+///     node [shape=box, fontname="monospace"];
+///     b0 [label="block 0x0000\l00000: PUSH1 0x05\l00002: JUMPI\l"];
+///     b0 -> b3 [label="false", color="#c62828", style=solid];
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Dot;
+
+impl GraphFormat for Dot {
+    fn write_graph<W: Write>(
+        &self,
+        w: &mut W,
+        cfg: &ControlFlowGraph<'_>,
+        options: &ExportOptions<'_>,
+    ) -> fmt::Result {
+        writeln!(w, "digraph cfg {{")?;
+        writeln!(w, "    node [shape=box, fontname=\"monospace\"];")?;
+
+        match options.functions {
+            Some(functions) => {
+                let (clustered, rest) = clusters(cfg, functions);
+
+                for (function, blocks) in functions.iter().zip(clustered) {
+                    writeln!(w, "    subgraph cluster_{} {{", function.entry)?;
+                    writeln!(w, "        label=\"{}\";", function_label(function))?;
+                    for block in blocks {
+                        write_block(w, "        ", block)?;
+                    }
+                    writeln!(w, "    }}")?;
+                }
+
+                for block in rest {
+                    write_block(w, "    ", block)?;
+                }
+            }
+            None => {
+                for block in cfg.blocks() {
+                    write_block(w, "    ", block)?;
+                }
+            }
+        }
+
+        for (id, label) in cfg.edges().iter().filter_map(target_node) {
+            writeln!(w, "    {id} [label=\"{}\", shape=octagon];", escape(&label))?;
+        }
+
+        for edge in cfg.edges() {
+            let style = EdgeStyle::of(edge, &options.palette);
+            write!(w, "    {} -> {} [", block_id(edge.from), target_id(edge))?;
+            if let Some(label) = style.label {
+                write!(w, "label=\"{label}\", ")?;
+            }
+            let line = match style.line {
+                Line::Solid => "solid",
+                Line::Bold => "bold",
+                Line::Dashed => "dashed",
+                Line::Dotted => "dotted",
+            };
+            writeln!(w, "color=\"{}\", style={line}];", style.color)?;
+        }
+
+        writeln!(w, "}}")
+    }
+}
+
+/// Writes the node of `block`.
+fn write_block<W: Write>(w: &mut W, indent: &str, block: &BasicBlock<'_>) -> fmt::Result {
+    write!(w, "{indent}{} [label=\"", block_id(block.start()))?;
+    for line in block_label(block) {
+        write!(w, "{}\\l", escape(&line))?;
+    }
+    writeln!(w, "\"];")
+}
+
+/// Escapes `text` to be used inside a quoted DOT string.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{functions::recover_functions, tests::assembly};
+
+    #[test]
+    fn dot() {
+        // 0: PUSH1 0x05 JUMPI
+        // 3: CALLVALUE JUMP
+        // 5: JUMPDEST STOP
+        let assembly = assembly("0x60055734565b00");
+        let cfg = ControlFlowGraph::new(&assembly);
+
+        assert_eq!(
+            Dot.display(&cfg, &ExportOptions::default()).to_string(),
+            concat!(
+                "digraph cfg {\n",
+                "    node [shape=box, fontname=\"monospace\"];\n",
+                "    b0 [label=\"block 0x0000\\l00000: PUSH1 0x05\\l00002: JUMPI\\l\"];\n",
+                "    b3 [label=\"block 0x0003\\l00003: CALLVALUE\\l00004: JUMP\\l\"];\n",
+                "    b5 [label=\"block 0x0005\\l00005: JUMPDEST\\l00006: STOP\\l\"];\n",
+                "    unresolved3 [label=\"unresolved\", shape=octagon];\n",
+                "    b0 -> b3 [label=\"false\", color=\"#c62828\", style=solid];\n",
+                "    b0 -> b5 [label=\"true\", color=\"#2e7d32\", style=solid];\n",
+                "    b3 -> unresolved3 [color=\"#ef6c00\", style=dotted];\n",
+                "}\n",
+            )
+        );
+
+        let functions = recover_functions(&cfg);
+        let options = ExportOptions {
+            functions: Some(&functions),
+            ..ExportOptions::default()
+        };
+        let clustered = Dot.display(&cfg, &options).to_string();
+        assert!(
+            clustered.contains("    subgraph cluster_0 {\n        label=\"function 0x0000\";\n")
+        );
+    }
+}
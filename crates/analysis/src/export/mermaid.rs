@@ -0,0 +1,158 @@
+//! Mermaid flowchart format.
+
+use std::fmt::{self, Write};
+
+use super::{
+    EdgeStyle, ExportOptions, GraphFormat, Line, block_id, block_label, clusters, function_label,
+    target_id, target_node,
+};
+use crate::blocks::BasicBlock;
+use crate::cfg::ControlFlowGraph;
+
+/// Mermaid flowcharts, as rendered by GitHub and most Markdown viewers.
+///
+/// Blocks are rectangles, functions are subgraphs and edges are coloured with `linkStyle`:
+///
+/// ```text
+/// flowchart TD
+///     b0["block 0x0000<br>00000: PUSH1 0x06<br>00002: JUMPI"]
+///     b0 -->|false| b3
+///     linkStyle 0 stroke:#c62828
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Mermaid;
+
+impl GraphFormat for Mermaid {
+    fn write_graph<W: Write>(
+        &self,
+        w: &mut W,
+        cfg: &ControlFlowGraph<'_>,
+        options: &ExportOptions<'_>,
+    ) -> fmt::Result {
+        writeln!(w, "flowchart TD")?;
+
+        match options.functions {
+            Some(functions) => {
+                let (clustered, rest) = clusters(cfg, functions);
+
+                for (function, blocks) in functions.iter().zip(clustered) {
+                    writeln!(
+                        w,
+                        "    subgraph f{} [\"{}\"]",
+                        function.entry,
+                        function_label(function)
+                    )?;
+                    for block in blocks {
+                        write_block(w, "        ", block)?;
+                    }
+                    writeln!(w, "    end")?;
+                }
+
+                for block in rest {
+                    write_block(w, "    ", block)?;
+                }
+            }
+            None => {
+                for block in cfg.blocks() {
+                    write_block(w, "    ", block)?;
+                }
+            }
+        }
+
+        for (id, label) in cfg.edges().iter().filter_map(target_node) {
+            writeln!(w, "    {id}{{{{\"{}\"}}}}", escape(&label))?;
+        }
+
+        for edge in cfg.edges() {
+            let style = EdgeStyle::of(edge, &options.palette);
+            let arrow = match style.line {
+                Line::Solid => "-->",
+                Line::Bold => "==>",
+                Line::Dashed | Line::Dotted => "-.->",
+            };
+
+            write!(w, "    {} {arrow}", block_id(edge.from))?;
+            if let Some(label) = style.label {
+                write!(w, "|{label}|")?;
+            }
+            writeln!(w, " {}", target_id(edge))?;
+        }
+
+        for (index, edge) in cfg.edges().iter().enumerate() {
+            let style = EdgeStyle::of(edge, &options.palette);
+            write!(w, "    linkStyle {index} stroke:{}", style.color)?;
+            if style.line == Line::Dotted {
+                write!(w, ",stroke-dasharray:2")?;
+            }
+            writeln!(w)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes the node of `block`.
+fn write_block<W: Write>(w: &mut W, indent: &str, block: &BasicBlock<'_>) -> fmt::Result {
+    let lines: Vec<_> = block_label(block).iter().map(|line| escape(line)).collect();
+    writeln!(
+        w,
+        "{indent}{}[\"{}\"]",
+        block_id(block.start()),
+        lines.join("<br>")
+    )
+}
+
+/// Escapes `text` to be used inside a quoted Mermaid label.
+fn escape(text: &str) -> String {
+    text.replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cfg::Target, export::Palette, functions::recover_functions, tests::assembly};
+
+    #[test]
+    fn mermaid() {
+        // 0: PUSH1 0x06 JUMPI
+        // 3: PUSH1 0x08 JUMP
+        // 6: JUMPDEST JUMPDEST STOP
+        let assembly = assembly("0x6006576008565b5b00");
+        let cfg = ControlFlowGraph::new(&assembly);
+        assert!(matches!(cfg.edges()[2].target, Target::Invalid { .. }));
+
+        let functions = recover_functions(&cfg);
+        let options = ExportOptions {
+            functions: Some(&functions),
+            palette: Palette::MONOCHROME,
+        };
+        assert_eq!(
+            Mermaid.display(&cfg, &options).to_string(),
+            concat!(
+                "flowchart TD\n",
+                "    subgraph f0 [\"function 0x0000\"]\n",
+                "        b0[\"block 0x0000<br>00000: PUSH1 0x06<br>00002: JUMPI\"]\n",
+                "        b3[\"block 0x0003<br>00003: PUSH1 0x08<br>00005: JUMP\"]\n",
+                "        b6[\"block 0x0006<br>00006: JUMPDEST\"]\n",
+                "        b7[\"block 0x0007<br>00007: JUMPDEST<br>00008: STOP\"]\n",
+                "    end\n",
+                "    invalid3_8{{\"invalid 0x8: not a JUMPDEST\"}}\n",
+                "    b0 -->|false| b3\n",
+                "    b0 -->|true| b6\n",
+                "    b3 -.-> invalid3_8\n",
+                "    b6 -.-> b7\n",
+                "    linkStyle 0 stroke:#000000\n",
+                "    linkStyle 1 stroke:#000000\n",
+                "    linkStyle 2 stroke:#000000,stroke-dasharray:2\n",
+                "    linkStyle 3 stroke:#000000\n",
+            )
+        );
+    }
+
+    #[test]
+    fn escaping() {
+        assert_eq!(escape("\"<a>\""), "#quot;#lt;a#gt;#quot;");
+    }
+}
@@ -0,0 +1,243 @@
+//! Export of control-flow graphs to graph description languages.
+//!
+//! Every [`GraphFormat`] writes a [`ControlFlowGraph`] with one node per basic block, labelled with
+//! the offset of the block and its instructions in [`Geth`] syntax. Edges are coloured and styled
+//! by their [kind](EdgeKind), jumps that are unresolved or invalid lead to a node of their own.
+//! Blocks can be clustered by the [function](Function) they belong to.
+//!
+//! ```
+//! # use oculars_analysis::{cfg::ControlFlowGraph, export::{Dot, ExportOptions, GraphFormat}};
+//! # use dasm::disassembler::Disassembler;
+//! # use upgrades::forks::shanghai::Shanghai;
+//! // PUSH1 0x05 JUMPI PUSH0 STOP JUMPDEST STOP
+//! let assembly = Disassembler::<Shanghai>::new().disassemble_hex("0x6005575f005b00").unwrap();
+//! let cfg = ControlFlowGraph::new(&assembly);
+//!
+//! let dot = Dot.display(&cfg, &ExportOptions::default()).to_string();
+//! assert!(dot.starts_with("digraph cfg {"));
+//! assert!(dot.contains("b0 -> b5 [label=\"true\""));
+//! ```
+
+mod dot;
+mod mermaid;
+
+use dasm::syntax::{Geth, Syntax};
+use std::fmt::{self, Display, Write};
+
+use crate::blocks::BasicBlock;
+use crate::cfg::{ControlFlowGraph, Edge, EdgeKind, Target};
+use crate::functions::Function;
+
+pub use dot::Dot;
+pub use mermaid::Mermaid;
+
+/// A language that describes graphs.
+pub trait GraphFormat {
+    /// Writes a control-flow graph.
+    ///
+    /// # Errors
+    /// Returns an error if writing to `w` fails.
+    fn write_graph<W: Write>(
+        &self,
+        w: &mut W,
+        cfg: &ControlFlowGraph<'_>,
+        options: &ExportOptions<'_>,
+    ) -> fmt::Result;
+
+    /// Returns a value that displays a control-flow graph in this format.
+    fn display<'g>(
+        &'g self,
+        cfg: &'g ControlFlowGraph<'g>,
+        options: &'g ExportOptions<'g>,
+    ) -> GraphDisplay<'g, Self>
+    where
+        Self: Sized,
+    {
+        GraphDisplay {
+            format: self,
+            cfg,
+            options,
+        }
+    }
+}
+
+/// Displays a [`ControlFlowGraph`] in a [`GraphFormat`].
+#[derive(Debug, Clone, Copy)]
+pub struct GraphDisplay<'g, F> {
+    /// Format of the graph.
+    format: &'g F,
+    /// The displayed graph.
+    cfg: &'g ControlFlowGraph<'g>,
+    /// How the graph is displayed.
+    options: &'g ExportOptions<'g>,
+}
+
+impl<F: GraphFormat> Display for GraphDisplay<'_, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format.write_graph(f, self.cfg, self.options)
+    }
+}
+
+/// Options of a graph export.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExportOptions<'f> {
+    /// Functions to cluster the blocks by. Blocks that belong to no function are not clustered.
+    pub functions: Option<&'f [Function]>,
+
+    /// Colours of the edges.
+    pub palette: Palette,
+}
+
+/// Colours of the edges of an exported graph, as `#rrggbb` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    /// Colour of [fall-through](EdgeKind::FallThrough) edges.
+    pub fall_through: &'static str,
+    /// Colour of edges taken when a `JUMPI` jumps.
+    pub conditional_true: &'static str,
+    /// Colour of edges taken when a `JUMPI` does not jump.
+    pub conditional_false: &'static str,
+    /// Colour of unconditional jumps.
+    pub jump: &'static str,
+    /// Colour of jumps whose target is unresolved or invalid.
+    pub unresolved: &'static str,
+}
+
+impl Palette {
+    /// Tells edge kinds apart by colour.
+    pub const COLORED: Self = Self {
+        fall_through: "#616161",
+        conditional_true: "#2e7d32",
+        conditional_false: "#c62828",
+        jump: "#1565c0",
+        unresolved: "#ef6c00",
+    };
+
+    /// Draws every edge in black, edge kinds are only told apart by their style.
+    pub const MONOCHROME: Self = Self {
+        fall_through: "#000000",
+        conditional_true: "#000000",
+        conditional_false: "#000000",
+        jump: "#000000",
+        unresolved: "#000000",
+    };
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::COLORED
+    }
+}
+
+/// How the line of an edge is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Line {
+    /// A plain line.
+    Solid,
+    /// A thick line.
+    Bold,
+    /// A dashed line.
+    Dashed,
+    /// A dotted line.
+    Dotted,
+}
+
+/// How an edge is drawn.
+struct EdgeStyle {
+    /// Text written next to the edge.
+    label: Option<&'static str>,
+    /// Colour of the edge.
+    color: &'static str,
+    /// How the line is drawn.
+    line: Line,
+}
+
+impl EdgeStyle {
+    /// Returns the style of `edge`.
+    fn of(edge: &Edge, palette: &Palette) -> Self {
+        let (label, color, line) = match (edge.target, edge.kind) {
+            (Target::Unresolved | Target::Invalid { .. }, _) => {
+                (None, palette.unresolved, Line::Dotted)
+            }
+            (_, EdgeKind::FallThrough) => (None, palette.fall_through, Line::Dashed),
+            (_, EdgeKind::ConditionalTrue) => (Some("true"), palette.conditional_true, Line::Solid),
+            (_, EdgeKind::ConditionalFalse) => {
+                (Some("false"), palette.conditional_false, Line::Solid)
+            }
+            (_, EdgeKind::Jump) => (None, palette.jump, Line::Bold),
+        };
+
+        Self { label, color, line }
+    }
+}
+
+/// Returns the identifier of the node of the block starting at `start`.
+fn block_id(start: usize) -> String {
+    format!("b{start}")
+}
+
+/// Returns the identifier and label of the node an unresolved or invalid jump leads to, or
+/// [`None`] if the edge leads to a block.
+fn target_node(edge: &Edge) -> Option<(String, String)> {
+    match edge.target {
+        Target::Block(_) => None,
+        Target::Unresolved => Some((format!("unresolved{}", edge.from), "unresolved".to_owned())),
+        Target::Invalid { target, reason } => Some((
+            format!("invalid{}_{target:x}", edge.from),
+            format!("invalid {target:#x}: {reason}"),
+        )),
+    }
+}
+
+/// Returns the identifier of the node `edge` leads to.
+fn target_id(edge: &Edge) -> String {
+    match edge.target {
+        Target::Block(start) => block_id(start),
+        _ => target_node(edge).map(|(id, _)| id).unwrap_or_default(),
+    }
+}
+
+/// Returns the lines of the label of `block`: its offset followed by its instructions.
+fn block_label(block: &BasicBlock<'_>) -> Vec<String> {
+    let mut lines = Vec::with_capacity(block.instructions.len() + 1);
+    lines.push(format!("block {:#06x}", block.start()));
+
+    for instruction in block.instructions {
+        let mut line = String::new();
+        Geth.write_instruction(&mut line, instruction)
+            .expect("writing to a string does not fail");
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Returns the label of the cluster of `function`.
+fn function_label(function: &Function) -> String {
+    format!("function {:#06x}", function.entry)
+}
+
+/// Groups the blocks of `cfg` by the function they belong to.
+///
+/// Returns one group per function, in the order of `functions`, followed by the group of blocks
+/// without a function.
+fn clusters<'c, 'a>(
+    cfg: &'c ControlFlowGraph<'a>,
+    functions: &[Function],
+) -> (Vec<Vec<&'c BasicBlock<'a>>>, Vec<&'c BasicBlock<'a>>) {
+    let mut clustered = vec![Vec::new(); functions.len()];
+    let mut rest = Vec::new();
+
+    for (block, function) in cfg
+        .blocks()
+        .iter()
+        .zip(crate::functions::function_of_blocks(cfg, functions))
+    {
+        match function {
+            Some(index) => clustered[index].push(block),
+            None => rest.push(block),
+        }
+    }
+
+    (clustered, rest)
+}
@@ -0,0 +1,206 @@
+//! Recovery of internal functions.
+//!
+//! Solidity and Vyper call an internal function by pushing a return address, pushing the
+//! arguments and jumping to the function with a statically pushed target:
+//!
+//! ```text
+//! PUSH2 <return address> ... PUSH2 <function> JUMP
+//! ```
+//!
+//! The targets of such jumps are function entries, the entry of the code is the entry of the
+//! outermost function. The body of a function are the blocks reachable from its entry without
+//! entering another function and without following indirect jumps, which return to the caller. A
+//! call continues at its return address instead of entering the called function.
+//!
+//! ```
+//! # use oculars_analysis::{cfg::ControlFlowGraph, functions::recover_functions};
+//! # use dasm::disassembler::Disassembler;
+//! # use upgrades::forks::shanghai::Shanghai;
+//! // 0: PUSH1 0x06 PUSH1 0x08 JUMP
+//! // 5: STOP
+//! // 6: JUMPDEST STOP
+//! // 8: JUMPDEST JUMP
+//! let assembly = Disassembler::<Shanghai>::new()
+//!     .disassemble_hex("0x6006600856005b005b56")
+//!     .unwrap();
+//! let functions = recover_functions(&ControlFlowGraph::with_stack_analysis(&assembly));
+//!
+//! assert_eq!(functions.len(), 2);
+//! assert_eq!(functions[0].blocks, [0, 6]);
+//! assert_eq!(functions[1].entry, 8);
+//! assert_eq!(functions[1].blocks, [8]);
+//! ```
+
+use asm2::{AssemblyInstruction, Mnemonic, U256};
+use dasm::assembly::PositionedInstruction;
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+use crate::blocks::BasicBlock;
+use crate::cfg::ControlFlowGraph;
+
+/// A function recovered from a [`ControlFlowGraph`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Function {
+    /// Start offset of the block the function is entered at.
+    pub entry: usize,
+
+    /// Start offsets of the blocks of the function, ordered by offset. Starts with the entry.
+    pub blocks: Vec<usize>,
+}
+
+/// A call of an internal function.
+struct Call {
+    /// Start offset of the called function.
+    function: usize,
+    /// Start offset of the block execution continues at after the function returns.
+    return_to: usize,
+}
+
+/// Returns the call ending `block`, if the block ends with a call.
+fn call(cfg: &ControlFlowGraph<'_>, block: &BasicBlock<'_>) -> Option<Call> {
+    let [pushes @ .., push, jump] = block.instructions else {
+        return None;
+    };
+
+    if jump.instruction.opcode() != Mnemonic::JUMP || !push.instruction.is_push() {
+        return None;
+    }
+
+    let block_at = |instruction: &PositionedInstruction| {
+        U256::from_be_slice(instruction.instruction.immediate_bytes())
+            .and_then(|target| cfg.resolve_target(target).block())
+    };
+
+    Some(Call {
+        function: block_at(push)?,
+        return_to: pushes
+            .iter()
+            .rev()
+            .filter(|instruction| instruction.instruction.is_push())
+            .find_map(block_at)?,
+    })
+}
+
+/// Returns the blocks execution continues at after `block`, within the same function.
+fn successors(cfg: &ControlFlowGraph<'_>, block: &BasicBlock<'_>) -> Vec<usize> {
+    if let Some(call) = call(cfg, block) {
+        return vec![call.return_to];
+    }
+
+    let [.., before_jump, _] = block.instructions else {
+        return block.fall_through.into_iter().collect();
+    };
+    let static_jump = before_jump.instruction.is_push();
+
+    cfg.successors(block.start())
+        .filter(|edge| static_jump || !edge.kind.is_jump())
+        .filter_map(|edge| edge.target.block())
+        .collect()
+}
+
+/// Recovers the functions of a control-flow graph, ordered by their entry.
+///
+/// Every block belongs to at most one function. A block reachable from several functions belongs
+/// to the one with the lowest entry.
+#[must_use]
+pub fn recover_functions(cfg: &ControlFlowGraph<'_>) -> Vec<Function> {
+    let Some(first) = cfg.blocks().first() else {
+        return Vec::new();
+    };
+
+    let entries: BTreeSet<usize> = core::iter::once(first.start())
+        .chain(
+            cfg.blocks()
+                .iter()
+                .filter_map(|block| call(cfg, block))
+                .map(|call| call.function),
+        )
+        .collect();
+
+    let mut claimed = HashSet::new();
+    let mut functions = Vec::with_capacity(entries.len());
+
+    for &entry in &entries {
+        let mut blocks = Vec::new();
+        let mut queue = VecDeque::from([entry]);
+        claimed.insert(entry);
+
+        while let Some(start) = queue.pop_front() {
+            blocks.push(start);
+
+            let Some(block) = cfg.block(start) else {
+                continue;
+            };
+            for next in successors(cfg, block) {
+                if !entries.contains(&next) && claimed.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        blocks.sort_unstable();
+        functions.push(Function { entry, blocks });
+    }
+
+    functions
+}
+
+/// Returns the function each block belongs to, as an index into `functions`, if any.
+///
+/// The returned indices are ordered like [`ControlFlowGraph::blocks`].
+#[must_use]
+pub fn function_of_blocks(
+    cfg: &ControlFlowGraph<'_>,
+    functions: &[Function],
+) -> Vec<Option<usize>> {
+    cfg.blocks()
+        .iter()
+        .map(|block| {
+            functions
+                .iter()
+                .position(|function| function.blocks.binary_search(&block.start()).is_ok())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assembly;
+    use dasm::assembly::Assembly;
+
+    #[test]
+    fn internal_functions() {
+        // 0: PUSH1 0x07 PUSH1 0x01 PUSH1 0x11 JUMP
+        // 7: JUMPDEST PUSH1 0x0f PUSH1 0x02 PUSH1 0x11 JUMP
+        // 15: JUMPDEST STOP
+        // 17: JUMPDEST DUP1 ADD SWAP1 JUMP
+        let assembly = assembly("0x600760016011565b600f60026011565b005b80019056");
+        let cfg = ControlFlowGraph::with_stack_analysis(&assembly);
+        let functions = recover_functions(&cfg);
+
+        assert_eq!(
+            functions,
+            [
+                Function {
+                    entry: 0,
+                    blocks: vec![0, 7, 15],
+                },
+                Function {
+                    entry: 17,
+                    blocks: vec![17],
+                },
+            ]
+        );
+        assert_eq!(
+            function_of_blocks(&cfg, &functions),
+            [Some(0), Some(0), Some(0), Some(1)]
+        );
+    }
+
+    #[test]
+    fn no_functions_without_code() {
+        let assembly = Assembly::default();
+        assert!(recover_functions(&ControlFlowGraph::new(&assembly)).is_empty());
+    }
+}
@@ -0,0 +1,22 @@
+//! EVM bytecode analysis.
+
+pub mod blocks;
+pub mod cfg;
+pub mod dispatcher;
+pub mod export;
+pub mod functions;
+pub mod signatures;
+pub mod stack;
+
+#[cfg(test)]
+mod tests {
+    use dasm::{assembly::Assembly, disassembler::Disassembler};
+    use upgrades::forks::shanghai::Shanghai;
+
+    /// Disassembles hex encoded bytecode.
+    pub fn assembly(hex: &str) -> Assembly {
+        Disassembler::<Shanghai>::new()
+            .disassemble_hex(hex)
+            .unwrap()
+    }
+}
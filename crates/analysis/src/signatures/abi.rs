@@ -0,0 +1,141 @@
+//! Signatures of contract ABIs.
+
+use serde_json::Value;
+
+use super::{
+    Hash, Origin, Signature, SignatureDatabase, SignatureError, event_topic, function_selector,
+};
+
+/// Returns the canonical type of an ABI parameter, with tuples expanded into their components.
+fn canonical_type(parameter: &Value) -> Result<String, SignatureError> {
+    let Some(kind) = parameter.get("type").and_then(Value::as_str) else {
+        return Err(SignatureError::Abi("parameter without type".to_string()));
+    };
+
+    let Some(dimensions) = kind.strip_prefix("tuple") else {
+        return Ok(kind.to_string());
+    };
+
+    Ok(format!(
+        "{}{dimensions}",
+        canonical_parameters(parameter.get("components"))?
+    ))
+}
+
+/// Returns the parenthesized canonical types of ABI parameters.
+fn canonical_parameters(parameters: Option<&Value>) -> Result<String, SignatureError> {
+    let types = match parameters {
+        Some(Value::Array(parameters)) => parameters
+            .iter()
+            .map(canonical_type)
+            .collect::<Result<Vec<_>, _>>()?,
+        None => Vec::new(),
+        Some(_) => {
+            return Err(SignatureError::Abi(
+                "parameters are not an array".to_string(),
+            ));
+        }
+    };
+
+    Ok(format!("({})", types.join(",")))
+}
+
+/// Adds the signatures of the functions, errors and events of a JSON encoded ABI to `database`.
+pub(super) fn read(database: &mut SignatureDatabase, abi: &str) -> Result<(), SignatureError> {
+    let mut abi: Value = serde_json::from_str(abi)?;
+    if let Some(artifact) = abi.get_mut("abi") {
+        abi = artifact.take();
+    }
+    let Value::Array(items) = abi else {
+        return Err(SignatureError::Abi(
+            "expected an array of items".to_string(),
+        ));
+    };
+
+    for item in &items {
+        // Functions may omit their type.
+        let kind = item
+            .get("type")
+            .and_then(Value::as_str)
+            .unwrap_or("function");
+        if !matches!(kind, "function" | "error" | "event")
+            || item.get("anonymous").and_then(Value::as_bool) == Some(true)
+        {
+            continue;
+        }
+
+        let Some(name) = item.get("name").and_then(Value::as_str) else {
+            return Err(SignatureError::Abi(format!("{kind} without name")));
+        };
+        let text = format!("{name}{}", canonical_parameters(item.get("inputs"))?);
+        let hash = if kind == "event" {
+            Hash::Topic(event_topic(&text))
+        } else {
+            Hash::Selector(function_selector(&text))
+        };
+
+        database.insert(hash, Signature::new(&text, Origin::Abi));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn abi() {
+        let abi = r#"{"abi": [
+            {"type": "constructor", "inputs": []},
+            {"name": "balanceOf", "inputs": [{"name": "owner", "type": "address"}]},
+            {"type": "function", "name": "execute", "inputs": [
+                {"type": "tuple[]", "components": [
+                    {"type": "address"},
+                    {"type": "tuple", "components": [{"type": "uint8"}, {"type": "bytes"}]}
+                ]},
+                {"type": "uint256[2]"}
+            ]},
+            {"type": "error", "name": "Unauthorized", "inputs": []},
+            {"type": "event", "name": "Transfer", "inputs": [
+                {"type": "address", "indexed": true},
+                {"type": "address", "indexed": true},
+                {"type": "uint256", "indexed": false}
+            ]},
+            {"type": "event", "name": "Hidden", "anonymous": true, "inputs": []}
+        ]}"#;
+        let mut database = SignatureDatabase::new();
+        read(&mut database, abi).unwrap();
+
+        let text = |selector| database.selector(selector)[0].text.as_str();
+        assert_eq!(text([0x70, 0xa0, 0x82, 0x31]), "balanceOf(address)");
+        assert_eq!(
+            text(function_selector(
+                "execute((address,(uint8,bytes))[],uint256[2])"
+            )),
+            "execute((address,(uint8,bytes))[],uint256[2])"
+        );
+        assert_eq!(text([0x82, 0xb4, 0x29, 0x00]), "Unauthorized()");
+
+        let topic = event_topic("Transfer(address,address,uint256)");
+        assert_eq!(topic[..4], [0xdd, 0xf2, 0x52, 0xad]);
+        assert_eq!(database.topic(&topic)[0].origin, Origin::Abi);
+        assert_eq!(database.len(), 4);
+    }
+
+    #[test]
+    fn malformed() {
+        let mut database = SignatureDatabase::new();
+        for abi in [
+            r#"{"type": "function"}"#,
+            r#"[{"type": "function", "inputs": []}]"#,
+            r#"[{"type": "function", "name": "f", "inputs": [{"name": "a"}]}]"#,
+            r#"[{"type": "function", "name": "f", "inputs": {}}]"#,
+        ] {
+            assert!(matches!(
+                read(&mut database, abi),
+                Err(SignatureError::Abi(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! Compact binary signature indexes.
+//!
+//! An index starts with [`MAGIC`], followed by one record per selector or topic:
+//!
+//! ```text
+//! hash length (1 byte, 4 or 32) | hash | candidate count (varint) | candidates
+//! ```
+//!
+//! Every candidate is stored in rank order as:
+//!
+//! ```text
+//! origin (1 byte, 0 for ABIs, 1 for databases) | id + 1, 0 without id (varint)
+//!     | text length (varint) | UTF-8 text
+//! ```
+//!
+//! Varints are unsigned LEB128. Hashes are not checked against their candidates when an index is
+//! read.
+
+use super::{Hash, Origin, Signature, SignatureDatabase, SignatureError};
+
+/// Magic number and version every index starts with.
+pub(super) const MAGIC: &[u8] = b"OCSIG\x01";
+
+/// Appends `value` to `out` as a varint.
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "only the lowest 7 bits of the value are pushed at once"
+)]
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value & 0x7f) as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Appends the record of a hash to `out`.
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "hashes are 4 or 32 bytes long"
+)]
+fn write_record(out: &mut Vec<u8>, hash: &[u8], candidates: &[Signature]) {
+    out.push(hash.len() as u8);
+    out.extend_from_slice(hash);
+    write_varint(out, candidates.len() as u64);
+
+    for candidate in candidates {
+        out.push(match candidate.origin {
+            Origin::Abi => 0,
+            Origin::Database => 1,
+        });
+        write_varint(out, candidate.id.map_or(0, |id| id.saturating_add(1)));
+        write_varint(out, candidate.text.len() as u64);
+        out.extend_from_slice(candidate.text.as_bytes());
+    }
+}
+
+/// Writes `database` as an index.
+pub(super) fn write(database: &SignatureDatabase) -> Vec<u8> {
+    let mut out = MAGIC.to_vec();
+
+    for (selector, candidates) in &database.selectors {
+        write_record(&mut out, selector, candidates);
+    }
+    for (topic, candidates) in &database.topics {
+        write_record(&mut out, topic, candidates);
+    }
+
+    out
+}
+
+/// Reader of the records of an index.
+struct Reader<'d> {
+    /// The unread part of the index.
+    data: &'d [u8],
+}
+
+impl<'d> Reader<'d> {
+    /// Reads the next `len` bytes.
+    fn bytes(&mut self, len: usize) -> Result<&'d [u8], SignatureError> {
+        if len > self.data.len() {
+            return Err(SignatureError::Binary("unexpected end of index"));
+        }
+        let (bytes, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(bytes)
+    }
+
+    /// Reads the next byte.
+    fn byte(&mut self) -> Result<u8, SignatureError> {
+        self.bytes(1).map(|bytes| bytes[0])
+    }
+
+    /// Reads the next varint.
+    fn varint(&mut self) -> Result<u64, SignatureError> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(SignatureError::Binary("varint overflows 64 bits"))
+    }
+
+    /// Reads the next varint as a length.
+    fn len(&mut self) -> Result<usize, SignatureError> {
+        usize::try_from(self.varint()?).map_err(|_| SignatureError::Binary("length overflows"))
+    }
+}
+
+/// Reads the records of an index, without its magic number, into `database`.
+pub(super) fn read(database: &mut SignatureDatabase, index: &[u8]) -> Result<(), SignatureError> {
+    let mut reader = Reader { data: index };
+
+    while !reader.data.is_empty() {
+        let hash = match reader.byte()? {
+            4 => Hash::Selector(reader.bytes(4)?.try_into().unwrap_or_default()),
+            32 => Hash::Topic(reader.bytes(32)?.try_into().unwrap_or_default()),
+            _ => return Err(SignatureError::Binary("hash is not a selector or topic")),
+        };
+
+        for _ in 0..reader.len()? {
+            let origin = match reader.byte()? {
+                0 => Origin::Abi,
+                1 => Origin::Database,
+                _ => return Err(SignatureError::Binary("unknown origin")),
+            };
+            let id = reader.varint()?.checked_sub(1);
+            let len = reader.len()?;
+            let text = std::str::from_utf8(reader.bytes(len)?)
+                .map_err(|_| SignatureError::Binary("signature is not UTF-8 encoded"))?;
+
+            database.insert(
+                hash,
+                Signature {
+                    id,
+                    ..Signature::new(text, origin)
+                },
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signatures::tests::TRANSFER;
+
+    #[test]
+    fn round_trip() {
+        let mut database = SignatureDatabase::new();
+        database.insert_selector(TRANSFER, Signature::new("a()", Origin::Abi));
+        database.insert_selector(
+            TRANSFER,
+            Signature {
+                id: Some(300),
+                ..Signature::new("b()", Origin::Database)
+            },
+        );
+        database.insert_topic([7; 32], Signature::new("E(uint256)", Origin::Database));
+
+        let index = database.to_binary();
+        assert!(index.starts_with(MAGIC));
+        assert_eq!(SignatureDatabase::parse(&index).unwrap(), database);
+    }
+
+    #[test]
+    fn varints() {
+        for value in [0, 1, 0x7f, 0x80, 300, u64::MAX] {
+            let mut out = Vec::new();
+            write_varint(&mut out, value);
+            assert_eq!(Reader { data: &out }.varint().unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn malformed() {
+        let parse = |record: &[u8]| SignatureDatabase::parse(&[MAGIC, record].concat());
+
+        assert!(parse(&[]).unwrap().is_empty());
+        assert!(matches!(parse(&[5]), Err(SignatureError::Binary(_))));
+        assert!(matches!(
+            parse(&[4, 0xa9, 0x05, 0x9c]),
+            Err(SignatureError::Binary(_))
+        ));
+        assert!(matches!(
+            parse(&[4, 0xa9, 0x05, 0x9c, 0xbb, 1, 2, 0, 0]),
+            Err(SignatureError::Binary(_))
+        ));
+        assert!(matches!(
+            parse(&[4, 0xa9, 0x05, 0x9c, 0xbb, 1, 0, 0, 3, b'a', b'(']),
+            Err(SignatureError::Binary(_))
+        ));
+    }
+}
@@ -0,0 +1,304 @@
+//! JSON and CSV dumps of signature databases.
+
+use serde_json::Value;
+
+use super::{Hash, Origin, Signature, SignatureDatabase, SignatureError};
+
+/// Adds the entry of a dump to `database`, unless `hash` is not computed from `text`.
+fn add(database: &mut SignatureDatabase, hash: Hash, text: &str, id: Option<u64>) {
+    if hash.matches(text) {
+        database.insert(
+            hash,
+            Signature {
+                id,
+                ..Signature::new(text, Origin::Database)
+            },
+        );
+    }
+}
+
+/// Reads a JSON dump into `database`.
+///
+/// The dump is either a page of the 4byte.directory API, an array of the `results` of such pages
+/// or an object mapping hashes to a signature or an array of signatures.
+pub(super) fn read_json(
+    database: &mut SignatureDatabase,
+    json: &str,
+) -> Result<(), SignatureError> {
+    let layout = |reason: &str| SignatureError::JsonLayout(reason.to_string());
+
+    match serde_json::from_str(json)? {
+        Value::Object(mut page) if page.contains_key("results") => {
+            let Some(Value::Array(results)) = page.remove("results") else {
+                return Err(layout("`results` is not an array"));
+            };
+            read_results(database, &results)
+        }
+        Value::Array(results) => read_results(database, &results),
+        Value::Object(hashes) => {
+            for (hash, signatures) in &hashes {
+                let parsed = Hash::parse(hash)
+                    .ok_or_else(|| layout(&format!("`{hash}` is not a selector or topic")))?;
+
+                match signatures {
+                    Value::String(text) => add(database, parsed, text, None),
+                    Value::Array(texts) => {
+                        for text in texts {
+                            let Value::String(text) = text else {
+                                return Err(layout(&format!(
+                                    "signature of `{hash}` is not a string"
+                                )));
+                            };
+                            add(database, parsed, text, None);
+                        }
+                    }
+                    _ => return Err(layout(&format!("signature of `{hash}` is not a string"))),
+                }
+            }
+            Ok(())
+        }
+        _ => Err(layout("expected an object or an array")),
+    }
+}
+
+/// Reads the results of 4byte.directory API pages, objects with `hex_signature`,
+/// `text_signature` and optionally `id` fields.
+fn read_results(database: &mut SignatureDatabase, results: &[Value]) -> Result<(), SignatureError> {
+    for (index, result) in results.iter().enumerate() {
+        let (Some(hex), Some(text)) = (
+            result.get("hex_signature").and_then(Value::as_str),
+            result.get("text_signature").and_then(Value::as_str),
+        ) else {
+            return Err(SignatureError::JsonLayout(format!(
+                "result {index} lacks `hex_signature` or `text_signature`"
+            )));
+        };
+        let hash = Hash::parse(hex).ok_or_else(|| {
+            SignatureError::JsonLayout(format!("`{hex}` is not a selector or topic"))
+        })?;
+
+        add(
+            database,
+            hash,
+            text,
+            result.get("id").and_then(Value::as_u64),
+        );
+    }
+
+    Ok(())
+}
+
+/// Splits a CSV line into its fields, unquoting quoted fields.
+fn fields(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        let Some(field) = fields.last_mut() else {
+            break;
+        };
+        match char {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            char => field.push(char),
+        }
+    }
+
+    fields
+}
+
+/// Reads a CSV dump into `database`.
+///
+/// If the first line names the columns, the `hex_signature`, `text_signature` and optional `id`
+/// columns are read. Otherwise every line is a hash followed by its signature, which may contain
+/// unquoted commas. Empty lines and lines starting with `#` are skipped.
+pub(super) fn read_csv(database: &mut SignatureDatabase, csv: &str) -> Result<(), SignatureError> {
+    let mut lines = csv
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .peekable();
+
+    let header = lines.peek().map(|(_, line)| fields(line));
+    let column = |name: &str| {
+        header
+            .as_ref()
+            .and_then(|header| header.iter().position(|column| column.trim() == name))
+    };
+    let columns = column("hex_signature").zip(column("text_signature"));
+    let id_column = column("id");
+    if columns.is_some() {
+        lines.next();
+    }
+
+    for (line, text) in lines {
+        let error = |reason: &str| SignatureError::Csv {
+            line,
+            reason: reason.to_string(),
+        };
+        let fields = fields(text);
+
+        let (hex, text, id) = if let Some((hex, text)) = columns {
+            let (Some(hex), Some(text)) = (fields.get(hex), fields.get(text)) else {
+                return Err(error("missing column"));
+            };
+            let id = match id_column.and_then(|id| fields.get(id)) {
+                Some(id) => Some(id.trim().parse().map_err(|_| error("invalid id"))?),
+                None => None,
+            };
+            (hex.clone(), text.clone(), id)
+        } else {
+            let Some((hex, signature)) = fields.split_first() else {
+                return Err(error("missing signature"));
+            };
+            if signature.is_empty() {
+                return Err(error("missing signature"));
+            }
+            (hex.clone(), signature.join(","), None)
+        };
+
+        let hash =
+            Hash::parse(hex.trim()).ok_or_else(|| error("hash is not a selector or topic"))?;
+        add(database, hash, text.trim(), id);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signatures::tests::TRANSFER;
+
+    /// Topic of `Transfer(address,address,uint256)`.
+    const TRANSFER_EVENT: &str =
+        "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+
+    /// Returns the texts of the candidates of the `transfer(address,uint256)` selector.
+    fn transfer_candidates(database: &SignatureDatabase) -> Vec<&str> {
+        database
+            .selector(TRANSFER)
+            .iter()
+            .map(|signature| signature.text.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn json_page() {
+        let json = r#"{
+            "count": 3,
+            "next": null,
+            "results": [
+                {"id": 161159, "text_signature": "many_msg_babbage(bytes1)", "hex_signature": "0xa9059cbb"},
+                {"id": 145, "text_signature": "transfer(address,uint256)", "hex_signature": "0xa9059cbb"},
+                {"id": 1, "text_signature": "forged(uint256)", "hex_signature": "0xa9059cbb"}
+            ]
+        }"#;
+        let mut database = SignatureDatabase::new();
+        read_json(&mut database, json).unwrap();
+
+        assert_eq!(
+            transfer_candidates(&database),
+            ["transfer(address,uint256)", "many_msg_babbage(bytes1)"]
+        );
+        assert_eq!(database.selector(TRANSFER)[0].id, Some(145));
+    }
+
+    #[test]
+    fn json_hashes() {
+        let json = format!(
+            r#"{{
+                "0xa9059cbb": ["transfer(address,uint256)", "many_msg_babbage(bytes1)"],
+                "{TRANSFER_EVENT}": "Transfer(address,address,uint256)"
+            }}"#
+        );
+        let mut database = SignatureDatabase::new();
+        read_json(&mut database, &json).unwrap();
+
+        assert_eq!(
+            transfer_candidates(&database),
+            ["transfer(address,uint256)", "many_msg_babbage(bytes1)"]
+        );
+        let Some(Hash::Topic(topic)) = Hash::parse(TRANSFER_EVENT) else {
+            panic!("invalid topic");
+        };
+        assert_eq!(
+            database.topic(&topic)[0].text,
+            "Transfer(address,address,uint256)"
+        );
+
+        assert!(matches!(
+            read_json(&mut database, r#"{"0xa9": "a()"}"#),
+            Err(SignatureError::JsonLayout(_))
+        ));
+        assert!(matches!(
+            read_json(&mut database, r#"[{"id": 1}]"#),
+            Err(SignatureError::JsonLayout(_))
+        ));
+        assert!(matches!(
+            read_json(&mut database, "[1,"),
+            Err(SignatureError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn csv_with_header() {
+        let csv = concat!(
+            "id,created_at,text_signature,hex_signature,bytes_signature\n",
+            "161159,2018-05-01,many_msg_babbage(bytes1),0xa9059cbb,\"©\x05œ»\"\n",
+            "145,2016-11-28,\"transfer(address,uint256)\",0xa9059cbb,\"©\x05œ»\"\n",
+        );
+        let mut database = SignatureDatabase::new();
+        read_csv(&mut database, csv).unwrap();
+
+        assert_eq!(
+            transfer_candidates(&database),
+            ["transfer(address,uint256)", "many_msg_babbage(bytes1)"]
+        );
+
+        let error = read_csv(
+            &mut database,
+            "id,text_signature,hex_signature\nx,a(),0x00000000",
+        );
+        assert!(matches!(error, Err(SignatureError::Csv { line: 2, .. })));
+    }
+
+    #[test]
+    fn csv_without_header() {
+        let csv = format!(
+            concat!(
+                "# selectors\n",
+                "0xa9059cbb,transfer(address,uint256)\n",
+                "\n",
+                "a9059cbb,\"many_msg_babbage(bytes1)\"\n",
+                "{},Transfer(address,address,uint256)\n",
+            ),
+            TRANSFER_EVENT
+        );
+        let mut database = SignatureDatabase::new();
+        read_csv(&mut database, &csv).unwrap();
+
+        assert_eq!(
+            transfer_candidates(&database),
+            ["transfer(address,uint256)", "many_msg_babbage(bytes1)"]
+        );
+        assert_eq!(database.len(), 2);
+
+        let error = read_csv(&mut database, "0xa9059cbb,a()\n0xa9059c,b()");
+        assert!(matches!(error, Err(SignatureError::Csv { line: 2, .. })));
+        let error = read_csv(&mut database, "0xa9059cbb");
+        assert!(matches!(error, Err(SignatureError::Csv { line: 1, .. })));
+    }
+
+    #[test]
+    fn quoted_fields() {
+        assert_eq!(fields(r#"a,"b,c","d""e""#), ["a", "b,c", "d\"e"]);
+        assert_eq!(fields(""), [""]);
+    }
+}
@@ -0,0 +1,382 @@
+//! Resolution of function selectors and event topics to text signatures.
+//!
+//! A [`SignatureDatabase`] maps 4-byte selectors of functions and errors and 32-byte topics of
+//! events to the text signatures they may be computed from. It is loaded from local files, no
+//! network access is needed:
+//!
+//! - JSON dumps of [4byte.directory](https://www.4byte.directory), either pages of the API or
+//!   arrays of their results, and objects mapping hashes to one or more signatures,
+//! - CSV dumps, with `hex_signature` and `text_signature` columns or headerless `hash,signature`
+//!   lines,
+//! - compact binary indexes written by [`SignatureDatabase::to_binary`].
+//!
+//! Databases can be extended with the signatures of a contract ABI, which is the only way to be
+//! sure which of several colliding signatures a contract uses.
+//!
+//! ```
+//! # use oculars_analysis::signatures::{Origin, SignatureDatabase};
+//! let mut database = SignatureDatabase::parse(concat!(
+//!     "0xa9059cbb,transfer(address,uint256)\n",
+//!     "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef,",
+//!     "Transfer(address,address,uint256)\n",
+//! ).as_bytes())
+//! .unwrap();
+//! database
+//!     .extend_from_abi(
+//!         r#"[{"name": "many_msg_babbage", "inputs": [{"name": "x", "type": "bytes1"}]}]"#,
+//!     )
+//!     .unwrap();
+//!
+//! let candidates = database.selector([0xa9, 0x05, 0x9c, 0xbb]);
+//! assert_eq!(candidates[0].text, "many_msg_babbage(bytes1)");
+//! assert_eq!(candidates[0].origin, Origin::Abi);
+//! assert_eq!(candidates[1].text, "transfer(address,uint256)");
+//! ```
+
+mod abi;
+mod binary;
+mod dump;
+
+use asm2::eval::keccak256;
+use std::{collections::BTreeMap, fs, io, path::Path};
+use thiserror::Error;
+
+/// Where a signature comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Origin {
+    /// Computed from a contract ABI.
+    Abi,
+    /// Loaded from a signature database.
+    Database,
+}
+
+/// A text signature a selector or topic may be computed from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Signature {
+    /// The text signature, such as `transfer(address,uint256)`.
+    pub text: String,
+    /// Where the signature comes from.
+    pub origin: Origin,
+    /// Identifier of the signature in the database it was loaded from, if it has one.
+    ///
+    /// 4byte.directory numbers signatures in the order they were submitted.
+    pub id: Option<u64>,
+}
+
+impl Signature {
+    /// Creates a signature without an identifier.
+    #[must_use]
+    pub fn new(text: &str, origin: Origin) -> Self {
+        Self {
+            text: text.to_string(),
+            origin,
+            id: None,
+        }
+    }
+
+    /// Returns the key signatures are ranked by, lower keys rank first.
+    fn rank(&self) -> (Origin, u64) {
+        (self.origin, self.id.unwrap_or(u64::MAX))
+    }
+}
+
+/// Errors that can happen when loading signatures.
+#[derive(Debug, Error)]
+pub enum SignatureError {
+    /// The database could not be read.
+    #[error("failed to read signature database: {0}")]
+    Io(#[from] io::Error),
+
+    /// A JSON database or ABI is not valid JSON.
+    #[error("invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// A JSON database does not have any of the supported layouts.
+    #[error("invalid JSON signature database: {0}")]
+    JsonLayout(String),
+
+    /// A line of a CSV database is malformed.
+    #[error("invalid CSV signature database at line {line}: {reason}")]
+    Csv {
+        /// Line number, starting at 1.
+        line: usize,
+        /// What is wrong with the line.
+        reason: String,
+    },
+
+    /// A binary index is malformed.
+    #[error("invalid binary signature index: {0}")]
+    Binary(&'static str),
+
+    /// An ABI is malformed.
+    #[error("invalid ABI: {0}")]
+    Abi(String),
+}
+
+/// A hash a signature resolves from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Hash {
+    /// The selector of a function or error.
+    Selector([u8; 4]),
+    /// The topic of an event.
+    Topic([u8; 32]),
+}
+
+impl Hash {
+    /// Parses a hex encoded selector or topic, with or without `0x` prefix.
+    fn parse(hex: &str) -> Option<Self> {
+        let bytes = hex::decode(hex.strip_prefix("0x").unwrap_or(hex)).ok()?;
+
+        match bytes.len() {
+            4 => bytes.try_into().ok().map(Self::Selector),
+            32 => bytes.try_into().ok().map(Self::Topic),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the hash is computed from `text`.
+    fn matches(&self, text: &str) -> bool {
+        match self {
+            Self::Selector(selector) => *selector == function_selector(text),
+            Self::Topic(topic) => *topic == event_topic(text),
+        }
+    }
+}
+
+/// Returns the selector of a function or error with the text signature `text`.
+///
+/// ```
+/// # use oculars_analysis::signatures::function_selector;
+/// assert_eq!(function_selector("transfer(address,uint256)"), [0xa9, 0x05, 0x9c, 0xbb]);
+/// ```
+#[must_use]
+pub fn function_selector(text: &str) -> [u8; 4] {
+    let [a, b, c, d, ..] = event_topic(text);
+    [a, b, c, d]
+}
+
+/// Returns the topic of an event with the text signature `text`.
+#[must_use]
+pub fn event_topic(text: &str) -> [u8; 32] {
+    keccak256(text.as_bytes()).to_be_bytes()
+}
+
+/// Candidate signatures of selectors and topics.
+///
+/// The candidates of a hash are ranked by how likely they are to be the signature a contract
+/// uses: signatures from ABIs first, then signatures with lower identifiers, which were submitted
+/// to the database earlier. Colliding signatures are usually crafted after the signature they
+/// collide with became known. Signatures that rank the same keep the order they were added in.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SignatureDatabase {
+    /// Candidates of function and error selectors.
+    selectors: BTreeMap<[u8; 4], Vec<Signature>>,
+    /// Candidates of event topics.
+    topics: BTreeMap<[u8; 32], Vec<Signature>>,
+}
+
+impl SignatureDatabase {
+    /// Creates an empty database.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads a database from a file in any of the supported formats.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or is malformed.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, SignatureError> {
+        Self::parse(&fs::read(path)?)
+    }
+
+    /// Parses a database in any of the supported formats.
+    ///
+    /// Binary indexes are recognized by their magic number, JSON dumps by starting with `{` or
+    /// `[`, everything else is parsed as CSV. Entries of dumps whose hash is not computed from
+    /// their signature are skipped.
+    ///
+    /// # Errors
+    /// Returns an error if the database is malformed.
+    pub fn parse(data: &[u8]) -> Result<Self, SignatureError> {
+        let mut database = Self::new();
+
+        if let Some(index) = data.strip_prefix(binary::MAGIC) {
+            binary::read(&mut database, index)?;
+            return Ok(database);
+        }
+
+        let text = std::str::from_utf8(data)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        if text.trim_start().starts_with(['{', '[']) {
+            dump::read_json(&mut database, text)?;
+        } else {
+            dump::read_csv(&mut database, text)?;
+        }
+
+        Ok(database)
+    }
+
+    /// Adds the signatures of the functions, errors and events of a JSON encoded ABI.
+    ///
+    /// Both plain ABIs and compiler artifacts with an `abi` field are accepted. Anonymous events
+    /// are skipped, as they have no topic.
+    ///
+    /// # Errors
+    /// Returns an error if the ABI is malformed.
+    pub fn extend_from_abi(&mut self, abi: &str) -> Result<(), SignatureError> {
+        abi::read(self, abi)
+    }
+
+    /// Writes the database as a compact binary index, which loads faster than dumps.
+    #[must_use]
+    pub fn to_binary(&self) -> Vec<u8> {
+        binary::write(self)
+    }
+
+    /// Adds a candidate signature of a function or error selector.
+    ///
+    /// If the selector already has a candidate with the same text, the better ranked one is kept.
+    /// The signature is not checked to be computed from the selector.
+    pub fn insert_selector(&mut self, selector: [u8; 4], signature: Signature) {
+        insert(self.selectors.entry(selector).or_default(), signature);
+    }
+
+    /// Adds a candidate signature of an event topic.
+    ///
+    /// If the topic already has a candidate with the same text, the better ranked one is kept.
+    /// The signature is not checked to be computed from the topic.
+    pub fn insert_topic(&mut self, topic: [u8; 32], signature: Signature) {
+        insert(self.topics.entry(topic).or_default(), signature);
+    }
+
+    /// Adds a candidate signature of a selector or topic.
+    fn insert(&mut self, hash: Hash, signature: Signature) {
+        match hash {
+            Hash::Selector(selector) => self.insert_selector(selector, signature),
+            Hash::Topic(topic) => self.insert_topic(topic, signature),
+        }
+    }
+
+    /// Adds all candidates of `other`.
+    pub fn merge(&mut self, other: Self) {
+        for (selector, signatures) in other.selectors {
+            for signature in signatures {
+                self.insert_selector(selector, signature);
+            }
+        }
+        for (topic, signatures) in other.topics {
+            for signature in signatures {
+                self.insert_topic(topic, signature);
+            }
+        }
+    }
+
+    /// Returns the ranked candidate signatures of a function or error selector.
+    #[must_use]
+    pub fn selector(&self, selector: [u8; 4]) -> &[Signature] {
+        self.selectors.get(&selector).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the ranked candidate signatures of an event topic.
+    #[must_use]
+    pub fn topic(&self, topic: &[u8; 32]) -> &[Signature] {
+        self.topics.get(topic).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the amount of selectors and topics with candidates.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.selectors.len() + self.topics.len()
+    }
+
+    /// Returns whether the database has no candidates.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.selectors.is_empty() && self.topics.is_empty()
+    }
+}
+
+/// Adds `signature` to the ranked `candidates` of a hash.
+fn insert(candidates: &mut Vec<Signature>, signature: Signature) {
+    match candidates
+        .iter_mut()
+        .find(|candidate| candidate.text == signature.text)
+    {
+        Some(candidate) if signature.rank() < candidate.rank() => *candidate = signature,
+        Some(_) => return,
+        None => candidates.push(signature),
+    }
+
+    candidates.sort_by_key(Signature::rank);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Selector of `transfer(address,uint256)`, which `many_msg_babbage(bytes1)` collides with.
+    pub const TRANSFER: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+
+    #[test]
+    fn ranking() {
+        let mut database = SignatureDatabase::new();
+        let database_signature = |text: &str, id| Signature {
+            id,
+            ..Signature::new(text, Origin::Database)
+        };
+
+        database.insert_selector(TRANSFER, database_signature("c()", None));
+        database.insert_selector(TRANSFER, database_signature("b()", Some(7)));
+        database.insert_selector(TRANSFER, Signature::new("a()", Origin::Abi));
+        database.insert_selector(TRANSFER, database_signature("d()", None));
+        database.insert_selector(TRANSFER, database_signature("c()", Some(3)));
+        database.insert_selector(TRANSFER, database_signature("b()", Some(9)));
+
+        let ranked: Vec<_> = database
+            .selector(TRANSFER)
+            .iter()
+            .map(|signature| (signature.text.as_str(), signature.id))
+            .collect();
+        assert_eq!(
+            ranked,
+            [
+                ("a()", None),
+                ("c()", Some(3)),
+                ("b()", Some(7)),
+                ("d()", None)
+            ]
+        );
+        assert_eq!(database.len(), 1);
+        assert!(database.selector([0; 4]).is_empty());
+    }
+
+    #[test]
+    fn hashes() {
+        assert_eq!(Hash::parse("a9059cbb"), Some(Hash::Selector(TRANSFER)));
+        assert_eq!(Hash::parse("0xa9059cbb"), Some(Hash::Selector(TRANSFER)));
+        assert_eq!(Hash::parse("0xa9059c"), None);
+        assert_eq!(Hash::parse("0xa9059cbg"), None);
+        assert_eq!(Hash::parse("0xa9059cbé"), None);
+        assert_eq!(Hash::parse("0x+9059cbb"), None);
+
+        assert!(Hash::Selector(TRANSFER).matches("transfer(address,uint256)"));
+        assert!(Hash::Selector(TRANSFER).matches("many_msg_babbage(bytes1)"));
+        assert!(!Hash::Selector(TRANSFER).matches("transfer(address,uint)"));
+    }
+
+    #[test]
+    fn merge() {
+        let mut database = SignatureDatabase::new();
+        database.insert_selector(TRANSFER, Signature::new("b()", Origin::Database));
+
+        let mut other = SignatureDatabase::new();
+        other.insert_selector(TRANSFER, Signature::new("a()", Origin::Abi));
+        other.insert_topic([1; 32], Signature::new("E()", Origin::Abi));
+        database.merge(other);
+
+        assert_eq!(database.selector(TRANSFER)[0].text, "a()");
+        assert_eq!(database.topic(&[1; 32])[0].text, "E()");
+        assert_eq!(database.len(), 2);
+    }
+}
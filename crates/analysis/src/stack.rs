@@ -0,0 +1,528 @@
+//! Resolution of indirect jumps by abstract stack interpretation.
+//!
+//! Compilers do not always push the target of a jump right before jumping. Solidity internal
+//! function calls push a return address, jump into the function and jump back through the return
+//! address once the function is done, after moving it around with `DUPx`, `SWAPx` and `POP`.
+//!
+//! The analysis interprets the basic blocks of a [`ControlFlowGraph`] over an [`AbstractStack`]
+//! that only tracks constants pushed by `PUSHx` and `PC`. Every block is analyzed once per distinct
+//! entry stack (its _context_), so a function body is followed back to each of its callers. Blocks
+//! that are not reached from the entry of the code are analyzed with an unknown entry stack.
+//!
+//! ```
+//! # use oculars_analysis::cfg::{ControlFlowGraph, Target};
+//! # use dasm::disassembler::Disassembler;
+//! # use upgrades::forks::shanghai::Shanghai;
+//! // 0: PUSH1 0x06 PUSH1 0x08 JUMP
+//! // 5: STOP
+//! // 6: JUMPDEST STOP
+//! // 8: JUMPDEST JUMP
+//! let assembly = Disassembler::<Shanghai>::new()
+//!     .disassemble_hex("0x6006600856005b005b56")
+//!     .unwrap();
+//!
+//! let cfg = ControlFlowGraph::new(&assembly);
+//! assert_eq!(cfg.unresolved().count(), 1);
+//!
+//! let cfg = ControlFlowGraph::with_stack_analysis(&assembly);
+//! assert_eq!(cfg.unresolved().count(), 0);
+//! assert_eq!(cfg.successors(8).next().map(|edge| edge.target), Some(Target::Block(6)));
+//! ```
+
+use asm2::{AssemblyInstruction, Mnemonic, OpCode, U256};
+use dasm::assembly::PositionedInstruction;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+use crate::blocks::BasicBlock;
+use crate::cfg::{ControlFlowGraph, Target};
+
+/// Maximum amount of values an EVM stack can hold.
+const STACK_LIMIT: usize = 1024;
+
+/// Maximum amount of contexts a single block is analyzed in.
+///
+/// Code that keeps pushing constants in a loop would otherwise produce infinitely many contexts.
+const CONTEXT_LIMIT: usize = 64;
+
+/// A value on an [`AbstractStack`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Value {
+    /// A constant that is known statically.
+    Known(U256),
+    /// A value that is only known at runtime.
+    Unknown,
+}
+
+impl Value {
+    /// Returns the value if it is known statically.
+    #[must_use]
+    pub const fn known(&self) -> Option<U256> {
+        match self {
+            Self::Known(value) => Some(*value),
+            Self::Unknown => None,
+        }
+    }
+}
+
+/// A value that can be stored on an [`AbstractStack`].
+pub trait StackValue: Copy + Eq {
+    /// The value of every item of the stack that is not tracked.
+    const UNKNOWN: Self;
+}
+
+impl StackValue for Value {
+    const UNKNOWN: Self = Self::Unknown;
+}
+
+/// An approximation of the EVM stack.
+///
+/// Only the top of the stack is stored, every value below it is [unknown](StackValue::UNKNOWN).
+///
+/// ```
+/// # use oculars_analysis::stack::{AbstractStack, Value};
+/// # use asm2::U256;
+/// let mut stack = AbstractStack::default();
+/// stack.push(Value::Known(U256::from(1_u64)));
+/// stack.push(Value::Unknown);
+/// stack.swap(1);
+///
+/// assert_eq!(stack.pop(), Value::Known(U256::from(1_u64)));
+/// assert_eq!(stack.pop(), Value::Unknown);
+/// assert_eq!(stack.pop(), Value::Unknown);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AbstractStack<V = Value> {
+    /// The top of the stack, the last value is the topmost one. Never starts with an unknown
+    /// value, since every value below the stored ones is unknown anyway.
+    values: Vec<V>,
+}
+
+impl<V> Default for AbstractStack<V> {
+    fn default() -> Self {
+        Self { values: Vec::new() }
+    }
+}
+
+impl<V: StackValue> AbstractStack<V> {
+    /// Pushes a value on top of the stack.
+    pub fn push(&mut self, value: V) {
+        if value == V::UNKNOWN && self.values.is_empty() {
+            return;
+        }
+
+        if self.values.len() == STACK_LIMIT {
+            self.values.remove(0);
+            self.trim();
+        }
+        self.values.push(value);
+    }
+
+    /// Removes the topmost value from the stack and returns it.
+    pub fn pop(&mut self) -> V {
+        let value = self.values.pop().unwrap_or(V::UNKNOWN);
+        self.trim();
+        value
+    }
+
+    /// Returns the value `depth` items below the top of the stack.
+    #[must_use]
+    pub fn peek(&self, depth: usize) -> V {
+        self.values
+            .len()
+            .checked_sub(depth + 1)
+            .map_or(V::UNKNOWN, |index| self.values[index])
+    }
+
+    /// Returns [`true`] if `value` is one of the stored values.
+    #[must_use]
+    pub fn contains(&self, value: V) -> bool {
+        self.values.contains(&value)
+    }
+
+    /// Duplicates the `n`th value from the top of the stack, like `DUPn`.
+    pub fn dup(&mut self, n: usize) {
+        self.push(self.peek(n - 1));
+    }
+
+    /// Exchanges the top of the stack with the value `n` items below it, like `SWAPn`.
+    pub fn swap(&mut self, n: usize) {
+        if self.values.len() <= n {
+            let missing = n + 1 - self.values.len();
+            self.values
+                .splice(0..0, core::iter::repeat_n(V::UNKNOWN, missing));
+        }
+
+        let top = self.values.len() - 1;
+        self.values.swap(top, top - n);
+        self.trim();
+    }
+
+    /// Removes the unknown values at the bottom of the stored values.
+    fn trim(&mut self) {
+        let known = self
+            .values
+            .iter()
+            .position(|value| *value != V::UNKNOWN)
+            .unwrap_or(self.values.len());
+        self.values.drain(..known);
+    }
+
+    /// Applies the effect of a non-jumping instruction to the stack.
+    ///
+    /// `DUPx` and `SWAPx` move values around. Every other instruction pops its inputs and pushes
+    /// the value `evaluate` returns for the instruction and its inputs, topmost input first.
+    pub fn execute_with<F>(&mut self, instruction: &PositionedInstruction, evaluate: F)
+    where
+        F: FnOnce(&PositionedInstruction, &[V]) -> V,
+    {
+        let opcode = instruction.instruction.opcode();
+
+        if opcode.is_dup() {
+            self.dup(usize::from(opcode.into_byte() - 0x7F));
+        } else if opcode.is_swap() {
+            self.swap(usize::from(opcode.into_byte() - 0x8F));
+        } else {
+            let inputs: Vec<V> = (0..opcode.stack_inputs()).map(|_| self.pop()).collect();
+
+            if opcode.stack_outputs() > 0 {
+                self.push(evaluate(instruction, &inputs));
+            }
+            for _ in 1..opcode.stack_outputs() {
+                self.push(V::UNKNOWN);
+            }
+        }
+    }
+}
+
+impl AbstractStack {
+    /// Applies the effect of a non-jumping instruction to the stack, only keeping track of
+    /// constants pushed by `PUSHx` and `PC`.
+    fn execute(&mut self, instruction: &PositionedInstruction) {
+        self.execute_with(instruction, |instruction, _| {
+            let opcode = instruction.instruction.opcode();
+
+            if opcode.is_push() {
+                U256::from_be_slice(instruction.instruction.immediate_bytes())
+                    .map_or(Value::Unknown, Value::Known)
+            } else if opcode == Mnemonic::PC {
+                Value::Known(U256::from(instruction.position as u64))
+            } else {
+                Value::Unknown
+            }
+        });
+    }
+}
+
+/// Jump targets found by interpreting the blocks of a [`ControlFlowGraph`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JumpTargets {
+    /// Targets of the jump ending each analyzed block, keyed by the start of the block. A jump
+    /// whose target is unknown in some context has an [unresolved](Target::Unresolved) target.
+    targets: BTreeMap<usize, Vec<Target>>,
+}
+
+impl JumpTargets {
+    /// Interprets the blocks of `cfg` and collects the targets of their jumps.
+    #[must_use]
+    pub fn analyze(cfg: &ControlFlowGraph<'_>) -> Self {
+        let mut analysis = Analysis {
+            cfg,
+            queue: VecDeque::new(),
+            visited: HashSet::new(),
+            contexts: HashMap::new(),
+            targets: Self::default(),
+        };
+
+        if let Some(entry) = cfg.blocks().first() {
+            analysis.enqueue(entry.start(), AbstractStack::default());
+            analysis.run();
+        }
+
+        for block in cfg.blocks() {
+            if !analysis.contexts.contains_key(&block.start()) {
+                analysis.enqueue(block.start(), AbstractStack::default());
+                analysis.run();
+            }
+        }
+
+        analysis.targets
+    }
+
+    /// Returns the targets found for the jump ending the block starting at `start`, or [`None`]
+    /// if the block does not end with a jump.
+    ///
+    /// The targets are ordered, with [`Target::Unresolved`] last if the target was not known in
+    /// some context or the block was not interpreted in every context it is reached in, as blocks
+    /// are only interpreted in a limited amount of contexts.
+    #[must_use]
+    pub fn of(&self, start: usize) -> Option<&[Target]> {
+        self.targets.get(&start).map(Vec::as_slice)
+    }
+
+    /// Records `target` as a target of the jump ending the block starting at `start`, an unknown
+    /// target as [`Target::Unresolved`].
+    fn insert(&mut self, start: usize, target: Option<Target>) {
+        let targets = self.targets.entry(start).or_default();
+        let target = target.unwrap_or(Target::Unresolved);
+
+        if let Err(index) = targets.binary_search_by(|known| compare(known, &target)) {
+            targets.insert(index, target);
+        }
+    }
+}
+
+/// Orders resolved targets by their block, then invalid targets by their value.
+fn compare(a: &Target, b: &Target) -> core::cmp::Ordering {
+    /// Returns a sort key of a target.
+    fn key(target: &Target) -> (usize, U256) {
+        match target {
+            Target::Block(start) => (0, U256::from(*start as u64)),
+            Target::Invalid { target, .. } => (1, *target),
+            Target::Unresolved => (2, U256::ZERO),
+        }
+    }
+
+    key(a).cmp(&key(b))
+}
+
+/// State of the worklist algorithm.
+struct Analysis<'c, 'a> {
+    /// The analyzed graph.
+    cfg: &'c ControlFlowGraph<'a>,
+    /// Blocks and entry stacks left to interpret.
+    queue: VecDeque<(usize, AbstractStack)>,
+    /// Blocks and entry stacks that have been queued.
+    visited: HashSet<(usize, AbstractStack)>,
+    /// Amount of contexts each block has been queued in.
+    contexts: HashMap<usize, usize>,
+    /// The collected jump targets.
+    targets: JumpTargets,
+}
+
+impl Analysis<'_, '_> {
+    /// Queues the block starting at `start` with `stack` on entry, unless it has been queued with
+    /// the same stack or in too many contexts already.
+    ///
+    /// A context dropped because of the limit leaves the jump the block flows into unresolved.
+    fn enqueue(&mut self, start: usize, stack: AbstractStack) {
+        let contexts = self.contexts.entry(start).or_default();
+        let key = (start, stack);
+
+        if self.visited.contains(&key) {
+            return;
+        }
+        if *contexts >= CONTEXT_LIMIT {
+            self.drop_context(start);
+            return;
+        }
+
+        *contexts += 1;
+        self.visited.insert(key.clone());
+        self.queue.push_back(key);
+    }
+
+    /// Marks the jump that the block starting at `start` falls through to (or ends with) as
+    /// unresolved, since the block is not interpreted in a context.
+    fn drop_context(&mut self, mut start: usize) {
+        while let Some(block) = self.cfg.block(start) {
+            let last = block.instructions.last().expect("blocks are never empty");
+
+            if matches!(
+                last.instruction.opcode(),
+                OpCode::Known(Mnemonic::JUMP | Mnemonic::JUMPI)
+            ) {
+                self.targets.insert(start, None);
+                return;
+            }
+
+            let Some(next) = block.fall_through else {
+                return;
+            };
+            start = next;
+        }
+    }
+
+    /// Interprets queued blocks until the queue is empty.
+    fn run(&mut self) {
+        while let Some((start, stack)) = self.queue.pop_front() {
+            if let Some(block) = self.cfg.block(start) {
+                self.interpret(block, stack);
+            }
+        }
+    }
+
+    /// Interprets a block with `stack` on entry and queues its successors.
+    fn interpret(&mut self, block: &BasicBlock<'_>, mut stack: AbstractStack) {
+        let (last, body) = block
+            .instructions
+            .split_last()
+            .expect("blocks are never empty");
+
+        for instruction in body {
+            stack.execute(instruction);
+        }
+
+        let conditional = match last.instruction.opcode() {
+            OpCode::Known(Mnemonic::JUMP) => false,
+            OpCode::Known(Mnemonic::JUMPI) => true,
+            _ => {
+                stack.execute(last);
+                if let Some(next) = block.fall_through {
+                    self.enqueue(next, stack);
+                }
+                return;
+            }
+        };
+
+        let target = stack
+            .pop()
+            .known()
+            .map(|target| self.cfg.resolve_target(target));
+        self.targets.insert(block.start(), target);
+
+        if conditional {
+            stack.pop();
+            if let Some(next) = block.fall_through {
+                self.enqueue(next, stack.clone());
+            }
+        }
+
+        if let Some(Target::Block(target)) = target {
+            self.enqueue(target, stack);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cfg::EdgeKind;
+    use crate::tests::assembly;
+
+    /// Returns the known value `value`.
+    fn known(value: u64) -> Value {
+        Value::Known(U256::from(value))
+    }
+
+    #[test]
+    fn abstract_stack() {
+        let mut stack = AbstractStack::default();
+        stack.push(Value::Unknown);
+        assert_eq!(stack, AbstractStack::default());
+
+        stack.push(known(1));
+        stack.push(known(2));
+        stack.dup(2);
+        assert_eq!(stack.peek(0), known(1));
+        assert_eq!(stack.peek(2), known(1));
+        assert_eq!(stack.peek(3), Value::Unknown);
+
+        stack.swap(4);
+        assert_eq!(stack.peek(0), Value::Unknown);
+        assert_eq!(stack.peek(4), known(1));
+
+        for _ in 0..4 {
+            stack.pop();
+        }
+        assert_eq!(stack.pop(), known(1));
+        assert_eq!(stack, AbstractStack::default());
+    }
+
+    #[test]
+    fn stack_limit() {
+        let mut stack = AbstractStack::default();
+        for value in 0..=STACK_LIMIT as u64 {
+            stack.push(known(value));
+        }
+
+        assert_eq!(stack.peek(0), known(STACK_LIMIT as u64));
+        assert_eq!(stack.peek(STACK_LIMIT - 1), known(1));
+        assert_eq!(stack.peek(STACK_LIMIT), Value::Unknown);
+    }
+
+    #[test]
+    fn internal_function_returns() {
+        // 0: PUSH1 0x07 PUSH1 0x01 PUSH1 0x11 JUMP
+        // 7: JUMPDEST PUSH1 0x0f PUSH1 0x02 PUSH1 0x11 JUMP
+        // 15: JUMPDEST STOP
+        // 17: JUMPDEST DUP1 ADD SWAP1 JUMP
+        let assembly = assembly("0x600760016011565b600f60026011565b005b80019056");
+        let cfg = ControlFlowGraph::with_stack_analysis(&assembly);
+
+        assert_eq!(
+            cfg.successors(17)
+                .map(|edge| (edge.target, edge.kind))
+                .collect::<Vec<_>>(),
+            [
+                (Target::Block(7), EdgeKind::Jump),
+                (Target::Block(15), EdgeKind::Jump)
+            ]
+        );
+        assert_eq!(cfg.predecessors(17).count(), 2);
+        assert_eq!(cfg.unresolved().count(), 0);
+    }
+
+    #[test]
+    fn unknown_targets_stay_unresolved() {
+        // 0: PUSH0 CALLDATALOAD JUMP
+        // 3: JUMPDEST PUSH1 0x00 PC JUMPI
+        let assembly = assembly("0x5f35565b60005857");
+        let targets = JumpTargets::analyze(&ControlFlowGraph::new(&assembly));
+
+        assert_eq!(targets.of(0), Some(&[Target::Unresolved][..]));
+        assert_eq!(
+            targets.of(3),
+            Some(
+                &[Target::Invalid {
+                    target: U256::from(6_u64),
+                    reason: crate::cfg::InvalidTarget::NotJumpdest
+                }][..]
+            )
+        );
+
+        let cfg = ControlFlowGraph::with_stack_analysis(&assembly);
+        assert_eq!(
+            cfg.unresolved().map(|edge| edge.from).collect::<Vec<_>>(),
+            [0]
+        );
+    }
+
+    #[test]
+    fn growing_stack_terminates() {
+        // JUMPDEST PUSH1 0x00 PUSH1 0x00 JUMP
+        let assembly = assembly("0x5b6000600056");
+        let cfg = ControlFlowGraph::new(&assembly);
+        let targets = JumpTargets::analyze(&cfg);
+
+        // The stack keeps growing, so the block is dropped in all but the first contexts.
+        assert_eq!(
+            targets.of(0),
+            Some(&[Target::Block(0), Target::Unresolved][..])
+        );
+    }
+
+    #[test]
+    fn partially_unknown_targets() {
+        // 0: PUSH1 0x0b PUSH1 0x0d JUMP
+        // 5: JUMPDEST CALLDATASIZE PUSH1 0x0d JUMP
+        // 10: STOP
+        // 11: JUMPDEST STOP
+        // 13: JUMPDEST JUMP
+        let assembly = assembly("0x600b600d565b36600d56005b005b56");
+        let cfg = ControlFlowGraph::with_stack_analysis(&assembly);
+
+        assert_eq!(
+            cfg.successors(13)
+                .map(|edge| (edge.target, edge.kind))
+                .collect::<Vec<_>>(),
+            [
+                (Target::Block(11), EdgeKind::Jump),
+                (Target::Unresolved, EdgeKind::Jump)
+            ]
+        );
+        assert_eq!(
+            cfg.unresolved().map(|edge| edge.from).collect::<Vec<_>>(),
+            [13]
+        );
+    }
+}
@@ -0,0 +1,91 @@
+//! Parsing of push immediate values.
+
+/// An immediate value parsed from an integer literal.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Immediate {
+    /// Big-endian bytes of the value without leading zeroes.
+    pub bytes: Vec<u8>,
+
+    /// Amount of bytes the literal was written with (only known for hex literals).
+    pub written_size: Option<usize>,
+}
+
+impl Immediate {
+    /// Parses an integer literal (decimal, `0x`, `0o` or `0b` prefixed) into an immediate.
+    /// Returns [`None`] if the literal is not a valid unsuffixed integer.
+    pub fn parse(literal: &str) -> Option<Self> {
+        let literal = literal.replace('_', "");
+
+        let (radix, digits) = match literal.get(..2) {
+            Some("0x" | "0X") => (16, &literal[2..]),
+            Some("0o" | "0O") => (8, &literal[2..]),
+            Some("0b" | "0B") => (2, &literal[2..]),
+            _ => (10, literal.as_str()),
+        };
+
+        if digits.is_empty() {
+            return None;
+        }
+
+        let mut bytes: Vec<u8> = vec![];
+
+        for digit in digits.chars() {
+            let mut carry = digit.to_digit(radix)?;
+
+            // multiply the accumulated big-endian value by the radix and add the digit
+            for byte in bytes.iter_mut().rev() {
+                let value = u32::from(*byte) * radix + carry;
+                *byte = value.to_le_bytes()[0];
+                carry = value >> 8;
+            }
+
+            while carry > 0 {
+                bytes.insert(0, carry.to_le_bytes()[0]);
+                carry >>= 8;
+            }
+        }
+
+        Some(Self {
+            bytes,
+            written_size: (radix == 16).then(|| digits.len().div_ceil(2)),
+        })
+    }
+
+    /// Returns the size of a push that should carry this immediate when no explicit size is
+    /// given.
+    pub fn natural_size(&self) -> usize {
+        self.written_size.unwrap_or(self.bytes.len()).max(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literal_parsing() {
+        let parse = |literal| Immediate::parse(literal).unwrap();
+
+        assert_eq!(parse("0").bytes, Vec::<u8>::new());
+        assert_eq!(parse("1337").bytes, [0x05, 0x39]);
+        assert_eq!(parse("0x0539").bytes, [0x05, 0x39]);
+        assert_eq!(parse("0x_05_39").bytes, [0x05, 0x39]);
+        assert_eq!(parse("0o2471").bytes, [0x05, 0x39]);
+        assert_eq!(parse("0b10100111001").bytes, [0x05, 0x39]);
+        assert_eq!(parse(&format!("0x{}", "FF".repeat(32))).bytes, [0xFF; 32]);
+
+        assert_eq!(Immediate::parse("0x"), None);
+        assert_eq!(Immediate::parse("0xZZ"), None);
+        assert_eq!(Immediate::parse("1u8"), None);
+    }
+
+    #[test]
+    fn immediate_sizes() {
+        let parse = |literal| Immediate::parse(literal).unwrap();
+
+        assert_eq!(parse("0").natural_size(), 1);
+        assert_eq!(parse("256").natural_size(), 2);
+        assert_eq!(parse("0x0001").natural_size(), 2);
+        assert_eq!(parse("0x001").natural_size(), 2);
+    }
+}
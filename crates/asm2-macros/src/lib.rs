@@ -0,0 +1,53 @@
+//! Compile-time EVM assembly macros.
+
+mod immediate;
+mod parse;
+
+use proc_macro::TokenStream;
+use quote::quote;
+
+/// Assembles EVM mnemonics into a constant byte array at compile time.
+///
+/// Instructions are written as whitespace separated mnemonics. `PUSHx` mnemonics (except
+/// `PUSH0`) must be followed by an integer literal, which is left padded with zeroes to the size
+/// of the push. A bare `PUSH` picks the push size from the literal: hex literals keep the width
+/// they were written with, any other literal uses the smallest push that fits the value.
+/// Mnemonics are case-insensitive.
+///
+/// The macro expands to a `[u8; N]` expression, so it can be used to initialize constants.
+///
+/// # Example
+/// ```
+/// # use oculars_asm2_macros::evm_asm;
+/// const CODE: [u8; 7] = evm_asm!(PUSH1 0x80 PUSH1 0x40 MSTORE push 0x00);
+/// assert_eq!(CODE, [0x60, 0x80, 0x60, 0x40, 0x52, 0x60, 0x00]);
+///
+/// const WIDE: [u8; 4] = evm_asm!(PUSH3 1337);
+/// assert_eq!(WIDE, [0x62, 0x00, 0x05, 0x39]);
+/// ```
+///
+/// Unknown mnemonics fail to compile:
+///
+/// ```compile_fail
+/// # use oculars_asm2_macros::evm_asm;
+/// const CODE: [u8; 1] = evm_asm!(PAY_VITALIK); // compile fail!
+/// ```
+///
+/// So do immediate values that do not fit into their push:
+///
+/// ```compile_fail
+/// # use oculars_asm2_macros::evm_asm;
+/// const CODE: [u8; 2] = evm_asm!(PUSH1 0x1337); // compile fail!
+/// ```
+///
+/// ```compile_fail
+/// # use oculars_asm2_macros::evm_asm;
+/// const CODE: [u8; 34] = evm_asm!(PUSH 0x00112233445566778899AABBCCDDEEFF00112233445566778899AABBCCDDEEFF00); // compile fail!
+/// ```
+#[proc_macro]
+pub fn evm_asm(input: TokenStream) -> TokenStream {
+    match parse::assemble(input.into()) {
+        Ok(bytes) => quote!([#(#bytes),*]).into(),
+        Err(error) => error.into_compile_error().into(),
+    }
+}
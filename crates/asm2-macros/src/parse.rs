@@ -0,0 +1,189 @@
+//! Parsing of mnemonic syntax into bytecode.
+
+use crate::immediate::Immediate;
+use asm2::{AssemblyInstruction, Instruction, Mnemonic, OpInfo, U256};
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::quote_spanned;
+use std::iter::Peekable;
+
+/// An error that points at the offending tokens.
+pub struct Error {
+    /// Location of the error.
+    span: Span,
+    /// Error message.
+    message: String,
+}
+
+impl Error {
+    /// Creates a new error at the specified location.
+    fn new(span: Span, message: impl Into<String>) -> Self {
+        Self {
+            span,
+            message: message.into(),
+        }
+    }
+
+    /// Converts this error into a `compile_error!` invocation.
+    pub fn into_compile_error(self) -> TokenStream {
+        let message = self.message;
+        quote_spanned!(self.span => compile_error!(#message))
+    }
+}
+
+/// Returns the size of the immediate value that follows a (non-EOF) mnemonic.
+fn immediate_size(mnemonic: Mnemonic) -> usize {
+    OpInfo::of(mnemonic.into_byte())
+        .immediate_size()
+        .map_or(0, usize::from)
+}
+
+/// Finds a mnemonic by its (case-insensitive) name or one of its historical aliases.
+///
+/// EOF mnemonics are not supported, since their immediate values are not plain pushes.
+fn find_mnemonic(name: &str) -> Option<Mnemonic> {
+    name.parse::<Mnemonic>()
+        .ok()
+        .filter(|mnemonic| !mnemonic.is_eof())
+}
+
+/// Parses the immediate value of a push, returning the push that carries it.
+fn parse_immediate<I: Iterator<Item = TokenTree>>(
+    tokens: &mut Peekable<I>,
+    mnemonic: Option<Mnemonic>,
+    push_span: Span,
+) -> Result<Instruction, Error> {
+    let push_name = mnemonic.map_or_else(|| "PUSH".to_string(), |m| m.to_string());
+
+    let Some(TokenTree::Literal(literal)) = tokens.next() else {
+        return Err(Error::new(
+            push_span,
+            format!("`{push_name}` expects an immediate value"),
+        ));
+    };
+
+    let immediate = Immediate::parse(&literal.to_string()).ok_or_else(|| {
+        Error::new(
+            literal.span(),
+            "immediate value must be an unsuffixed integer literal",
+        )
+    })?;
+
+    let size = if let Some(mnemonic) = mnemonic {
+        immediate_size(mnemonic)
+    } else {
+        let size = immediate.natural_size();
+
+        if size > U256::BYTES {
+            return Err(Error::new(
+                literal.span(),
+                format!(
+                    "immediate value is {size} bytes long, but a push can carry at most {} bytes",
+                    U256::BYTES
+                ),
+            ));
+        }
+
+        size
+    };
+
+    U256::from_be_slice(&immediate.bytes)
+        .and_then(|value| Instruction::push_with_size(value, size))
+        .ok_or_else(|| {
+            Error::new(
+                literal.span(),
+                format!(
+                    "immediate value does not fit into `{push_name}` ({} bytes required, {size} available)",
+                    immediate.bytes.len()
+                ),
+            )
+        })
+}
+
+/// Assembles mnemonic syntax into bytecode.
+pub fn assemble(input: TokenStream) -> Result<Vec<u8>, Error> {
+    let mut bytecode = vec![];
+    let mut tokens = input.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        let TokenTree::Ident(ident) = token else {
+            return Err(Error::new(token.span(), "expected a mnemonic"));
+        };
+
+        let name = ident.to_string();
+
+        if name.eq_ignore_ascii_case("PUSH") {
+            parse_immediate(&mut tokens, None, ident.span())?.encode_into(&mut bytecode);
+            continue;
+        }
+
+        let mnemonic = find_mnemonic(&name)
+            .ok_or_else(|| Error::new(ident.span(), format!("unknown mnemonic `{name}`")))?;
+
+        if immediate_size(mnemonic) > 0 {
+            parse_immediate(&mut tokens, Some(mnemonic), ident.span())?.encode_into(&mut bytecode);
+        } else {
+            bytecode.push(mnemonic.into_byte());
+        }
+    }
+
+    Ok(bytecode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn asm(input: &str) -> Result<Vec<u8>, String> {
+        assemble(TokenStream::from_str(input).unwrap()).map_err(|err| err.message)
+    }
+
+    #[test]
+    fn assembles_mnemonics() {
+        assert_eq!(asm(""), Ok(vec![]));
+        assert_eq!(asm("STOP"), Ok(vec![0x00]));
+        assert_eq!(asm("push0 GAS"), Ok(vec![0x5F, 0x5A]));
+        assert_eq!(
+            asm("PUSH1 0x80 PUSH1 0x40 MSTORE"),
+            Ok(vec![0x60, 0x80, 0x60, 0x40, 0x52])
+        );
+        assert_eq!(asm("PUSH4 0xFF"), Ok(vec![0x63, 0, 0, 0, 0xFF]));
+        assert_eq!(asm("PUSH 0x0001"), Ok(vec![0x61, 0, 1]));
+        assert_eq!(asm("PUSH 65536"), Ok(vec![0x62, 1, 0, 0]));
+        assert_eq!(asm("DIFFICULTY sha3 SUICIDE"), Ok(vec![0x44, 0x20, 0xFF]));
+
+        let mut push32 = vec![0x7F];
+        push32.extend([0xAB; 32]);
+        assert_eq!(asm(&format!("PUSH32 0x{}", "AB".repeat(32))), Ok(push32));
+    }
+
+    #[test]
+    fn reports_errors() {
+        assert_eq!(
+            asm("PAY_VITALIK"),
+            Err("unknown mnemonic `PAY_VITALIK`".into())
+        );
+        assert_eq!(asm("0x10"), Err("expected a mnemonic".into()));
+        assert_eq!(asm("PUSH0 0x10"), Err("expected a mnemonic".into()));
+        assert_eq!(
+            asm("PUSH2"),
+            Err("`PUSH2` expects an immediate value".into())
+        );
+        assert_eq!(
+            asm("PUSH2 GAS"),
+            Err("`PUSH2` expects an immediate value".into())
+        );
+        assert_eq!(
+            asm("PUSH1 256"),
+            Err("immediate value does not fit into `PUSH1` (2 bytes required, 1 available)".into())
+        );
+        assert_eq!(
+            asm("PUSH1 1u8"),
+            Err("immediate value must be an unsuffixed integer literal".into())
+        );
+        assert_eq!(
+            asm(&format!("PUSH 0x{}", "00".repeat(33))),
+            Err("immediate value is 33 bytes long, but a push can carry at most 32 bytes".into())
+        );
+    }
+}
@@ -0,0 +1,122 @@
+//! Compares storing decoded bytecode as typed and as compact instructions, both in decoding time
+//! and in memory footprint.
+
+#![expect(
+    missing_docs,
+    reason = "criterion's macros generate undocumented items"
+)]
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use oculars_asm2::{AssemblyInstruction, CompactInstruction, Instruction};
+use std::hint::black_box;
+
+/// Amount of instructions in the benchmarked bytecode.
+const INSTRUCTIONS: usize = 1_000_000;
+
+/// Returns bytecode of [`INSTRUCTIONS`] instructions that mixes pushes of different sizes with
+/// plain instructions, like compiled contracts do.
+fn bytecode() -> Vec<u8> {
+    let pattern: &[u8] = &[
+        0x60, 0x80, 0x60, 0x40, 0x52, 0x34, 0x80, 0x15, 0x61, 0x00, 0x0F, 0x57, 0x5F, 0x80, 0xFD,
+        0x5B, 0x50, 0x63, 0xDE, 0xAD, 0xBE, 0xEF, 0x14,
+    ];
+    // Amount of instructions in `pattern`.
+    let pattern_instructions = 15;
+
+    pattern.repeat(INSTRUCTIONS / pattern_instructions)
+}
+
+/// Decodes all instructions of `bytes` with `decode`.
+fn decode_all<'a, T>(bytes: &'a [u8], decode: fn(&'a [u8]) -> (T, usize)) -> Vec<T> {
+    let mut instructions = Vec::new();
+    let mut offset = 0;
+
+    while offset < bytes.len() {
+        let (instruction, size) = decode(&bytes[offset..]);
+        instructions.push(instruction);
+        offset += size;
+    }
+
+    instructions
+}
+
+/// Returns the heap bytes an instruction owns, which only the jump table of `RJUMPV` does.
+fn owned_heap(instruction: &Instruction) -> usize {
+    match instruction {
+        #[cfg(feature = "eof")]
+        Instruction::RJumpV(rjumpv) => rjumpv.immediate_size(),
+        _ => 0,
+    }
+}
+
+/// Prints the memory footprint of decoded instructions: the buffer of their vector and the `heap`
+/// bytes the instructions own or borrow.
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "the footprint is only printed with two decimals"
+)]
+fn report_footprint<T>(name: &str, instructions: &Vec<T>, heap: usize) {
+    let bytes = instructions.capacity() * size_of::<T>() + heap;
+
+    println!(
+        "{name}: {bytes} bytes for {} instructions ({:.2} bytes per instruction), including {heap} \
+         bytes of immediate values outside of the vector",
+        instructions.len(),
+        bytes as f64 / instructions.len() as f64,
+    );
+}
+
+/// Benchmarks decoding bytecode into a vector and reading back its immediate values, after printing
+/// the memory footprint of the decoded vectors.
+///
+/// The size of a single instruction is part of the benchmark name.
+fn assembly(c: &mut Criterion) {
+    let bytecode = bytecode();
+
+    let instructions = decode_all(&bytecode, |bytes| Instruction::decode(bytes).unwrap());
+    let heap = instructions.iter().map(owned_heap).sum();
+    report_footprint("Instruction", &instructions, heap);
+
+    // Compact instructions borrow their immediate values, so the bytecode has to be kept around.
+    let compact = decode_all(&bytecode, |bytes| {
+        CompactInstruction::decode(bytes).unwrap()
+    });
+    report_footprint("CompactInstruction", &compact, bytecode.len());
+
+    let mut group = c.benchmark_group("assembly");
+
+    group.bench_function(
+        BenchmarkId::new("Instruction", size_of::<Instruction>()),
+        |b| {
+            b.iter(|| {
+                let instructions = decode_all(black_box(&bytecode), |bytes| {
+                    Instruction::decode(bytes).unwrap()
+                });
+                instructions
+                    .iter()
+                    .map(AssemblyInstruction::immediate_size)
+                    .sum::<usize>()
+            });
+        },
+    );
+
+    group.bench_function(
+        BenchmarkId::new("CompactInstruction", size_of::<CompactInstruction>()),
+        |b| {
+            b.iter(|| {
+                let instructions = decode_all(black_box(&bytecode), |bytes| {
+                    CompactInstruction::decode(bytes).unwrap()
+                });
+                instructions
+                    .iter()
+                    .map(AssemblyInstruction::immediate_size)
+                    .sum::<usize>()
+            });
+        },
+    );
+
+    group.finish();
+}
+
+criterion_group!(benches, assembly);
+criterion_main!(benches);
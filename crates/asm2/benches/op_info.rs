@@ -0,0 +1,309 @@
+//! Compares the opcode table lookups with the `match` implementations they replaced.
+
+#![expect(
+    missing_docs,
+    reason = "criterion's macros generate undocumented items"
+)]
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use oculars_asm2::{Mnemonic, OpCode};
+use std::hint::black_box;
+
+/// The `match` implementations of the opcode predicates from before the opcode table, kept as a
+/// baseline for the table lookups.
+mod reference {
+    use oculars_asm2::{Mnemonic, OpCode};
+
+    /// Converts a byte into an [`OpCode`] by matching on every known opcode.
+    pub fn from_byte(byte: u8) -> OpCode {
+        match mnemonic_from_byte(byte) {
+            Some(mnemonic) => OpCode::Known(mnemonic),
+            None => OpCode::Unknown(byte),
+        }
+    }
+
+    /// Converts a byte into a legacy [`Mnemonic`], returning [`None`] if no mnemonic exists.
+    #[expect(clippy::too_many_lines, reason = "one arm per opcode")]
+    fn mnemonic_from_byte(byte: u8) -> Option<Mnemonic> {
+        match byte {
+            0x00 => Some(Mnemonic::STOP),
+            0x01 => Some(Mnemonic::ADD),
+            0x02 => Some(Mnemonic::MUL),
+            0x03 => Some(Mnemonic::SUB),
+            0x04 => Some(Mnemonic::DIV),
+            0x05 => Some(Mnemonic::SDIV),
+            0x06 => Some(Mnemonic::MOD),
+            0x07 => Some(Mnemonic::SMOD),
+            0x08 => Some(Mnemonic::ADDMOD),
+            0x09 => Some(Mnemonic::MULMOD),
+            0x0A => Some(Mnemonic::EXP),
+            0x0B => Some(Mnemonic::SIGNEXTEND),
+            0x10 => Some(Mnemonic::LT),
+            0x11 => Some(Mnemonic::GT),
+            0x12 => Some(Mnemonic::SLT),
+            0x13 => Some(Mnemonic::SGT),
+            0x14 => Some(Mnemonic::EQ),
+            0x15 => Some(Mnemonic::ISZERO),
+            0x16 => Some(Mnemonic::AND),
+            0x17 => Some(Mnemonic::OR),
+            0x18 => Some(Mnemonic::XOR),
+            0x19 => Some(Mnemonic::NOT),
+            0x1A => Some(Mnemonic::BYTE),
+            0x1B => Some(Mnemonic::SHL),
+            0x1C => Some(Mnemonic::SHR),
+            0x1D => Some(Mnemonic::SAR),
+            0x20 => Some(Mnemonic::KECCAK256),
+            0x30 => Some(Mnemonic::ADDRESS),
+            0x31 => Some(Mnemonic::BALANCE),
+            0x32 => Some(Mnemonic::ORIGIN),
+            0x33 => Some(Mnemonic::CALLER),
+            0x34 => Some(Mnemonic::CALLVALUE),
+            0x35 => Some(Mnemonic::CALLDATALOAD),
+            0x36 => Some(Mnemonic::CALLDATASIZE),
+            0x37 => Some(Mnemonic::CALLDATACOPY),
+            0x38 => Some(Mnemonic::CODESIZE),
+            0x39 => Some(Mnemonic::CODECOPY),
+            0x3A => Some(Mnemonic::GASPRICE),
+            0x3B => Some(Mnemonic::EXTCODESIZE),
+            0x3C => Some(Mnemonic::EXTCODECOPY),
+            0x3D => Some(Mnemonic::RETURNDATASIZE),
+            0x3E => Some(Mnemonic::RETURNDATACOPY),
+            0x3F => Some(Mnemonic::EXTCODEHASH),
+            0x40 => Some(Mnemonic::BLOCKHASH),
+            0x41 => Some(Mnemonic::COINBASE),
+            0x42 => Some(Mnemonic::TIMESTAMP),
+            0x43 => Some(Mnemonic::NUMBER),
+            0x44 => Some(Mnemonic::PREVRANDAO),
+            0x45 => Some(Mnemonic::GASLIMIT),
+            0x46 => Some(Mnemonic::CHAINID),
+            0x47 => Some(Mnemonic::SELFBALANCE),
+            0x48 => Some(Mnemonic::BASEFEE),
+            0x49 => Some(Mnemonic::BLOBHASH),
+            0x4A => Some(Mnemonic::BLOBBASEFEE),
+            0x50 => Some(Mnemonic::POP),
+            0x51 => Some(Mnemonic::MLOAD),
+            0x52 => Some(Mnemonic::MSTORE),
+            0x53 => Some(Mnemonic::MSTORE8),
+            0x54 => Some(Mnemonic::SLOAD),
+            0x55 => Some(Mnemonic::SSTORE),
+            0x56 => Some(Mnemonic::JUMP),
+            0x57 => Some(Mnemonic::JUMPI),
+            0x58 => Some(Mnemonic::PC),
+            0x59 => Some(Mnemonic::MSIZE),
+            0x5A => Some(Mnemonic::GAS),
+            0x5B => Some(Mnemonic::JUMPDEST),
+            0x5C => Some(Mnemonic::TLOAD),
+            0x5D => Some(Mnemonic::TSTORE),
+            0x5E => Some(Mnemonic::MCOPY),
+            0x5F => Some(Mnemonic::PUSH0),
+            0x60 => Some(Mnemonic::PUSH1),
+            0x61 => Some(Mnemonic::PUSH2),
+            0x62 => Some(Mnemonic::PUSH3),
+            0x63 => Some(Mnemonic::PUSH4),
+            0x64 => Some(Mnemonic::PUSH5),
+            0x65 => Some(Mnemonic::PUSH6),
+            0x66 => Some(Mnemonic::PUSH7),
+            0x67 => Some(Mnemonic::PUSH8),
+            0x68 => Some(Mnemonic::PUSH9),
+            0x69 => Some(Mnemonic::PUSH10),
+            0x6A => Some(Mnemonic::PUSH11),
+            0x6B => Some(Mnemonic::PUSH12),
+            0x6C => Some(Mnemonic::PUSH13),
+            0x6D => Some(Mnemonic::PUSH14),
+            0x6E => Some(Mnemonic::PUSH15),
+            0x6F => Some(Mnemonic::PUSH16),
+            0x70 => Some(Mnemonic::PUSH17),
+            0x71 => Some(Mnemonic::PUSH18),
+            0x72 => Some(Mnemonic::PUSH19),
+            0x73 => Some(Mnemonic::PUSH20),
+            0x74 => Some(Mnemonic::PUSH21),
+            0x75 => Some(Mnemonic::PUSH22),
+            0x76 => Some(Mnemonic::PUSH23),
+            0x77 => Some(Mnemonic::PUSH24),
+            0x78 => Some(Mnemonic::PUSH25),
+            0x79 => Some(Mnemonic::PUSH26),
+            0x7A => Some(Mnemonic::PUSH27),
+            0x7B => Some(Mnemonic::PUSH28),
+            0x7C => Some(Mnemonic::PUSH29),
+            0x7D => Some(Mnemonic::PUSH30),
+            0x7E => Some(Mnemonic::PUSH31),
+            0x7F => Some(Mnemonic::PUSH32),
+            0x80 => Some(Mnemonic::DUP1),
+            0x81 => Some(Mnemonic::DUP2),
+            0x82 => Some(Mnemonic::DUP3),
+            0x83 => Some(Mnemonic::DUP4),
+            0x84 => Some(Mnemonic::DUP5),
+            0x85 => Some(Mnemonic::DUP6),
+            0x86 => Some(Mnemonic::DUP7),
+            0x87 => Some(Mnemonic::DUP8),
+            0x88 => Some(Mnemonic::DUP9),
+            0x89 => Some(Mnemonic::DUP10),
+            0x8A => Some(Mnemonic::DUP11),
+            0x8B => Some(Mnemonic::DUP12),
+            0x8C => Some(Mnemonic::DUP13),
+            0x8D => Some(Mnemonic::DUP14),
+            0x8E => Some(Mnemonic::DUP15),
+            0x8F => Some(Mnemonic::DUP16),
+            0x90 => Some(Mnemonic::SWAP1),
+            0x91 => Some(Mnemonic::SWAP2),
+            0x92 => Some(Mnemonic::SWAP3),
+            0x93 => Some(Mnemonic::SWAP4),
+            0x94 => Some(Mnemonic::SWAP5),
+            0x95 => Some(Mnemonic::SWAP6),
+            0x96 => Some(Mnemonic::SWAP7),
+            0x97 => Some(Mnemonic::SWAP8),
+            0x98 => Some(Mnemonic::SWAP9),
+            0x99 => Some(Mnemonic::SWAP10),
+            0x9A => Some(Mnemonic::SWAP11),
+            0x9B => Some(Mnemonic::SWAP12),
+            0x9C => Some(Mnemonic::SWAP13),
+            0x9D => Some(Mnemonic::SWAP14),
+            0x9E => Some(Mnemonic::SWAP15),
+            0x9F => Some(Mnemonic::SWAP16),
+            0xA0 => Some(Mnemonic::LOG0),
+            0xA1 => Some(Mnemonic::LOG1),
+            0xA2 => Some(Mnemonic::LOG2),
+            0xA3 => Some(Mnemonic::LOG3),
+            0xA4 => Some(Mnemonic::LOG4),
+            0xF0 => Some(Mnemonic::CREATE),
+            0xF1 => Some(Mnemonic::CALL),
+            0xF2 => Some(Mnemonic::CALLCODE),
+            0xF3 => Some(Mnemonic::RETURN),
+            0xF4 => Some(Mnemonic::DELEGATECALL),
+            0xF5 => Some(Mnemonic::CREATE2),
+            0xFA => Some(Mnemonic::STATICCALL),
+            0xFD => Some(Mnemonic::REVERT),
+            0xFE => Some(Mnemonic::INVALID),
+            0xFF => Some(Mnemonic::SELFDESTRUCT),
+            _ => None,
+        }
+    }
+
+    /// Returns [`true`] if the mnemonic is of the type `PUSHx`.
+    pub fn is_push(mnemonic: Mnemonic) -> bool {
+        matches!(
+            mnemonic,
+            Mnemonic::PUSH0
+                | Mnemonic::PUSH1
+                | Mnemonic::PUSH2
+                | Mnemonic::PUSH3
+                | Mnemonic::PUSH4
+                | Mnemonic::PUSH5
+                | Mnemonic::PUSH6
+                | Mnemonic::PUSH7
+                | Mnemonic::PUSH8
+                | Mnemonic::PUSH9
+                | Mnemonic::PUSH10
+                | Mnemonic::PUSH11
+                | Mnemonic::PUSH12
+                | Mnemonic::PUSH13
+                | Mnemonic::PUSH14
+                | Mnemonic::PUSH15
+                | Mnemonic::PUSH16
+                | Mnemonic::PUSH17
+                | Mnemonic::PUSH18
+                | Mnemonic::PUSH19
+                | Mnemonic::PUSH20
+                | Mnemonic::PUSH21
+                | Mnemonic::PUSH22
+                | Mnemonic::PUSH23
+                | Mnemonic::PUSH24
+                | Mnemonic::PUSH25
+                | Mnemonic::PUSH26
+                | Mnemonic::PUSH27
+                | Mnemonic::PUSH28
+                | Mnemonic::PUSH29
+                | Mnemonic::PUSH30
+                | Mnemonic::PUSH31
+                | Mnemonic::PUSH32
+        )
+    }
+
+    /// Returns [`true`] if the mnemonic halts execution.
+    pub fn is_terminator(mnemonic: Mnemonic) -> bool {
+        matches!(
+            mnemonic,
+            Mnemonic::STOP
+                | Mnemonic::RETURN
+                | Mnemonic::REVERT
+                | Mnemonic::INVALID
+                | Mnemonic::SELFDESTRUCT
+        )
+    }
+}
+
+/// Benchmarks converting every byte into an [`OpCode`].
+fn from_byte(c: &mut Criterion) {
+    #[cfg(not(feature = "eof"))]
+    for byte in 0..=u8::MAX {
+        assert_eq!(OpCode::from_byte(byte), reference::from_byte(byte));
+    }
+
+    let mut group = c.benchmark_group("from_byte");
+    group.bench_function("match", |b| {
+        b.iter(|| {
+            for byte in 0..=u8::MAX {
+                black_box(reference::from_byte(black_box(byte)));
+            }
+        });
+    });
+    group.bench_function("table", |b| {
+        b.iter(|| {
+            for byte in 0..=u8::MAX {
+                black_box(OpCode::from_byte(black_box(byte)));
+            }
+        });
+    });
+    group.finish();
+}
+
+/// Benchmarks a mnemonic predicate against its reference implementation over the mnemonics of
+/// every byte.
+fn predicate(
+    c: &mut Criterion,
+    name: &str,
+    table: fn(&Mnemonic) -> bool,
+    reference: fn(Mnemonic) -> bool,
+) {
+    let mnemonics: Vec<_> = (0..=u8::MAX).filter_map(Mnemonic::from_byte).collect();
+
+    #[cfg(not(feature = "eof"))]
+    for &mnemonic in &mnemonics {
+        assert_eq!(table(&mnemonic), reference(mnemonic), "{mnemonic}");
+    }
+
+    let mut group = c.benchmark_group(name);
+    group.bench_function("match", |b| {
+        b.iter(|| {
+            for &mnemonic in &mnemonics {
+                black_box(reference(black_box(mnemonic)));
+            }
+        });
+    });
+    group.bench_function("table", |b| {
+        b.iter(|| {
+            for mnemonic in &mnemonics {
+                black_box(table(black_box(mnemonic)));
+            }
+        });
+    });
+    group.finish();
+}
+
+/// Benchmarks [`Mnemonic::is_push`].
+fn is_push(c: &mut Criterion) {
+    predicate(c, "is_push", Mnemonic::is_push, reference::is_push);
+}
+
+/// Benchmarks [`Mnemonic::is_terminator`].
+fn is_terminator(c: &mut Criterion) {
+    predicate(
+        c,
+        "is_terminator",
+        Mnemonic::is_terminator,
+        reference::is_terminator,
+    );
+}
+
+criterion_group!(benches, from_byte, is_push, is_terminator);
+criterion_main!(benches);
@@ -1,6 +1,6 @@
 //! Instruction assembly information.
 
-use crate::OpCode;
+use crate::{Effects, Mnemonic, OpCode};
 
 /// An EVM assembly instruction.
 pub trait AssemblyInstruction {
@@ -14,7 +14,7 @@ pub trait AssemblyInstruction {
     /// ```
     #[must_use]
     #[inline]
-    fn size(&self) -> u8 {
+    fn size(&self) -> usize {
         self.immediate_size() + 1
     }
 
@@ -29,7 +29,7 @@ pub trait AssemblyInstruction {
     /// ```
     #[must_use]
     #[inline]
-    fn immediate_size(&self) -> u8 {
+    fn immediate_size(&self) -> usize {
         0
     }
 
@@ -44,6 +44,39 @@ pub trait AssemblyInstruction {
     #[must_use]
     fn opcode(&self) -> OpCode;
 
+    /// Returns the bytes of this instruction's immediate value.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{instruction::{Stop, Push}, AssemblyInstruction};
+    /// assert!(Stop.immediate_bytes().is_empty());
+    /// assert_eq!(Push::new([0x13, 0x37]).immediate_bytes(), &[0x13, 0x37]);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn immediate_bytes(&self) -> &[u8] {
+        &[]
+    }
+
+    /// Encodes this instruction by appending its opcode and immediate value to `buffer`.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{instruction::{Gas, Push}, AssemblyInstruction, Instruction};
+    /// let mut buffer = vec![];
+    /// Push::new([0x13, 0x37]).encode_into(&mut buffer);
+    /// Instruction::Gas(Gas).encode_into(&mut buffer);
+    /// assert_eq!(buffer, [0x61, 0x13, 0x37, 0x5A]);
+    /// ```
+    #[inline]
+    fn encode_into<E: Extend<u8>>(&self, buffer: &mut E)
+    where
+        Self: Sized,
+    {
+        buffer.extend(core::iter::once(self.opcode().into_byte()));
+        buffer.extend(self.immediate_bytes().iter().copied());
+    }
+
     /// Returns [`true`] if this instruction is of the type `PUSHx`.
     ///
     /// # Example
@@ -128,4 +161,76 @@ pub trait AssemblyInstruction {
     fn is_control_flow(&self) -> bool {
         self.opcode().is_control_flow()
     }
+
+    /// Returns the amount of stack items this instruction reads (pops).
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{AssemblyInstruction, instruction::{Dup, Return}};
+    /// assert_eq!(Return.stack_inputs(), 2);
+    /// assert_eq!(Dup::<5>::new().stack_inputs(), 5);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn stack_inputs(&self) -> u8 {
+        self.opcode().stack_inputs()
+    }
+
+    /// Returns the amount of stack items this instruction writes (pushes).
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{AssemblyInstruction, instruction::{Dup, Return}};
+    /// assert_eq!(Return.stack_outputs(), 0);
+    /// assert_eq!(Dup::<5>::new().stack_outputs(), 6);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn stack_outputs(&self) -> u8 {
+        self.opcode().stack_outputs()
+    }
+
+    /// Returns the names of the stack items this instruction reads, starting from the top of the
+    /// stack.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{AssemblyInstruction, instruction::Return};
+    /// assert_eq!(Return.stack_operands(), &["offset", "size"]);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn stack_operands(&self) -> &'static [&'static str] {
+        self.opcode().stack_operands()
+    }
+
+    /// Returns the effects of executing this instruction.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{AssemblyInstruction, Effects, instruction::{Push, SStore}};
+    /// assert!(SStore.effects().is_state_modifying());
+    /// assert!(Push::<1>::new([0x2A]).effects().is_pure());
+    /// ```
+    #[must_use]
+    #[inline]
+    fn effects(&self) -> Effects {
+        self.opcode().effects()
+    }
+}
+
+/// An instruction whose [`Mnemonic`] is known at compile time.
+///
+/// This is implemented for every instruction except [`Unknown`](crate::instruction::Unknown).
+///
+/// # Example
+/// ```
+/// # use oculars_asm2::{instruction::{Dup, Gas, Push}, KnownInstruction, Mnemonic};
+/// assert_eq!(Gas::MNEMONIC, Mnemonic::GAS);
+/// assert_eq!(Push::<4>::MNEMONIC, Mnemonic::PUSH4);
+/// assert_eq!(Dup::<16>::MNEMONIC, Mnemonic::DUP16);
+/// ```
+pub trait KnownInstruction: AssemblyInstruction {
+    /// [`Mnemonic`] of this instruction.
+    const MNEMONIC: Mnemonic;
 }
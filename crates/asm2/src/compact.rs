@@ -0,0 +1,262 @@
+//! Compact instruction representation.
+
+use crate::{
+    AssemblyInstruction, Instruction, OpCode, OpInfo,
+    decode::{self, DecodeError},
+    instruction::Unknown,
+};
+
+/// An instruction stored as its opcode and a borrowed immediate value.
+///
+/// [`Instruction`] has to be large enough to hold a `PUSH32` immediate inline, while a
+/// `CompactInstruction` only borrows its immediate value from the decoded bytecode (or from the
+/// typed [`Instruction`] it was created from), which makes it well suited for storing long lists
+/// of instructions.
+///
+/// A `CompactInstruction` decoded with [`CompactInstruction::decode_padded`] may hold an immediate
+/// value cut off by the end of the bytecode, which is zero padded when it is converted into a
+/// typed [`Instruction`]. Conversions never fail.
+///
+/// # Example
+/// ```
+/// # use oculars_asm2::{instruction::Push, AssemblyInstruction, CompactInstruction, Instruction};
+/// let (compact, size) = CompactInstruction::decode(&[0x61, 0x13, 0x37, 0x00]).unwrap();
+/// assert_eq!(size, 3);
+/// assert_eq!(compact.immediate_bytes(), &[0x13, 0x37]);
+/// assert_eq!(compact.to_instruction(), Instruction::Push2(Push::new([0x13, 0x37])));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompactInstruction<'a> {
+    /// Opcode of the instruction.
+    opcode: OpCode,
+    /// The immediate value of the instruction, complete unless cut off by the end of the bytecode.
+    immediate: &'a [u8],
+}
+
+impl<'a> CompactInstruction<'a> {
+    /// Creates an unknown instruction, even if `byte` is the opcode of a known mnemonic.
+    ///
+    /// This is the compact counterpart of [`Instruction::Unknown`].
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{instruction::Unknown, CompactInstruction, Instruction, OpCode, AssemblyInstruction};
+    /// let compact = CompactInstruction::unknown(0x5F);
+    /// assert_eq!(compact.opcode(), OpCode::Unknown(0x5F));
+    /// assert_eq!(compact.to_instruction(), Instruction::Unknown(Unknown::new(0x5F)));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn unknown(byte: u8) -> Self {
+        Self {
+            opcode: OpCode::Unknown(byte),
+            immediate: &[],
+        }
+    }
+
+    /// Decodes the first instruction of `bytes`, returning the instruction and its size in bytes.
+    ///
+    /// The immediate value of the instruction is borrowed from `bytes`.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`Instruction::decode`].
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{CompactInstruction, Mnemonic, OpCode, AssemblyInstruction};
+    /// let (compact, size) = CompactInstruction::decode(&[0x5A, 0x00]).unwrap();
+    /// assert_eq!(compact.opcode(), OpCode::Known(Mnemonic::GAS));
+    /// assert_eq!(size, 1);
+    ///
+    /// assert!(CompactInstruction::decode(&[0x61, 0x13]).is_err());
+    /// ```
+    #[inline]
+    pub fn decode(bytes: &'a [u8]) -> Result<(Self, usize), DecodeError> {
+        let Some((&opcode, available)) = bytes.split_first() else {
+            return Err(DecodeError::Empty);
+        };
+        let expected = decode::immediate_size(opcode, available);
+
+        let Some(immediate) = available.get(..expected) else {
+            return Err(DecodeError::TruncatedImmediate {
+                mnemonic: OpInfo::of(opcode)
+                    .mnemonic()
+                    .unwrap_or_else(|| unreachable!("unknown opcodes have no immediate value")),
+                expected,
+                available: available.len(),
+            });
+        };
+
+        Ok((
+            Self {
+                opcode: OpCode::from_byte(opcode),
+                immediate,
+            },
+            1 + expected,
+        ))
+    }
+
+    /// Decodes the first instruction of `bytes` like [`CompactInstruction::decode`], but keeps an
+    /// immediate value cut off by the end of the bytes instead of failing.
+    ///
+    /// The truncated immediate value is borrowed as is and zero padded by
+    /// [`CompactInstruction::to_instruction`], see [`Instruction::decode_padded`].
+    ///
+    /// # Errors
+    /// Returns [`DecodeError::Empty`] if `bytes` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{instruction::Push, AssemblyInstruction, CompactInstruction, Instruction};
+    /// let (compact, size) = CompactInstruction::decode_padded(&[0x61, 0x13]).unwrap();
+    /// assert_eq!(size, 2);
+    /// assert_eq!(compact.immediate_bytes(), &[0x13]);
+    /// assert_eq!(compact.to_instruction(), Instruction::Push2(Push::new([0x13, 0x00])));
+    /// ```
+    #[inline]
+    pub fn decode_padded(bytes: &'a [u8]) -> Result<(Self, usize), DecodeError> {
+        let Some((&opcode, available)) = bytes.split_first() else {
+            return Err(DecodeError::Empty);
+        };
+        let size = decode::immediate_size(opcode, available).min(available.len());
+
+        Ok((
+            Self {
+                opcode: OpCode::from_byte(opcode),
+                immediate: &available[..size],
+            },
+            1 + size,
+        ))
+    }
+
+    /// Converts this instruction into a typed [`Instruction`].
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{instruction::Gas, CompactInstruction, Instruction};
+    /// let instruction = Instruction::Gas(Gas);
+    /// assert_eq!(CompactInstruction::from(&instruction).to_instruction(), instruction);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_instruction(&self) -> Instruction {
+        let opcode = match self.opcode {
+            OpCode::Known(mnemonic) => mnemonic.into_byte(),
+            OpCode::Unknown(byte) => return Instruction::Unknown(Unknown::new(byte)),
+        };
+
+        match decode::decode_padded(opcode, self.immediate) {
+            Ok((instruction, _)) => instruction,
+            Err(_) => unreachable!("truncated immediate values are padded"),
+        }
+    }
+}
+
+impl AssemblyInstruction for CompactInstruction<'_> {
+    #[inline]
+    fn opcode(&self) -> OpCode {
+        self.opcode
+    }
+
+    #[inline]
+    fn immediate_size(&self) -> usize {
+        self.immediate.len()
+    }
+
+    #[inline]
+    fn immediate_bytes(&self) -> &[u8] {
+        self.immediate
+    }
+}
+
+impl<'a> From<&'a Instruction> for CompactInstruction<'a> {
+    #[inline]
+    fn from(instruction: &'a Instruction) -> Self {
+        Self {
+            opcode: instruction.opcode(),
+            immediate: instruction.immediate_bytes(),
+        }
+    }
+}
+
+impl From<CompactInstruction<'_>> for Instruction {
+    #[inline]
+    fn from(compact: CompactInstruction<'_>) -> Self {
+        compact.to_instruction()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruction::Push;
+
+    #[test]
+    fn smaller_than_instruction() {
+        assert!(size_of::<CompactInstruction>() < size_of::<Instruction>());
+    }
+
+    #[test]
+    fn compact_roundtrip() {
+        for byte in 0..=u8::MAX {
+            let mut bytes = vec![byte];
+            bytes.extend([0xAB; 1024]);
+
+            let (instruction, size) = Instruction::decode(&bytes).unwrap();
+            let (compact, compact_size) = CompactInstruction::decode(&bytes).unwrap();
+
+            assert_eq!(compact_size, size);
+            assert_eq!(compact, CompactInstruction::from(&instruction));
+            assert_eq!(compact.opcode(), instruction.opcode());
+            assert_eq!(compact.immediate_bytes(), instruction.immediate_bytes());
+            assert_eq!(compact.to_instruction(), instruction);
+        }
+    }
+
+    #[test]
+    fn decodes_like_instructions() {
+        for byte in 0..=u8::MAX {
+            let bytes = [byte, 0x01, 0x02, 0x03, 0x04];
+
+            for end in 1..=bytes.len() {
+                let bytes = &bytes[..end];
+                let to_instruction =
+                    |(compact, size): (CompactInstruction, usize)| (compact.to_instruction(), size);
+
+                assert_eq!(
+                    CompactInstruction::decode(bytes).map(to_instruction),
+                    Instruction::decode(bytes),
+                    "{bytes:02x?}"
+                );
+                assert_eq!(
+                    CompactInstruction::decode_padded(bytes).map(to_instruction),
+                    Instruction::decode_padded(bytes),
+                    "{bytes:02x?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn compact_encoding() {
+        let push = Instruction::Push4(Push::new([0xDE, 0xAD, 0xBE, 0xEF]));
+        let mut encoded = vec![];
+        CompactInstruction::from(&push).encode_into(&mut encoded);
+        assert_eq!(encoded, [0x63, 0xDE, 0xAD, 0xBE, 0xEF]);
+
+        let unknown = Instruction::Unknown(Unknown::new(0x0C));
+        assert_eq!(
+            Instruction::from(CompactInstruction::from(&unknown)),
+            unknown
+        );
+    }
+
+    #[test]
+    fn reports_errors() {
+        assert_eq!(CompactInstruction::decode(&[]), Err(DecodeError::Empty));
+        assert!(matches!(
+            CompactInstruction::decode(&[0x7F, 0x01]),
+            Err(DecodeError::TruncatedImmediate { .. })
+        ));
+    }
+}
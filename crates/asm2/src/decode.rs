@@ -0,0 +1,264 @@
+//! Instruction decoding.
+
+#[cfg(feature = "eof")]
+use crate::instruction::RJumpV;
+use crate::{Instruction, Mnemonic, OpInfo, defs::instruction::DECODERS};
+use core::fmt::Display;
+
+/// An error that occurs while decoding an instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecodeError {
+    /// There are no bytes to decode.
+    Empty,
+    /// The immediate value is cut off by the end of the bytes.
+    TruncatedImmediate {
+        /// Mnemonic of the truncated instruction.
+        mnemonic: Mnemonic,
+        /// Expected size of the immediate value.
+        expected: usize,
+        /// Amount of immediate bytes that were available.
+        available: usize,
+    },
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no bytes to decode"),
+            Self::TruncatedImmediate {
+                mnemonic,
+                expected,
+                available,
+            } => write!(
+                f,
+                "immediate value of {mnemonic} is truncated (expected {expected} bytes, {available} available)"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+/// Decodes an instruction from the bytes following its opcode.
+pub(crate) trait Decode: Sized {
+    /// Decodes the instruction, `immediate` holds the bytes following the instruction's opcode.
+    fn decode(immediate: &[u8]) -> Result<Self, DecodeError>;
+}
+
+/// Decodes an instruction from its opcode and the bytes following it into an [`Instruction`] and
+/// returns its size.
+pub(crate) type Decoder = fn(u8, &[u8]) -> Result<(Instruction, usize), DecodeError>;
+
+impl Instruction {
+    /// Decodes the first instruction of `bytes`, returning the instruction and its size in bytes.
+    ///
+    /// Bytes that do not represent a known opcode are decoded as [`Instruction::Unknown`].
+    ///
+    /// # Errors
+    /// Returns [`DecodeError::Empty`] if `bytes` is empty and
+    /// [`DecodeError::TruncatedImmediate`] if `bytes` end in the middle of an immediate value.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{Instruction, instruction::{Gas, Push}};
+    /// assert_eq!(Instruction::decode(&[0x5A, 0x00]), Ok((Instruction::Gas(Gas), 1)));
+    /// assert_eq!(
+    ///     Instruction::decode(&[0x61, 0x13, 0x37]),
+    ///     Ok((Instruction::Push2(Push::new([0x13, 0x37])), 3))
+    /// );
+    /// assert!(Instruction::decode(&[0x61, 0x13]).is_err());
+    /// ```
+    #[inline]
+    pub fn decode(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let Some((&opcode, immediate)) = bytes.split_first() else {
+            return Err(DecodeError::Empty);
+        };
+
+        DECODERS[usize::from(opcode)](opcode, immediate)
+    }
+
+    /// Decodes the first instruction of `bytes` like [`Instruction::decode`], but zero pads an
+    /// immediate value cut off by the end of the bytes, like the EVM does when executing a `PUSHx`
+    /// at the end of the code.
+    ///
+    /// The returned size is the amount of decoded bytes, which is smaller than the size of the
+    /// instruction if its immediate value is padded.
+    ///
+    /// # Errors
+    /// Returns [`DecodeError::Empty`] if `bytes` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{Instruction, instruction::Push};
+    /// assert_eq!(
+    ///     Instruction::decode_padded(&[0x61, 0x13]),
+    ///     Ok((Instruction::Push2(Push::new([0x13, 0x00])), 2))
+    /// );
+    /// ```
+    #[inline]
+    pub fn decode_padded(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let Some((&opcode, immediate)) = bytes.split_first() else {
+            return Err(DecodeError::Empty);
+        };
+
+        decode_padded(opcode, immediate)
+            .map(|(instruction, size)| (instruction, size.min(bytes.len())))
+    }
+}
+
+/// Returns the size of the immediate value following `opcode`, which starts with `immediate` and
+/// may be cut off by the end of the bytes.
+#[cfg_attr(
+    not(feature = "eof"),
+    expect(unused_variables, reason = "only `RJUMPV` reads its immediate value")
+)]
+pub(crate) fn immediate_size(opcode: u8, immediate: &[u8]) -> usize {
+    match OpInfo::of(opcode).immediate_size() {
+        Some(size) => usize::from(size),
+        #[cfg(feature = "eof")]
+        None => RJumpV::immediate_size_of(immediate),
+        #[cfg(not(feature = "eof"))]
+        None => unreachable!("only EOF instructions have immediate values of variable size"),
+    }
+}
+
+/// Largest immediate value of any instruction, the `RJUMPV` jump table with 256 offsets.
+const MAX_IMMEDIATE_SIZE: usize = 1 + 256 * 2;
+
+/// Decodes an instruction from its opcode and the bytes following it, zero padding a truncated
+/// immediate value, and returns the instruction and its size.
+pub(crate) fn decode_padded(
+    opcode: u8,
+    immediate: &[u8],
+) -> Result<(Instruction, usize), DecodeError> {
+    let decode = DECODERS[usize::from(opcode)];
+
+    match decode(opcode, immediate) {
+        Err(DecodeError::TruncatedImmediate { expected, .. }) if expected <= MAX_IMMEDIATE_SIZE => {
+            let mut padded = [0; MAX_IMMEDIATE_SIZE];
+            padded[..immediate.len()].copy_from_slice(immediate);
+            decode(opcode, &padded[..expected])
+        }
+        result => result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AssemblyInstruction,
+        instruction::{Dup, Log, Push, Swap, Unknown},
+    };
+
+    #[test]
+    fn decodes_every_byte() {
+        for byte in 0..=u8::MAX {
+            // Long enough for the largest `RJUMPV` jump table.
+            let mut bytes = vec![byte];
+            bytes.extend([0xAB; 1024]);
+
+            let (instruction, size) = Instruction::decode(&bytes).unwrap();
+            assert_eq!(instruction.opcode().into_byte(), byte);
+            assert_eq!(size, instruction.size());
+            assert_eq!(instruction.immediate_bytes(), &bytes[1..size]);
+        }
+    }
+
+    #[test]
+    fn decodes_instructions() {
+        assert_eq!(
+            Instruction::decode(&[0x5F]),
+            Ok((Instruction::Push0(Push::new([])), 1))
+        );
+        assert_eq!(
+            Instruction::decode(&[0x8F]),
+            Ok((Instruction::Dup16(Dup::new()), 1))
+        );
+        assert_eq!(
+            Instruction::decode(&[0x90]),
+            Ok((Instruction::Swap1(Swap::new()), 1))
+        );
+        assert_eq!(
+            Instruction::decode(&[0xA4]),
+            Ok((Instruction::Log4(Log::new()), 1))
+        );
+        assert_eq!(
+            Instruction::decode(&[0x0F]),
+            Ok((Instruction::Unknown(Unknown::new(0x0F)), 1))
+        );
+    }
+
+    #[test]
+    fn pads_truncated_immediates() {
+        assert_eq!(
+            Instruction::decode_padded(&[0x7F, 0x01]),
+            Ok((
+                Instruction::Push32(Push::new(core::array::from_fn(|i| u8::from(i == 0)))),
+                2
+            ))
+        );
+        assert_eq!(
+            Instruction::decode_padded(&[0x60]),
+            Ok((Instruction::Push1(Push::new([0])), 1))
+        );
+        assert_eq!(
+            Instruction::decode_padded(&[0x61, 0x13, 0x37, 0x00]),
+            Instruction::decode(&[0x61, 0x13, 0x37, 0x00])
+        );
+        assert_eq!(Instruction::decode_padded(&[]), Err(DecodeError::Empty));
+    }
+
+    #[cfg(feature = "eof")]
+    #[test]
+    fn pads_truncated_jump_tables() {
+        let (instruction, size) = Instruction::decode_padded(&[0xE2]).unwrap();
+        assert_eq!(instruction.immediate_bytes(), [0, 0, 0]);
+        assert_eq!(size, 1);
+
+        let (instruction, size) = Instruction::decode_padded(&[0xE2, 0x01, 0xAA]).unwrap();
+        assert_eq!(instruction.immediate_bytes(), [0x01, 0xAA, 0, 0, 0]);
+        assert_eq!(size, 3);
+    }
+
+    #[test]
+    fn reports_errors() {
+        assert_eq!(Instruction::decode(&[]), Err(DecodeError::Empty));
+        assert_eq!(
+            Instruction::decode(&[0x7F, 0x01]),
+            Err(DecodeError::TruncatedImmediate {
+                mnemonic: Mnemonic::PUSH32,
+                expected: 32,
+                available: 1
+            })
+        );
+        assert_eq!(
+            Instruction::decode(&[0x60]).unwrap_err().to_string(),
+            "immediate value of PUSH1 is truncated (expected 1 bytes, 0 available)"
+        );
+    }
+
+    #[test]
+    fn encode_decode_roundtrip() {
+        let bytecode = [
+            0x60, 0x80, 0x60, 0x40, 0x52, 0x34, 0x80, 0x15, 0x61, 0x00, 0x0F, 0x57, 0x5F, 0x80,
+            0xFD, 0x5B, 0x50, 0x0C, 0xFE,
+        ];
+
+        let mut instructions = vec![];
+        let mut offset = 0;
+        while offset < bytecode.len() {
+            let (instruction, size) = Instruction::decode(&bytecode[offset..]).unwrap();
+            instructions.push(instruction);
+            offset += size;
+        }
+
+        let mut encoded = vec![];
+        for instruction in &instructions {
+            instruction.encode_into(&mut encoded);
+        }
+
+        assert_eq!(instructions.len(), 15);
+        assert_eq!(encoded, bytecode);
+    }
+}
@@ -1,14 +1,37 @@
-use crate::instruction::{Dup, Log, Push, Swap, Unknown};
+//! Instruction, mnemonic and opcode definitions.
 
-/// Defines the `Mnemonic` enum and implements a `VARIANTS` constant, a byte conversion and [`std::fmt::Display`] for the created enum.
+#[cfg(feature = "eof")]
+use crate::instruction::{
+    CallF, DataLoadN, DupN, EofCreate, Exchange, JumpF, RJump, RJumpI, RJumpV, ReturnContract,
+    SwapN,
+};
+use crate::{
+    AssemblyInstruction,
+    decode::{Decode, Decoder},
+    effects::Effects,
+    instruction::{Dup, Log, Push, Swap, Unknown},
+};
+
+/// Counts the amount of token trees passed to it.
+macro_rules! count_tts {
+    (@one $_tt: tt) => { 1 };
+    ($($tt: tt)*) => { 0 $(+ count_tts!(@one $tt))* };
+}
+
+/// Defines the `Mnemonic` enum and implements a `VARIANTS` constant, stack information, effects
+/// and [`core::fmt::Display`] for the created enum.
+///
+/// Attributes in front of a mnemonic (e.g. `#[cfg(...)]`) are applied to everything generated for
+/// it. Former names of a mnemonic follow its documentation after `aka`, separated by `|`.
 macro_rules! define_mnemonics {
-    ($($name: ident = $opcode: literal / $doc: literal),+) => {
+    ($($(#[$meta: meta])* $name: ident = $opcode: literal / [$($operand: tt),*] -> $outputs: literal / {$($effect: ident)|*} / $doc: literal $(/ aka $($alias: ident)|+)?),+) => {
         /// EVM operation code mnemonic.
         #[repr(u8)]
         #[non_exhaustive]
         #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
         pub enum Mnemonic {
             $(
+                $(#[$meta])*
                 #[doc = $doc]
                 $name = $opcode
             ),+
@@ -26,35 +49,143 @@ macro_rules! define_mnemonics {
             /// assert_eq!(Mnemonic::VARIANTS[0], Mnemonic::STOP);
             /// assert_eq!(Mnemonic::VARIANTS[2], Mnemonic::MUL);
             /// ```
-            pub const VARIANTS: &[Self] = &[$(Self::$name),+];
+            pub const VARIANTS: &[Self] = &[$($(#[$meta])* Self::$name),+];
 
-            /// Attempts to parse a byte as a mnemonic. Returns [`None`] if the byte is not a known
-            /// mnemonic.
+            /// Returns the name of this mnemonic.
             ///
             /// # Example
             /// ```
             /// # use oculars_asm2::Mnemonic;
-            /// assert_eq!(Mnemonic::from_byte(0x5A), Some(Mnemonic::GAS));
-            /// assert_eq!(Mnemonic::from_byte(0xF), None);
+            /// assert_eq!(Mnemonic::KECCAK256.name(), "KECCAK256");
             /// ```
             #[must_use]
-            pub const fn from_byte(byte: u8) -> Option<Self> {
-                match byte {
+            #[inline]
+            pub const fn name(&self) -> &'static str {
+                match self {
                     $(
-                        $opcode => Some(Self::$name),
+                        $(#[$meta])*
+                        Self::$name => stringify!($name),
                     )+
-                    _ => None
                 }
             }
-        }
 
-        impl std::fmt::Display for Mnemonic {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "{}", match self {
+            /// Returns the names this mnemonic was known by in the past.
+            ///
+            /// Aliases are accepted when parsing a mnemonic, but never used for formatting.
+            ///
+            /// # Example
+            /// ```
+            /// # use oculars_asm2::Mnemonic;
+            /// assert_eq!(Mnemonic::KECCAK256.aliases(), &["SHA3"]);
+            /// assert_eq!(Mnemonic::PREVRANDAO.aliases(), &["DIFFICULTY"]);
+            /// assert!(Mnemonic::GAS.aliases().is_empty());
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn aliases(&self) -> &'static [&'static str] {
+                match self {
                     $(
-                        Self::$name => stringify!($name),
+                        $(#[$meta])*
+                        Self::$name => &[$($(stringify!($alias)),+)?],
                     )+
-                })
+                }
+            }
+
+            /// Returns the amount of stack items this mnemonic reads (pops).
+            ///
+            /// For `DUPx` and `SWAPx` this is the depth of the stack they touch.
+            ///
+            /// # Example
+            /// ```
+            /// # use oculars_asm2::Mnemonic;
+            /// assert_eq!(Mnemonic::ADD.stack_inputs(), 2);
+            /// assert_eq!(Mnemonic::DUP3.stack_inputs(), 3);
+            /// assert_eq!(Mnemonic::SWAP3.stack_inputs(), 4);
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn stack_inputs(&self) -> u8 {
+                match self {
+                    $(
+                        $(#[$meta])*
+                        Self::$name => count_tts!($($operand)*),
+                    )+
+                }
+            }
+
+            /// Returns the amount of stack items this mnemonic writes (pushes).
+            ///
+            /// For `DUPx` and `SWAPx` this includes the items that are left in place.
+            ///
+            /// # Example
+            /// ```
+            /// # use oculars_asm2::Mnemonic;
+            /// assert_eq!(Mnemonic::ADD.stack_outputs(), 1);
+            /// assert_eq!(Mnemonic::DUP3.stack_outputs(), 4);
+            /// assert_eq!(Mnemonic::SWAP3.stack_outputs(), 4);
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn stack_outputs(&self) -> u8 {
+                match self {
+                    $(
+                        $(#[$meta])*
+                        Self::$name => $outputs,
+                    )+
+                }
+            }
+
+            /// Returns the names of the stack items this mnemonic reads, starting from the top of
+            /// the stack.
+            ///
+            /// Items that are only skipped over (by `DUPx` and `SWAPx`) are named `_`.
+            ///
+            /// # Example
+            /// ```
+            /// # use oculars_asm2::Mnemonic;
+            /// assert_eq!(Mnemonic::RETURN.stack_operands(), &["offset", "size"]);
+            /// assert_eq!(Mnemonic::SWAP2.stack_operands(), &["a", "_", "b"]);
+            /// assert!(Mnemonic::GAS.stack_operands().is_empty());
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn stack_operands(&self) -> &'static [&'static str] {
+                match self {
+                    $(
+                        $(#[$meta])*
+                        Self::$name => &[$(stringify!($operand)),*],
+                    )+
+                }
+            }
+
+            /// Returns the effects of executing this mnemonic.
+            ///
+            /// Stack effects are derived from the [stack inputs](Self::stack_inputs) and
+            /// [outputs](Self::stack_outputs).
+            ///
+            /// # Example
+            /// ```
+            /// # use oculars_asm2::{Effects, Mnemonic};
+            /// assert_eq!(Mnemonic::ADD.effects(), Effects::STACK_READ | Effects::STACK_WRITE);
+            /// assert!(Mnemonic::SLOAD.effects().contains(Effects::STORAGE_READ));
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn effects(&self) -> Effects {
+                let effects = match self {
+                    $(
+                        $(#[$meta])*
+                        Self::$name => Effects::NONE$(.union(Effects::$effect))*,
+                    )+
+                };
+
+                effects.union(Effects::stack(self.stack_inputs(), self.stack_outputs()))
+            }
+        }
+
+        impl core::fmt::Display for Mnemonic {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{}", self.name())
             }
         }
     };
@@ -62,32 +193,93 @@ macro_rules! define_mnemonics {
 
 /// Defines an instruction by creating a struct and implementing [`crate::AssemblyInstruction`] for the
 /// struct.
+///
+/// Instructions prefixed with `legacy` also get conversions from and to their `oculars-asm`
+/// counterpart.
 macro_rules! define_instruction {
-    ($name: ident = $mnemonic: ident / $doc: literal) => {
+    (legacy $name: ident = $mnemonic: ident / $doc: literal) => {
+        define_instruction!($name = $mnemonic / $doc);
+
+        #[cfg(feature = "legacy")]
+        impl From<$name> for asm::instruction::$name {
+            #[inline]
+            fn from(_: $name) -> Self {
+                Self
+            }
+        }
+
+        #[cfg(feature = "legacy")]
+        impl From<asm::instruction::$name> for $name {
+            #[inline]
+            fn from(_: asm::instruction::$name) -> Self {
+                Self
+            }
+        }
+    };
+    (legacy $name: ident = $mnemonic: ident / $doc: literal, !) => {};
+    ($(#[$meta: meta])* $name: ident = $mnemonic: ident / $doc: literal) => {
+        $(#[$meta])*
         #[doc = $doc]
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
         pub struct $name;
 
+        $(#[$meta])*
         impl $crate::assembly::AssemblyInstruction for $name {
             fn opcode(&self) -> $crate::opcode::OpCode {
                 $crate::opcode::OpCode::Known($crate::mnemonic::Mnemonic::$mnemonic)
             }
         }
 
+        $(#[$meta])*
+        impl $crate::assembly::KnownInstruction for $name {
+            const MNEMONIC: $crate::mnemonic::Mnemonic = $crate::mnemonic::Mnemonic::$mnemonic;
+        }
+
+        $(#[$meta])*
+        impl $crate::decode::Decode for $name {
+            #[inline]
+            fn decode(_: &[u8]) -> Result<Self, $crate::decode::DecodeError> {
+                Ok(Self)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        $(#[$meta])*
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $crate::serialization::serialize_instruction(self, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        $(#[$meta])*
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $crate::serialization::deserialize_instruction(
+                    deserializer,
+                    self::AssemblyInstruction::opcode(&Self),
+                )
+            }
+        }
+
+        $(#[$meta])*
         $crate::fmt::forward_opcode_fmt!($name, Display, LowerHex, UpperHex, Binary, Octal);
     };
     // Don't define an instruction struct if there is a `!` argument present.
-    // This allows for manual implementation of instructions that can be generic (like `Push<N>`).
-    ($name: ident = $mnemonic: ident / $doc: literal, !) => {};
+    // This allows for manual implementation of instructions that can be generic (like `Push<N>`)
+    // or that carry an immediate value (like `RJump`).
+    ($(#[$meta: meta])* $name: ident = $mnemonic: ident / $doc: literal, !) => {};
 }
 
 /// Creates an enumeration containing all instructions.
 macro_rules! define_instructions_enum {
-    ($($name: ident, $value: path =/ $doc: literal),+) => {
+    ($($(#[$meta: meta])* $name: ident, $value: path =/ $doc: literal),+) => {
         /// An EVM instruction.
-        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
         pub enum Instruction {
             $(
+                $(#[$meta])*
                 #[doc = $doc]
                 $name($value),
             )+
@@ -99,17 +291,17 @@ macro_rules! define_instructions_enum {
 
 /// Creates a macro that matches an instruction and calls a function passing it as the argument.
 macro_rules! define_instruction_matcher {
-    (($d: tt) $($name: ident),+) => {
+    (($d: tt) $($(#[$meta: meta])* $name: ident),+) => {
         pub(crate) mod macros {
             /// Matches an instruction and calls the specified function, passing the instruction as the
             /// first argument and any optional arguments.
-            #[macro_export]
             macro_rules! match_instruction {
                 ($instr: ident, $fn: path $d(,$arg: expr)*) => {{
                     use $crate::Instruction;
 
                     match $instr {
                         $(
+                            $(#[$meta])*
                             Instruction::$name(i) => $fn(i $d(,$arg)*),
                         )+
                         Instruction::Unknown(i) => $fn(i $d(,$arg)*)
@@ -117,211 +309,414 @@ macro_rules! define_instruction_matcher {
                 }}
             }
 
-            pub use match_instruction;
+            pub(crate) use match_instruction;
         }
     };
-    ($($name: ident),+) => {
-        define_instruction_matcher!(($) $($name),+);
+    ($($(#[$meta: meta])* $name: ident),+) => {
+        define_instruction_matcher!(($) $($(#[$meta])* $name),+);
+    };
+}
+
+/// Creates a table of instruction decoders indexed by opcode.
+macro_rules! define_decoders {
+    ($($(#[$meta: meta])* $name: ident, $struct: path = $opcode: literal),+) => {
+        /// Instruction decoders indexed by opcode.
+        pub(crate) static DECODERS: [Decoder; 256] = {
+            let mut decoders: [Decoder; 256] =
+                [|opcode, _| Ok((Instruction::Unknown(Unknown::new(opcode)), 1)); 256];
+
+            $(
+                $(#[$meta])*
+                {
+                    decoders[$opcode] = |_, immediate| {
+                        let instruction = <$struct as Decode>::decode(immediate)?;
+                        let size = instruction.size();
+                        Ok((Instruction::$name(instruction), size))
+                    };
+                }
+            )+
+
+            decoders
+        };
     };
 }
 
 /// Defines the lists of instructions, mnemonics and additional helpers.
+///
+/// The instructions after `eof:` are only valid inside of EOF containers and are only defined if
+/// the `eof` feature is enabled. They have no `oculars-asm` counterpart.
 macro_rules! define_instructions {
-    ($($mnemonic: ident, $name: ident, $struct: path $([$dont: tt])? = $opcode: literal / $doc: literal),+) => {
+    (
+        $($mnemonic: ident, $name: ident, $struct: path $([$dont: tt])? = $opcode: literal / [$($operand: tt),*] -> $outputs: literal / {$($effect: ident)|*} / $doc: literal $(/ aka $($alias: ident)|+)?),+;
+        eof: $($eof_mnemonic: ident, $eof_name: ident, $eof_struct: path $([$eof_dont: tt])? = $eof_opcode: literal / [$($eof_operand: tt),*] -> $eof_outputs: literal / {$($eof_effect: ident)|*} / $eof_doc: literal),+
+    ) => {
         pub mod mnemonic {
-            define_mnemonics!($($mnemonic = $opcode / $doc),+);
+            use super::*;
+
+            define_mnemonics!(
+                $($mnemonic = $opcode / [$($operand),*] -> $outputs / {$($effect)|*} / $doc $(/ aka $($alias)|+)?),+,
+                $(#[cfg(feature = "eof")] $eof_mnemonic = $eof_opcode / [$($eof_operand),*] -> $eof_outputs / {$($eof_effect)|*} / $eof_doc),+
+            );
+
+            #[cfg(feature = "legacy")]
+            impl TryFrom<Mnemonic> for asm::opcode::Mnemonic {
+                type Error = Mnemonic;
+
+                /// Converts a mnemonic to its legacy counterpart, returning the mnemonic back if it
+                /// is an EOF mnemonic.
+                fn try_from(mnemonic: Mnemonic) -> Result<Self, Self::Error> {
+                    match mnemonic {
+                        $(
+                            Mnemonic::$mnemonic => Ok(Self::$mnemonic),
+                        )+
+                        $(
+                            #[cfg(feature = "eof")]
+                            Mnemonic::$eof_mnemonic => Err(mnemonic),
+                        )+
+                    }
+                }
+            }
+
+            #[cfg(feature = "legacy")]
+            impl From<asm::opcode::Mnemonic> for Mnemonic {
+                fn from(mnemonic: asm::opcode::Mnemonic) -> Self {
+                    match mnemonic {
+                        $(
+                            asm::opcode::Mnemonic::$mnemonic => Self::$mnemonic,
+                        )+
+                        // The legacy crate defines a subset of these mnemonics, the legacy enum
+                        // is only marked as non exhaustive.
+                        _ => unreachable!("unsupported legacy mnemonic {mnemonic}"),
+                    }
+                }
+            }
+        }
+
+        pub mod info {
+            use super::mnemonic::Mnemonic;
+            use crate::info::OpInfo;
+
+            /// Information of every opcode byte, indexed by the byte.
+            ///
+            /// # Example
+            /// ```
+            /// # use oculars_asm2::{Mnemonic, OPCODE_TABLE};
+            /// assert_eq!(OPCODE_TABLE[0x5A].mnemonic(), Some(Mnemonic::GAS));
+            /// assert!(OPCODE_TABLE[0x00].is_terminator());
+            /// ```
+            pub const OPCODE_TABLE: &[OpInfo; 256] = &{
+                let mut table = [OpInfo::UNKNOWN; 256];
+
+                $(
+                    table[$opcode] = OpInfo::known(Mnemonic::$mnemonic, false);
+                )+
+                $(
+                    #[cfg(feature = "eof")]
+                    {
+                        table[$eof_opcode] = OpInfo::known(Mnemonic::$eof_mnemonic, true);
+                    }
+                )+
+
+                table
+            };
         }
 
         pub mod instruction {
             use super::*;
 
             $(
-                define_instruction!($name = $mnemonic / $doc $(,$dont)?);
+                define_instruction!(legacy $name = $mnemonic / $doc $(,$dont)?);
+            )+
+            $(
+                define_instruction!(#[cfg(feature = "eof")] $eof_name = $eof_mnemonic / $eof_doc $(,$eof_dont)?);
             )+
 
-            define_instructions_enum!($($name, $struct =/ $doc),+);
-            define_instruction_matcher!($($name),+);
+            define_instructions_enum!(
+                $($name, $struct =/ $doc),+,
+                $(#[cfg(feature = "eof")] $eof_name, $eof_struct =/ $eof_doc),+
+            );
+            define_instruction_matcher!($($name),+, $(#[cfg(feature = "eof")] $eof_name),+);
+            define_decoders!(
+                $($name, $struct = $opcode),+,
+                $(#[cfg(feature = "eof")] $eof_name, $eof_struct = $eof_opcode),+
+            );
+
+            /// Converts an instruction to its legacy counterpart. EOF instructions have no legacy
+            /// counterpart and are converted to [`asm::instruction::Unknown`].
+            #[cfg(feature = "legacy")]
+            impl From<Instruction> for asm::instruction::Instruction {
+                fn from(instruction: Instruction) -> Self {
+                    match instruction {
+                        $(
+                            Instruction::$name(i) => Self::$name(i.into()),
+                        )+
+                        $(
+                            #[cfg(feature = "eof")]
+                            Instruction::$eof_name(i) => {
+                                Self::Unknown(asm::instruction::Unknown(i.opcode().into_byte()))
+                            }
+                        )+
+                        Instruction::Unknown(i) => Self::Unknown(i.into()),
+                    }
+                }
+            }
+
+            #[cfg(feature = "legacy")]
+            impl From<asm::instruction::Instruction> for Instruction {
+                fn from(instruction: asm::instruction::Instruction) -> Self {
+                    match instruction {
+                        $(
+                            asm::instruction::Instruction::$name(i) => Self::$name(i.into()),
+                        )+
+                        asm::instruction::Instruction::Unknown(i) => Self::Unknown(i.into()),
+                        // The legacy crate defines a subset of these instructions, the legacy enum
+                        // is only marked as non exhaustive.
+                        _ => unreachable!("unsupported legacy instruction {instruction}"),
+                    }
+                }
+            }
         }
     };
 }
 
 define_instructions!(
-    STOP,             Stop,             Stop =              0x00 /     "Halts execution.",
-    ADD,              Add,              Add =               0x01 /     "Addition operation.",
-    MUL,              Mul,              Mul =               0x02 /     "Multiplication operation.",
-    SUB,              Sub,              Sub =               0x03 /     "Subtraction operation.",
-    DIV,              Div,              Div =               0x04 /     "Integer division operation.",
-    SDIV,             SDiv,             SDiv =              0x05 /     "Signed integer division operation (truncated.",
-    MOD,              Mod,              Mod =               0x06 /     "Modulo remainder operation.",
-    SMOD,             SMod,             SMod =              0x07 /     "Signed modulo remainder operation.",
-    ADDMOD,           AddMod,           AddMod =            0x08 /     "Modulo addition operation.",
-    MULMOD,           MulMod,           MulMod =            0x09 /     "Modulo multiplication operation.",
-    EXP,              Exp,              Exp =               0x0A /     "Exponential operation.",
-    SIGNEXTEND,       SignExtend,       SignExtend =        0x0B /     "Extend length of two's complement signed integer.",
-    LT,               Lt,               Lt =                0x10 /     "Less-than comparison.",
-    GT,               Gt,               Gt =                0x11 /     "Greater-than comparison.",
-    SLT,              SLt,              SLt =               0x12 /     "Signed less-than comparison.",
-    SGT,              SGt,              SGt =               0x13 /     "Signed greater-than comparison.",
-    EQ,               Eq,               Eq =                0x14 /     "Equality comparison.",
-    ISZERO,           IsZero,           IsZero =            0x15 /     "Is-zero comparison.",
-    AND,              And,              And =               0x16 /     "Bitwise AND operation.",
-    OR,               Or,               Or =                0x17 /     "Bitwise OR operation.",
-    XOR,              Xor,              Xor =               0x18 /     "Bitwise XOR operation.",
-    NOT,              Not,              Not =               0x19 /     "Bitwise NOT operation.",
-    BYTE,             Byte,             Byte =              0x1A /     "Retrieve single byte from word.",
-    SHL,              Shl,              Shl =               0x1B /     "Left shift operation.",
-    SHR,              Shr,              Shr =               0x1C /     "Logical right shift operation.",
-    SAR,              Sar,              Sar =               0x1D /     "Arithmetic (signed) right shift operation.",
-    KECCAK256,        Keccak256,        Keccak256 =         0x20 /     "Compute Keccak-256 hash.",
-    ADDRESS,          Address,          Address =           0x30 /     "Get address of currently executing account.",
-    BALANCE,          Balance,          Balance =           0x31 /     "Get balance of the given account.",
-    ORIGIN,           Origin,           Origin =            0x32 /     "Get execution origination address.",
-    CALLER,           Caller,           Caller =            0x33 /     "Get caller address.",
-    CALLVALUE,        CallValue,        CallValue =         0x34 /     "Get deposited value by the instruction transaction responsible for this execution.",
-    CALLDATALOAD,     CallDataLoad,     CallDataLoad =      0x35 /     "Get input data of current environment.",
-    CALLDATASIZE,     CallDataSize,     CallDataSize =      0x36 /     "Get size of input data in current environment.",
-    CALLDATACOPY,     CallDataCopy,     CallDataCopy =      0x37 /     "Copy input data in current environment to memory.",
-    CODESIZE,         CodeSize,         CodeSize =          0x38 /     "Get size of code running in current environment.",
-    CODECOPY,         CodeCopy,         CodeCopy =          0x39 /     "Copy code running in current environment to memory.",
-    GASPRICE,         GasPrice,         GasPrice =          0x3A /     "Get price of gas in current environment.",
-    EXTCODESIZE,      ExtCodeSize,      ExtCodeSize =       0x3B /     "Get size of an account's code.",
-    EXTCODECOPY,      ExtCodeCopy,      ExtCodeCopy =       0x3C /     "Copy an account's code to memory.",
-    RETURNDATASIZE,   ReturnDataSize,   ReturnDataSize =    0x3D /     "Get size of output data from the previous call from the current environment.",
-    RETURNDATACOPY,   ReturnDataCopy,   ReturnDataCopy =    0x3E /     "Copy output data from the previous call to memory.",
-    EXTCODEHASH,      ExtCodeHash,      ExtCodeHash =       0x3F /     "Get hash of an account's code.",
-    BLOCKHASH,        BlockHash,        BlockHash =         0x40 /     "Get the hash of one of the 256 most recent complete blocks.",
-    COINBASE,         CoinBase,         CoinBase =          0x41 /     "Get the block's beneficiary address.",
-    TIMESTAMP,        Timestamp,        Timestamp =         0x42 /     "Get the block's timestamp.",
-    NUMBER,           Number,           Number =            0x43 /     "Get the block's number.",
-    PREVRANDAO,       PrevRandao,       PrevRandao =        0x44 /     "Get the block's difficulty.",
-    GASLIMIT,         GasLimit,         GasLimit =          0x45 /     "Get the block's gas limit.",
-    CHAINID,          ChainId,          ChainId =           0x46 /     "Get the chain ID.",
-    SELFBALANCE,      SelfBalance,      SelfBalance =       0x47 /     "Get balance of currently executing account.",
-    BASEFEE,          BaseFee,          BaseFee =           0x48 /     "Get the base fee.",
-    BLOBHASH,         BlobHash,         BlobHash =          0x49 /     "Get versioned hashes.",
-    BLOBBASEFEE,      BlobBaseFee,      BlobBaseFee =       0x4A /     "Returns the value of the blob base-fee of the current block.",
-    POP,              Pop,              Pop =               0x50 /     "Remove item from stack.",
-    MLOAD,            MLoad,            MLoad =             0x51 /     "Load word from memory.",
-    MSTORE,           MStore,           MStore =            0x52 /     "Save word to memory.",
-    MSTORE8,          MStore8,          MStore8 =           0x53 /     "Save byte to memory.",
-    SLOAD,            SLoad,            SLoad =             0x54 /     "Load word from storage.",
-    SSTORE,           SStore,           SStore =            0x55 /     "Save word to storage.",
-    JUMP,             Jump,             Jump =              0x56 /     "Alter the program counter.",
-    JUMPI,            JumpI,            JumpI =             0x57 /     "Conditionally alter the program counter.",
-    PC,               Pc,               Pc =                0x58 /     "Get the value of the program counter prior to the increment corresponding to this instruction.",
-    MSIZE,            MSize,            MSize =             0x59 /     "Get the size of active memory in bytes.",
-    GAS,              Gas,              Gas =               0x5A /     "Get the amount of available gas including the corresponding reduction for the cost of this instruction.",
-    JUMPDEST,         JumpDest,         JumpDest =          0x5B /     "Mark a valid destination for jumps.",
-    TLOAD,            TLoad,            TLoad =             0x5C /     "Load word from transient storage.",
-    TSTORE,           TStore,           TStore =            0x5D /     "Save word to transient storage.",
-    MCOPY,            MCopy,            MCopy =             0x5E /     "Copy memory areas.",
-    PUSH0,            Push0,            Push<0> [!] =       0x5F /     "Place value 0 on stack.",
-    PUSH1,            Push1,            Push<1> [!] =       0x60 /     "Place 1 byte item on stack.",
-    PUSH2,            Push2,            Push<2> [!] =       0x61 /     "Place 2 byte item on stack.",
-    PUSH3,            Push3,            Push<3> [!] =       0x62 /     "Place 3 byte item on stack.",
-    PUSH4,            Push4,            Push<4> [!] =       0x63 /     "Place 4 byte item on stack.",
-    PUSH5,            Push5,            Push<5> [!] =       0x64 /     "Place 5 byte item on stack.",
-    PUSH6,            Push6,            Push<6> [!] =       0x65 /     "Place 6 byte item on stack.",
-    PUSH7,            Push7,            Push<7> [!] =       0x66 /     "Place 7 byte item on stack.",
-    PUSH8,            Push8,            Push<8> [!] =       0x67 /     "Place 8 byte item on stack.",
-    PUSH9,            Push9,            Push<9> [!] =       0x68 /     "Place 9 byte item on stack.",
-    PUSH10,           Push10,           Push<10> [!] =      0x69 /     "Place 10 byte item on stack.",
-    PUSH11,           Push11,           Push<11> [!] =      0x6A /     "Place 11 byte item on stack.",
-    PUSH12,           Push12,           Push<12> [!] =      0x6B /     "Place 12 byte item on stack.",
-    PUSH13,           Push13,           Push<13> [!] =      0x6C /     "Place 13 byte item on stack.",
-    PUSH14,           Push14,           Push<14> [!] =      0x6D /     "Place 14 byte item on stack.",
-    PUSH15,           Push15,           Push<15> [!] =      0x6E /     "Place 15 byte item on stack.",
-    PUSH16,           Push16,           Push<16> [!] =      0x6F /     "Place 16 byte item on stack.",
-    PUSH17,           Push17,           Push<17> [!] =      0x70 /     "Place 17 byte item on stack.",
-    PUSH18,           Push18,           Push<18> [!] =      0x71 /     "Place 18 byte item on stack.",
-    PUSH19,           Push19,           Push<19> [!] =      0x72 /     "Place 19 byte item on stack.",
-    PUSH20,           Push20,           Push<20> [!] =      0x73 /     "Place 20 byte item on stack.",
-    PUSH21,           Push21,           Push<21> [!] =      0x74 /     "Place 21 byte item on stack.",
-    PUSH22,           Push22,           Push<22> [!] =      0x75 /     "Place 22 byte item on stack.",
-    PUSH23,           Push23,           Push<23> [!] =      0x76 /     "Place 23 byte item on stack.",
-    PUSH24,           Push24,           Push<24> [!] =      0x77 /     "Place 24 byte item on stack.",
-    PUSH25,           Push25,           Push<25> [!] =      0x78 /     "Place 25 byte item on stack.",
-    PUSH26,           Push26,           Push<26> [!] =      0x79 /     "Place 26 byte item on stack.",
-    PUSH27,           Push27,           Push<27> [!] =      0x7A /     "Place 27 byte item on stack.",
-    PUSH28,           Push28,           Push<28> [!] =      0x7B /     "Place 28 byte item on stack.",
-    PUSH29,           Push29,           Push<29> [!] =      0x7C /     "Place 29 byte item on stack.",
-    PUSH30,           Push30,           Push<30> [!] =      0x7D /     "Place 30 byte item on stack.",
-    PUSH31,           Push31,           Push<31> [!] =      0x7E /     "Place 31 byte item on stack.",
-    PUSH32,           Push32,           Push<32> [!] =      0x7F /     "Place 32 byte (full word) item on stack.",
-    DUP1,             Dup1,             Dup<1> [!] =        0x80 /     "Duplicate 1st stack item.",
-    DUP2,             Dup2,             Dup<2> [!] =        0x81 /     "Duplicate 2nd stack item.",
-    DUP3,             Dup3,             Dup<3> [!] =        0x82 /     "Duplicate 3rd stack item.",
-    DUP4,             Dup4,             Dup<4> [!] =        0x83 /     "Duplicate 4th stack item.",
-    DUP5,             Dup5,             Dup<5> [!] =        0x84 /     "Duplicate 5th stack item.",
-    DUP6,             Dup6,             Dup<6> [!] =        0x85 /     "Duplicate 6th stack item.",
-    DUP7,             Dup7,             Dup<7> [!] =        0x86 /     "Duplicate 7th stack item.",
-    DUP8,             Dup8,             Dup<8> [!] =        0x87 /     "Duplicate 8th stack item.",
-    DUP9,             Dup9,             Dup<9> [!] =        0x88 /     "Duplicate 9th stack item.",
-    DUP10,            Dup10,            Dup<10> [!] =       0x89 /     "Duplicate 10th stack item.",
-    DUP11,            Dup11,            Dup<11> [!] =       0x8A /     "Duplicate 11th stack item.",
-    DUP12,            Dup12,            Dup<12> [!] =       0x8B /     "Duplicate 12th stack item.",
-    DUP13,            Dup13,            Dup<13> [!] =       0x8C /     "Duplicate 13th stack item.",
-    DUP14,            Dup14,            Dup<14> [!] =       0x8D /     "Duplicate 14th stack item.",
-    DUP15,            Dup15,            Dup<15> [!] =       0x8E /     "Duplicate 15th stack item.",
-    DUP16,            Dup16,            Dup<16> [!] =       0x8F /     "Duplicate 16th stack item.",
-    SWAP1,            Swap1,            Swap<1> [!] =       0x90 /     "Exchange 1st and 2nd stack items.",
-    SWAP2,            Swap2,            Swap<2> [!] =       0x91 /     "Exchange 1st and 3rd stack items.",
-    SWAP3,            Swap3,            Swap<3> [!] =       0x92 /     "Exchange 1st and 4th stack items.",
-    SWAP4,            Swap4,            Swap<4> [!] =       0x93 /     "Exchange 1st and 5th stack items.",
-    SWAP5,            Swap5,            Swap<5> [!] =       0x94 /     "Exchange 1st and 6th stack items.",
-    SWAP6,            Swap6,            Swap<6> [!] =       0x95 /     "Exchange 1st and 7th stack items.",
-    SWAP7,            Swap7,            Swap<7> [!] =       0x96 /     "Exchange 1st and 8th stack items.",
-    SWAP8,            Swap8,            Swap<8> [!] =       0x97 /     "Exchange 1st and 9th stack items.",
-    SWAP9,            Swap9,            Swap<9> [!] =       0x98 /     "Exchange 1st and 10th stack items.",
-    SWAP10,           Swap10,           Swap<10> [!] =      0x99 /     "Exchange 1st and 11th stack items.",
-    SWAP11,           Swap11,           Swap<11> [!] =      0x9A /     "Exchange 1st and 12th stack items.",
-    SWAP12,           Swap12,           Swap<12> [!] =      0x9B /     "Exchange 1st and 13th stack items.",
-    SWAP13,           Swap13,           Swap<13> [!] =      0x9C /     "Exchange 1st and 14th stack items.",
-    SWAP14,           Swap14,           Swap<14> [!] =      0x9D /     "Exchange 1st and 15th stack items.",
-    SWAP15,           Swap15,           Swap<15> [!] =      0x9E /     "Exchange 1st and 16th stack items.",
-    SWAP16,           Swap16,           Swap<16> [!] =      0x9F /     "Exchange 1st and 17th stack items.",
-    LOG0,             Log0,             Log<0> [!] =        0xA0 /     "Append log record with no topics.",
-    LOG1,             Log1,             Log<1> [!] =        0xA1 /     "Append log record with one topic.",
-    LOG2,             Log2,             Log<2> [!] =        0xA2 /     "Append log record with two topics.",
-    LOG3,             Log3,             Log<3> [!] =        0xA3 /     "Append log record with three topics.",
-    LOG4,             Log4,             Log<4> [!] =        0xA4 /     "Append log record with four topics.",
-    CREATE,           Create,           Create =            0xF0 /     "Create a new account with associated code.",
-    CALL,             Call,             Call =              0xF1 /     "Message-call into an account.",
-    CALLCODE,         CallCode,         CallCode =          0xF2 /     "Message-call into this account with alternative account's code.",
-    RETURN,           Return,           Return =            0xF3 /     "Halt execution returning output data.",
-    DELEGATECALL,     DelegateCall,     DelegateCall =      0xF4 /     "Message-call into this account with an alternative account's code but persisting the current values for sender and value.",
-    CREATE2,          Create2,          Create2 =           0xF5 /     "Create a new account with associated code at a predictable address.",
-    STATICCALL,       StaticCall,       StaticCall =        0xFA /     "Static message-call into an account.",
-    REVERT,           Revert,           Revert =            0xFD /     "Halt execution reverting state changes but returning data and remaining gas.",
-    INVALID,          Invalid,          Invalid =           0xFE /     "Designated invalid instruction.",
-    SELFDESTRUCT,     SelfDestruct,     SelfDestruct =      0xFF /     "Halt execution and register account for later deletion or send all Ether to address (post-Cancun."
+    STOP,             Stop,             Stop =              0x00 / [] -> 0                                      / {HALT}                                       / "Halts execution.",
+    ADD,              Add,              Add =               0x01 / [a, b] -> 1                                  / {}                                           / "Addition operation.",
+    MUL,              Mul,              Mul =               0x02 / [a, b] -> 1                                  / {}                                           / "Multiplication operation.",
+    SUB,              Sub,              Sub =               0x03 / [a, b] -> 1                                  / {}                                           / "Subtraction operation.",
+    DIV,              Div,              Div =               0x04 / [a, b] -> 1                                  / {}                                           / "Integer division operation.",
+    SDIV,             SDiv,             SDiv =              0x05 / [a, b] -> 1                                  / {}                                           / "Signed integer division operation (truncated.",
+    MOD,              Mod,              Mod =               0x06 / [a, b] -> 1                                  / {}                                           / "Modulo remainder operation.",
+    SMOD,             SMod,             SMod =              0x07 / [a, b] -> 1                                  / {}                                           / "Signed modulo remainder operation.",
+    ADDMOD,           AddMod,           AddMod =            0x08 / [a, b, n] -> 1                               / {}                                           / "Modulo addition operation.",
+    MULMOD,           MulMod,           MulMod =            0x09 / [a, b, n] -> 1                               / {}                                           / "Modulo multiplication operation.",
+    EXP,              Exp,              Exp =               0x0A / [base, exponent] -> 1                        / {}                                           / "Exponential operation.",
+    SIGNEXTEND,       SignExtend,       SignExtend =        0x0B / [b, x] -> 1                                  / {}                                           / "Extend length of two's complement signed integer.",
+    LT,               Lt,               Lt =                0x10 / [a, b] -> 1                                  / {}                                           / "Less-than comparison.",
+    GT,               Gt,               Gt =                0x11 / [a, b] -> 1                                  / {}                                           / "Greater-than comparison.",
+    SLT,              SLt,              SLt =               0x12 / [a, b] -> 1                                  / {}                                           / "Signed less-than comparison.",
+    SGT,              SGt,              SGt =               0x13 / [a, b] -> 1                                  / {}                                           / "Signed greater-than comparison.",
+    EQ,               Eq,               Eq =                0x14 / [a, b] -> 1                                  / {}                                           / "Equality comparison.",
+    ISZERO,           IsZero,           IsZero =            0x15 / [a] -> 1                                     / {}                                           / "Is-zero comparison.",
+    AND,              And,              And =               0x16 / [a, b] -> 1                                  / {}                                           / "Bitwise AND operation.",
+    OR,               Or,               Or =                0x17 / [a, b] -> 1                                  / {}                                           / "Bitwise OR operation.",
+    XOR,              Xor,              Xor =               0x18 / [a, b] -> 1                                  / {}                                           / "Bitwise XOR operation.",
+    NOT,              Not,              Not =               0x19 / [a] -> 1                                     / {}                                           / "Bitwise NOT operation.",
+    BYTE,             Byte,             Byte =              0x1A / [i, x] -> 1                                  / {}                                           / "Retrieve single byte from word.",
+    SHL,              Shl,              Shl =               0x1B / [shift, value] -> 1                          / {}                                           / "Left shift operation.",
+    SHR,              Shr,              Shr =               0x1C / [shift, value] -> 1                          / {}                                           / "Logical right shift operation.",
+    SAR,              Sar,              Sar =               0x1D / [shift, value] -> 1                          / {}                                           / "Arithmetic (signed) right shift operation.",
+    KECCAK256,        Keccak256,        Keccak256 =         0x20 / [offset, size] -> 1                          / {MEMORY_READ}                                / "Compute Keccak-256 hash." / aka SHA3,
+    ADDRESS,          Address,          Address =           0x30 / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Get address of currently executing account.",
+    BALANCE,          Balance,          Balance =           0x31 / [address] -> 1                               / {ENVIRONMENT_READ}                           / "Get balance of the given account.",
+    ORIGIN,           Origin,           Origin =            0x32 / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Get execution origination address.",
+    CALLER,           Caller,           Caller =            0x33 / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Get caller address.",
+    CALLVALUE,        CallValue,        CallValue =         0x34 / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Get deposited value by the instruction transaction responsible for this execution.",
+    CALLDATALOAD,     CallDataLoad,     CallDataLoad =      0x35 / [i] -> 1                                     / {ENVIRONMENT_READ}                           / "Get input data of current environment.",
+    CALLDATASIZE,     CallDataSize,     CallDataSize =      0x36 / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Get size of input data in current environment.",
+    CALLDATACOPY,     CallDataCopy,     CallDataCopy =      0x37 / [dest_offset, offset, size] -> 0             / {ENVIRONMENT_READ | MEMORY_WRITE}            / "Copy input data in current environment to memory.",
+    CODESIZE,         CodeSize,         CodeSize =          0x38 / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Get size of code running in current environment.",
+    CODECOPY,         CodeCopy,         CodeCopy =          0x39 / [dest_offset, offset, size] -> 0             / {ENVIRONMENT_READ | MEMORY_WRITE}            / "Copy code running in current environment to memory.",
+    GASPRICE,         GasPrice,         GasPrice =          0x3A / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Get price of gas in current environment.",
+    EXTCODESIZE,      ExtCodeSize,      ExtCodeSize =       0x3B / [address] -> 1                               / {ENVIRONMENT_READ}                           / "Get size of an account's code.",
+    EXTCODECOPY,      ExtCodeCopy,      ExtCodeCopy =       0x3C / [address, dest_offset, offset, size] -> 0    / {ENVIRONMENT_READ | MEMORY_WRITE}            / "Copy an account's code to memory.",
+    RETURNDATASIZE,   ReturnDataSize,   ReturnDataSize =    0x3D / [] -> 1                                      / {RETURN_DATA_READ}                           / "Get size of output data from the previous call from the current environment.",
+    RETURNDATACOPY,   ReturnDataCopy,   ReturnDataCopy =    0x3E / [dest_offset, offset, size] -> 0             / {RETURN_DATA_READ | MEMORY_WRITE}            / "Copy output data from the previous call to memory.",
+    EXTCODEHASH,      ExtCodeHash,      ExtCodeHash =       0x3F / [address] -> 1                               / {ENVIRONMENT_READ}                           / "Get hash of an account's code.",
+    BLOCKHASH,        BlockHash,        BlockHash =         0x40 / [block_number] -> 1                          / {ENVIRONMENT_READ}                           / "Get the hash of one of the 256 most recent complete blocks.",
+    COINBASE,         CoinBase,         CoinBase =          0x41 / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Get the block's beneficiary address.",
+    TIMESTAMP,        Timestamp,        Timestamp =         0x42 / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Get the block's timestamp.",
+    NUMBER,           Number,           Number =            0x43 / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Get the block's number.",
+    PREVRANDAO,       PrevRandao,       PrevRandao =        0x44 / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Get the block's difficulty." / aka DIFFICULTY,
+    GASLIMIT,         GasLimit,         GasLimit =          0x45 / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Get the block's gas limit.",
+    CHAINID,          ChainId,          ChainId =           0x46 / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Get the chain ID.",
+    SELFBALANCE,      SelfBalance,      SelfBalance =       0x47 / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Get balance of currently executing account.",
+    BASEFEE,          BaseFee,          BaseFee =           0x48 / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Get the base fee.",
+    BLOBHASH,         BlobHash,         BlobHash =          0x49 / [index] -> 1                                 / {ENVIRONMENT_READ}                           / "Get versioned hashes.",
+    BLOBBASEFEE,      BlobBaseFee,      BlobBaseFee =       0x4A / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Returns the value of the blob base-fee of the current block.",
+    POP,              Pop,              Pop =               0x50 / [y] -> 0                                     / {}                                           / "Remove item from stack.",
+    MLOAD,            MLoad,            MLoad =             0x51 / [offset] -> 1                                / {MEMORY_READ}                                / "Load word from memory.",
+    MSTORE,           MStore,           MStore =            0x52 / [offset, value] -> 0                         / {MEMORY_WRITE}                               / "Save word to memory.",
+    MSTORE8,          MStore8,          MStore8 =           0x53 / [offset, value] -> 0                         / {MEMORY_WRITE}                               / "Save byte to memory.",
+    SLOAD,            SLoad,            SLoad =             0x54 / [key] -> 1                                   / {STORAGE_READ}                               / "Load word from storage.",
+    SSTORE,           SStore,           SStore =            0x55 / [key, value] -> 0                            / {STORAGE_WRITE}                              / "Save word to storage.",
+    JUMP,             Jump,             Jump =              0x56 / [counter] -> 0                               / {JUMP}                                       / "Alter the program counter.",
+    JUMPI,            JumpI,            JumpI =             0x57 / [counter, b] -> 0                            / {JUMP}                                       / "Conditionally alter the program counter.",
+    PC,               Pc,               Pc =                0x58 / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Get the value of the program counter prior to the increment corresponding to this instruction.",
+    MSIZE,            MSize,            MSize =             0x59 / [] -> 1                                      / {MEMORY_READ}                                / "Get the size of active memory in bytes.",
+    GAS,              Gas,              Gas =               0x5A / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Get the amount of available gas including the corresponding reduction for the cost of this instruction.",
+    JUMPDEST,         JumpDest,         JumpDest =          0x5B / [] -> 0                                      / {}                                           / "Mark a valid destination for jumps.",
+    TLOAD,            TLoad,            TLoad =             0x5C / [key] -> 1                                   / {TRANSIENT_STORAGE_READ}                     / "Load word from transient storage.",
+    TSTORE,           TStore,           TStore =            0x5D / [key, value] -> 0                            / {TRANSIENT_STORAGE_WRITE}                    / "Save word to transient storage.",
+    MCOPY,            MCopy,            MCopy =             0x5E / [dest_offset, offset, size] -> 0             / {MEMORY_READ | MEMORY_WRITE}                 / "Copy memory areas.",
+    PUSH0,            Push0,            Push<0> [!] =       0x5F / [] -> 1                                      / {}                                           / "Place value 0 on stack.",
+    PUSH1,            Push1,            Push<1> [!] =       0x60 / [] -> 1                                      / {}                                           / "Place 1 byte item on stack.",
+    PUSH2,            Push2,            Push<2> [!] =       0x61 / [] -> 1                                      / {}                                           / "Place 2 byte item on stack.",
+    PUSH3,            Push3,            Push<3> [!] =       0x62 / [] -> 1                                      / {}                                           / "Place 3 byte item on stack.",
+    PUSH4,            Push4,            Push<4> [!] =       0x63 / [] -> 1                                      / {}                                           / "Place 4 byte item on stack.",
+    PUSH5,            Push5,            Push<5> [!] =       0x64 / [] -> 1                                      / {}                                           / "Place 5 byte item on stack.",
+    PUSH6,            Push6,            Push<6> [!] =       0x65 / [] -> 1                                      / {}                                           / "Place 6 byte item on stack.",
+    PUSH7,            Push7,            Push<7> [!] =       0x66 / [] -> 1                                      / {}                                           / "Place 7 byte item on stack.",
+    PUSH8,            Push8,            Push<8> [!] =       0x67 / [] -> 1                                      / {}                                           / "Place 8 byte item on stack.",
+    PUSH9,            Push9,            Push<9> [!] =       0x68 / [] -> 1                                      / {}                                           / "Place 9 byte item on stack.",
+    PUSH10,           Push10,           Push<10> [!] =      0x69 / [] -> 1                                      / {}                                           / "Place 10 byte item on stack.",
+    PUSH11,           Push11,           Push<11> [!] =      0x6A / [] -> 1                                      / {}                                           / "Place 11 byte item on stack.",
+    PUSH12,           Push12,           Push<12> [!] =      0x6B / [] -> 1                                      / {}                                           / "Place 12 byte item on stack.",
+    PUSH13,           Push13,           Push<13> [!] =      0x6C / [] -> 1                                      / {}                                           / "Place 13 byte item on stack.",
+    PUSH14,           Push14,           Push<14> [!] =      0x6D / [] -> 1                                      / {}                                           / "Place 14 byte item on stack.",
+    PUSH15,           Push15,           Push<15> [!] =      0x6E / [] -> 1                                      / {}                                           / "Place 15 byte item on stack.",
+    PUSH16,           Push16,           Push<16> [!] =      0x6F / [] -> 1                                      / {}                                           / "Place 16 byte item on stack.",
+    PUSH17,           Push17,           Push<17> [!] =      0x70 / [] -> 1                                      / {}                                           / "Place 17 byte item on stack.",
+    PUSH18,           Push18,           Push<18> [!] =      0x71 / [] -> 1                                      / {}                                           / "Place 18 byte item on stack.",
+    PUSH19,           Push19,           Push<19> [!] =      0x72 / [] -> 1                                      / {}                                           / "Place 19 byte item on stack.",
+    PUSH20,           Push20,           Push<20> [!] =      0x73 / [] -> 1                                      / {}                                           / "Place 20 byte item on stack.",
+    PUSH21,           Push21,           Push<21> [!] =      0x74 / [] -> 1                                      / {}                                           / "Place 21 byte item on stack.",
+    PUSH22,           Push22,           Push<22> [!] =      0x75 / [] -> 1                                      / {}                                           / "Place 22 byte item on stack.",
+    PUSH23,           Push23,           Push<23> [!] =      0x76 / [] -> 1                                      / {}                                           / "Place 23 byte item on stack.",
+    PUSH24,           Push24,           Push<24> [!] =      0x77 / [] -> 1                                      / {}                                           / "Place 24 byte item on stack.",
+    PUSH25,           Push25,           Push<25> [!] =      0x78 / [] -> 1                                      / {}                                           / "Place 25 byte item on stack.",
+    PUSH26,           Push26,           Push<26> [!] =      0x79 / [] -> 1                                      / {}                                           / "Place 26 byte item on stack.",
+    PUSH27,           Push27,           Push<27> [!] =      0x7A / [] -> 1                                      / {}                                           / "Place 27 byte item on stack.",
+    PUSH28,           Push28,           Push<28> [!] =      0x7B / [] -> 1                                      / {}                                           / "Place 28 byte item on stack.",
+    PUSH29,           Push29,           Push<29> [!] =      0x7C / [] -> 1                                      / {}                                           / "Place 29 byte item on stack.",
+    PUSH30,           Push30,           Push<30> [!] =      0x7D / [] -> 1                                      / {}                                           / "Place 30 byte item on stack.",
+    PUSH31,           Push31,           Push<31> [!] =      0x7E / [] -> 1                                      / {}                                           / "Place 31 byte item on stack.",
+    PUSH32,           Push32,           Push<32> [!] =      0x7F / [] -> 1                                      / {}                                           / "Place 32 byte (full word) item on stack.",
+    DUP1,             Dup1,             Dup<1> [!] =        0x80 / [value] -> 2                                 / {}                                           / "Duplicate 1st stack item.",
+    DUP2,             Dup2,             Dup<2> [!] =        0x81 / [_, value] -> 3                              / {}                                           / "Duplicate 2nd stack item.",
+    DUP3,             Dup3,             Dup<3> [!] =        0x82 / [_, _, value] -> 4                           / {}                                           / "Duplicate 3rd stack item.",
+    DUP4,             Dup4,             Dup<4> [!] =        0x83 / [_, _, _, value] -> 5                        / {}                                           / "Duplicate 4th stack item.",
+    DUP5,             Dup5,             Dup<5> [!] =        0x84 / [_, _, _, _, value] -> 6                     / {}                                           / "Duplicate 5th stack item.",
+    DUP6,             Dup6,             Dup<6> [!] =        0x85 / [_, _, _, _, _, value] -> 7                  / {}                                           / "Duplicate 6th stack item.",
+    DUP7,             Dup7,             Dup<7> [!] =        0x86 / [_, _, _, _, _, _, value] -> 8               / {}                                           / "Duplicate 7th stack item.",
+    DUP8,             Dup8,             Dup<8> [!] =        0x87 / [_, _, _, _, _, _, _, value] -> 9            / {}                                           / "Duplicate 8th stack item.",
+    DUP9,             Dup9,             Dup<9> [!] =        0x88 / [_, _, _, _, _, _, _, _, value] -> 10        / {}                                           / "Duplicate 9th stack item.",
+    DUP10,            Dup10,            Dup<10> [!] =       0x89 / [_, _, _, _, _, _, _, _, _, value] -> 11     / {}                                           / "Duplicate 10th stack item.",
+    DUP11,            Dup11,            Dup<11> [!] =       0x8A / [_, _, _, _, _, _, _, _, _, _, value] -> 12  / {}                                           / "Duplicate 11th stack item.",
+    DUP12,            Dup12,            Dup<12> [!] =       0x8B / [_, _, _, _, _, _, _, _, _, _, _, value] -> 13 / {}                                           / "Duplicate 12th stack item.",
+    DUP13,            Dup13,            Dup<13> [!] =       0x8C / [_, _, _, _, _, _, _, _, _, _, _, _, value] -> 14 / {}                                           / "Duplicate 13th stack item.",
+    DUP14,            Dup14,            Dup<14> [!] =       0x8D / [_, _, _, _, _, _, _, _, _, _, _, _, _, value] -> 15 / {}                                           / "Duplicate 14th stack item.",
+    DUP15,            Dup15,            Dup<15> [!] =       0x8E / [_, _, _, _, _, _, _, _, _, _, _, _, _, _, value] -> 16 / {}                                           / "Duplicate 15th stack item.",
+    DUP16,            Dup16,            Dup<16> [!] =       0x8F / [_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, value] -> 17 / {}                                           / "Duplicate 16th stack item.",
+    SWAP1,            Swap1,            Swap<1> [!] =       0x90 / [a, b] -> 2                                  / {}                                           / "Exchange 1st and 2nd stack items.",
+    SWAP2,            Swap2,            Swap<2> [!] =       0x91 / [a, _, b] -> 3                               / {}                                           / "Exchange 1st and 3rd stack items.",
+    SWAP3,            Swap3,            Swap<3> [!] =       0x92 / [a, _, _, b] -> 4                            / {}                                           / "Exchange 1st and 4th stack items.",
+    SWAP4,            Swap4,            Swap<4> [!] =       0x93 / [a, _, _, _, b] -> 5                         / {}                                           / "Exchange 1st and 5th stack items.",
+    SWAP5,            Swap5,            Swap<5> [!] =       0x94 / [a, _, _, _, _, b] -> 6                      / {}                                           / "Exchange 1st and 6th stack items.",
+    SWAP6,            Swap6,            Swap<6> [!] =       0x95 / [a, _, _, _, _, _, b] -> 7                   / {}                                           / "Exchange 1st and 7th stack items.",
+    SWAP7,            Swap7,            Swap<7> [!] =       0x96 / [a, _, _, _, _, _, _, b] -> 8                / {}                                           / "Exchange 1st and 8th stack items.",
+    SWAP8,            Swap8,            Swap<8> [!] =       0x97 / [a, _, _, _, _, _, _, _, b] -> 9             / {}                                           / "Exchange 1st and 9th stack items.",
+    SWAP9,            Swap9,            Swap<9> [!] =       0x98 / [a, _, _, _, _, _, _, _, _, b] -> 10         / {}                                           / "Exchange 1st and 10th stack items.",
+    SWAP10,           Swap10,           Swap<10> [!] =      0x99 / [a, _, _, _, _, _, _, _, _, _, b] -> 11      / {}                                           / "Exchange 1st and 11th stack items.",
+    SWAP11,           Swap11,           Swap<11> [!] =      0x9A / [a, _, _, _, _, _, _, _, _, _, _, b] -> 12   / {}                                           / "Exchange 1st and 12th stack items.",
+    SWAP12,           Swap12,           Swap<12> [!] =      0x9B / [a, _, _, _, _, _, _, _, _, _, _, _, b] -> 13 / {}                                           / "Exchange 1st and 13th stack items.",
+    SWAP13,           Swap13,           Swap<13> [!] =      0x9C / [a, _, _, _, _, _, _, _, _, _, _, _, _, b] -> 14 / {}                                           / "Exchange 1st and 14th stack items.",
+    SWAP14,           Swap14,           Swap<14> [!] =      0x9D / [a, _, _, _, _, _, _, _, _, _, _, _, _, _, b] -> 15 / {}                                           / "Exchange 1st and 15th stack items.",
+    SWAP15,           Swap15,           Swap<15> [!] =      0x9E / [a, _, _, _, _, _, _, _, _, _, _, _, _, _, _, b] -> 16 / {}                                           / "Exchange 1st and 16th stack items.",
+    SWAP16,           Swap16,           Swap<16> [!] =      0x9F / [a, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, b] -> 17 / {}                                           / "Exchange 1st and 17th stack items.",
+    LOG0,             Log0,             Log<0> [!] =        0xA0 / [offset, size] -> 0                          / {MEMORY_READ | LOG}                          / "Append log record with no topics.",
+    LOG1,             Log1,             Log<1> [!] =        0xA1 / [offset, size, topic0] -> 0                  / {MEMORY_READ | LOG}                          / "Append log record with one topic.",
+    LOG2,             Log2,             Log<2> [!] =        0xA2 / [offset, size, topic0, topic1] -> 0          / {MEMORY_READ | LOG}                          / "Append log record with two topics.",
+    LOG3,             Log3,             Log<3> [!] =        0xA3 / [offset, size, topic0, topic1, topic2] -> 0  / {MEMORY_READ | LOG}                          / "Append log record with three topics.",
+    LOG4,             Log4,             Log<4> [!] =        0xA4 / [offset, size, topic0, topic1, topic2, topic3] -> 0 / {MEMORY_READ | LOG}                          / "Append log record with four topics.",
+    CREATE,           Create,           Create =            0xF0 / [value, offset, size] -> 1                   / {MEMORY_READ | EXTERNAL_CALL | VALUE_TRANSFER | ACCOUNT_CREATION | RETURN_DATA_WRITE} / "Create a new account with associated code.",
+    CALL,             Call,             Call =              0xF1 / [gas, address, value, args_offset, args_size, ret_offset, ret_size] -> 1 / {MEMORY_READ | MEMORY_WRITE | EXTERNAL_CALL | VALUE_TRANSFER | RETURN_DATA_WRITE} / "Message-call into an account.",
+    CALLCODE,         CallCode,         CallCode =          0xF2 / [gas, address, value, args_offset, args_size, ret_offset, ret_size] -> 1 / {MEMORY_READ | MEMORY_WRITE | EXTERNAL_CALL | RETURN_DATA_WRITE} / "Message-call into this account with alternative account's code.",
+    RETURN,           Return,           Return =            0xF3 / [offset, size] -> 0                          / {MEMORY_READ | RETURN_DATA_WRITE | HALT}     / "Halt execution returning output data.",
+    DELEGATECALL,     DelegateCall,     DelegateCall =      0xF4 / [gas, address, args_offset, args_size, ret_offset, ret_size] -> 1 / {MEMORY_READ | MEMORY_WRITE | EXTERNAL_CALL | RETURN_DATA_WRITE} / "Message-call into this account with an alternative account's code but persisting the current values for sender and value.",
+    CREATE2,          Create2,          Create2 =           0xF5 / [value, offset, size, salt] -> 1             / {MEMORY_READ | EXTERNAL_CALL | VALUE_TRANSFER | ACCOUNT_CREATION | RETURN_DATA_WRITE} / "Create a new account with associated code at a predictable address.",
+    STATICCALL,       StaticCall,       StaticCall =        0xFA / [gas, address, args_offset, args_size, ret_offset, ret_size] -> 1 / {MEMORY_READ | MEMORY_WRITE | EXTERNAL_CALL | RETURN_DATA_WRITE} / "Static message-call into an account.",
+    REVERT,           Revert,           Revert =            0xFD / [offset, size] -> 0                          / {MEMORY_READ | RETURN_DATA_WRITE | HALT}     / "Halt execution reverting state changes but returning data and remaining gas.",
+    INVALID,          Invalid,          Invalid =           0xFE / [] -> 0                                      / {HALT}                                       / "Designated invalid instruction.",
+    SELFDESTRUCT,     SelfDestruct,     SelfDestruct =      0xFF / [address] -> 0                               / {ENVIRONMENT_READ | VALUE_TRANSFER | ACCOUNT_DESTRUCTION | HALT} / "Halt execution and register account for later deletion or send all Ether to address (post-Cancun." / aka SUICIDE;
+eof:
+    DATALOAD,         DataLoad,         DataLoad =          0xD0 / [offset] -> 1                                / {ENVIRONMENT_READ}                           / "Load word from the data section of the current container.",
+    DATALOADN,        DataLoadN,        DataLoadN [!] =     0xD1 / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Load word from the data section of the current container at a static offset.",
+    DATASIZE,         DataSize,         DataSize =          0xD2 / [] -> 1                                      / {ENVIRONMENT_READ}                           / "Get size of the data section of the current container.",
+    DATACOPY,         DataCopy,         DataCopy =          0xD3 / [mem_offset, offset, size] -> 0              / {ENVIRONMENT_READ | MEMORY_WRITE}            / "Copy the data section of the current container to memory.",
+    RJUMP,            RJump,            RJump [!] =         0xE0 / [] -> 0                                      / {JUMP}                                       / "Jump by a relative offset.",
+    RJUMPI,           RJumpI,           RJumpI [!] =        0xE1 / [condition] -> 0                             / {JUMP}                                       / "Conditionally jump by a relative offset.",
+    RJUMPV,           RJumpV,           RJumpV [!] =        0xE2 / [case] -> 0                                  / {JUMP}                                       / "Jump by one of the relative offsets of a jump table.",
+    CALLF,            CallF,            CallF [!] =         0xE3 / [] -> 0                                      / {JUMP}                                       / "Call a code section.",
+    RETF,             RetF,             RetF =              0xE4 / [] -> 0                                      / {JUMP}                                       / "Return from a code section.",
+    JUMPF,            JumpF,            JumpF [!] =         0xE5 / [] -> 0                                      / {JUMP}                                       / "Jump to a code section without returning.",
+    DUPN,             DupN,             DupN [!] =          0xE6 / [] -> 1                                      / {STACK_READ}                                 / "Duplicate the stack item at an immediate depth.",
+    SWAPN,            SwapN,            SwapN [!] =         0xE7 / [] -> 0                                      / {STACK_READ | STACK_WRITE}                   / "Exchange the 1st stack item with the stack item at an immediate depth.",
+    EXCHANGE,         Exchange,         Exchange [!] =      0xE8 / [] -> 0                                      / {STACK_READ | STACK_WRITE}                   / "Exchange two stack items at immediate depths.",
+    EOFCREATE,        EofCreate,        EofCreate [!] =     0xEC / [value, salt, input_offset, input_size] -> 1 / {MEMORY_READ | EXTERNAL_CALL | VALUE_TRANSFER | ACCOUNT_CREATION | RETURN_DATA_WRITE} / "Create a new account from a subcontainer of the current container.",
+    RETURNCONTRACT,   ReturnContract,   ReturnContract [!] = 0xEE / [aux_data_offset, aux_data_size] -> 0       / {MEMORY_READ | HALT}                         / "Halt execution returning a subcontainer as the deployed contract.",
+    EXTCALL,          ExtCall,          ExtCall =           0xF8 / [target_address, input_offset, input_size, value] -> 1 / {MEMORY_READ | EXTERNAL_CALL | VALUE_TRANSFER | RETURN_DATA_WRITE} / "Message-call into an account without forwarding a specific amount of gas.",
+    EXTDELEGATECALL,  ExtDelegateCall,  ExtDelegateCall =   0xF9 / [target_address, input_offset, input_size] -> 1 / {MEMORY_READ | EXTERNAL_CALL | RETURN_DATA_WRITE} / "Message-call into this account with an alternative account's code without forwarding a specific amount of gas.",
+    EXTSTATICCALL,    ExtStaticCall,    ExtStaticCall =     0xFB / [target_address, input_offset, input_size] -> 1 / {MEMORY_READ | EXTERNAL_CALL | RETURN_DATA_WRITE} / "Static message-call into an account without forwarding a specific amount of gas."
 );
 
 #[cfg(test)]
 mod tests {
     use super::{instruction::Add, mnemonic::Mnemonic};
-    use crate::{AssemblyInstruction, OpCode};
+    use crate::{AssemblyInstruction, Effects, OpCode};
 
     #[expect(non_camel_case_types, reason = "le funny")]
+    #[expect(clippy::upper_case_acronyms, reason = "mnemonics are upper case")]
     #[test]
     fn define_mnemonics_works() {
         define_mnemonics!(
-            PAY_VITALIK = 0xF / "Pays Vitalik",
-            REVERT = 0xFD / "Reverts"
+            PAY_VITALIK = 0xF / [amount] -> 0 / {VALUE_TRANSFER} / "Pays Vitalik" / aka TIP | DONATE,
+            REVERT = 0xFD / [offset, size] -> 0 / {MEMORY_READ | HALT} / "Reverts"
         );
 
         assert_eq!(Mnemonic::VARIANTS[0], Mnemonic::PAY_VITALIK);
         assert_eq!(Mnemonic::VARIANTS[1], Mnemonic::REVERT);
-        assert_eq!(Mnemonic::from_byte(0xF), Some(Mnemonic::PAY_VITALIK));
-        assert_eq!(Mnemonic::from_byte(0x5A), None);
         assert_eq!(Mnemonic::PAY_VITALIK.to_string(), "PAY_VITALIK");
+        assert_eq!(Mnemonic::PAY_VITALIK.stack_inputs(), 1);
+        assert_eq!(Mnemonic::PAY_VITALIK.stack_outputs(), 0);
+        assert_eq!(Mnemonic::REVERT.stack_operands(), &["offset", "size"]);
+        assert_eq!(Mnemonic::PAY_VITALIK.aliases(), &["TIP", "DONATE"]);
+        assert!(Mnemonic::REVERT.aliases().is_empty());
+        assert_eq!(
+            Mnemonic::PAY_VITALIK.effects(),
+            Effects::STACK_READ | Effects::VALUE_TRANSFER
+        );
+        assert!(Mnemonic::REVERT.effects().contains(Effects::HALT));
     }
 
     #[test]
     fn instructions_are_defined_properly() {
         assert_eq!(Mnemonic::from_byte(0x1), Some(Mnemonic::ADD));
+        assert_eq!(Mnemonic::from_byte(0xF), None);
         assert_eq!(Mnemonic::ADD.into_byte(), 0x1);
         assert_eq!(Add.size(), 1);
         assert_eq!(Add.opcode(), OpCode::Known(Mnemonic::ADD));
     }
+
+    #[test]
+    fn stack_information_is_consistent() {
+        for mnemonic in Mnemonic::VARIANTS {
+            assert_eq!(
+                mnemonic.stack_operands().len(),
+                usize::from(mnemonic.stack_inputs()),
+                "{mnemonic}"
+            );
+        }
+
+        for (n, dup) in (1..).zip(Mnemonic::VARIANTS.iter().filter(|m| m.is_dup())) {
+            assert_eq!(
+                (dup.stack_inputs(), dup.stack_outputs()),
+                (n, n + 1),
+                "{dup}"
+            );
+        }
+
+        for (n, swap) in (1..).zip(Mnemonic::VARIANTS.iter().filter(|m| m.is_swap())) {
+            assert_eq!(
+                (swap.stack_inputs(), swap.stack_outputs()),
+                (n + 1, n + 1),
+                "{swap}"
+            );
+        }
+
+        assert_eq!(Mnemonic::CALL.stack_inputs(), 7);
+        assert_eq!(Mnemonic::DELEGATECALL.stack_inputs(), 6);
+        assert_eq!(Mnemonic::LOG4.stack_inputs(), 6);
+        assert_eq!(Mnemonic::PUSH32.stack_outputs(), 1);
+    }
 }
@@ -0,0 +1,346 @@
+//! Instruction effects.
+
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not};
+
+/// A set of effects an instruction can have when executed.
+///
+/// # Example
+/// ```
+/// # use oculars_asm2::{Effects, Mnemonic};
+/// let effects = Mnemonic::SSTORE.effects();
+/// assert!(effects.contains(Effects::STORAGE_WRITE | Effects::STACK_READ));
+/// assert!(!effects.contains(Effects::STORAGE_READ));
+/// assert!(effects.is_state_modifying());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Effects(u32);
+
+/// Defines the effect flags and their names.
+macro_rules! define_effects {
+    ($($name: ident = $bit: literal / $doc: literal),+) => {
+        impl Effects {
+            $(
+                #[doc = $doc]
+                pub const $name: Self = Self(1 << $bit);
+            )+
+
+            /// All effects and their names.
+            const NAMED: &[(Self, &'static str)] = &[$((Self::$name, stringify!($name))),+];
+
+            /// All effects.
+            pub const ALL: Self = Self(0 $(| (1 << $bit))+);
+        }
+    };
+}
+
+define_effects!(
+    STACK_READ = 0 / "Reads (pops) stack items.",
+    STACK_WRITE = 1 / "Writes (pushes) stack items.",
+    MEMORY_READ = 2 / "Reads memory.",
+    MEMORY_WRITE = 3 / "Writes memory.",
+    STORAGE_READ = 4 / "Reads persistent storage.",
+    STORAGE_WRITE = 5 / "Writes persistent storage.",
+    TRANSIENT_STORAGE_READ = 6 / "Reads transient storage.",
+    TRANSIENT_STORAGE_WRITE = 7 / "Writes transient storage.",
+    RETURN_DATA_READ = 8 / "Reads the return data of the last call.",
+    RETURN_DATA_WRITE = 9 / "Sets the return data (of the current or a sub context).",
+    ENVIRONMENT_READ = 10
+        / "Reads the execution environment (transaction, block, account or execution state data).",
+    LOG = 11 / "Appends a log record.",
+    EXTERNAL_CALL = 12 / "Executes code in a new context.",
+    VALUE_TRANSFER = 13 / "Can transfer value to another account.",
+    ACCOUNT_CREATION = 14 / "Creates a new account.",
+    ACCOUNT_DESTRUCTION = 15 / "Registers the current account for deletion.",
+    JUMP = 16 / "Alters the program counter.",
+    HALT = 17 / "Halts execution of the current context."
+);
+
+impl Effects {
+    /// No effects.
+    pub const NONE: Self = Self(0);
+
+    /// Effects that modify the state and are therefore disallowed in a static context (EIP-214
+    /// and EIP-1153).
+    pub const STATE_MODIFYING: Self = Self(
+        Self::STORAGE_WRITE.0
+            | Self::TRANSIENT_STORAGE_WRITE.0
+            | Self::LOG.0
+            | Self::VALUE_TRANSFER.0
+            | Self::ACCOUNT_CREATION.0
+            | Self::ACCOUNT_DESTRUCTION.0,
+    );
+
+    /// Returns the stack effects of an instruction reading `inputs` and writing `outputs` stack
+    /// items.
+    #[must_use]
+    #[inline]
+    pub(crate) const fn stack(inputs: u8, outputs: u8) -> Self {
+        let read = if inputs > 0 { Self::STACK_READ.0 } else { 0 };
+        let write = if outputs > 0 { Self::STACK_WRITE.0 } else { 0 };
+
+        Self(read | write)
+    }
+
+    /// Returns the raw bits of the effect set.
+    #[must_use]
+    #[inline]
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns [`true`] if no effects are set.
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns [`true`] if all effects of `other` are set.
+    #[must_use]
+    #[inline]
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns [`true`] if any effect of `other` is set.
+    #[must_use]
+    #[inline]
+    pub const fn intersects(&self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// Returns the effects set in either `self` or `other`.
+    #[must_use]
+    #[inline]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns the effects set in both `self` and `other`.
+    #[must_use]
+    #[inline]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Returns the effects set in `self` but not in `other`.
+    #[must_use]
+    #[inline]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Returns [`true`] if the effects do not go beyond the stack, meaning that the instruction's
+    /// outputs only depend on its inputs.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::Mnemonic;
+    /// assert!(Mnemonic::ADD.effects().is_pure());
+    /// assert!(Mnemonic::PUSH1.effects().is_pure());
+    /// assert!(!Mnemonic::MLOAD.effects().is_pure());
+    /// assert!(!Mnemonic::JUMP.effects().is_pure());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_pure(&self) -> bool {
+        self.difference(Self::STACK_READ.union(Self::STACK_WRITE))
+            .is_empty()
+    }
+
+    /// Returns [`true`] if the effects (may) modify the state.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::Mnemonic;
+    /// assert!(Mnemonic::SSTORE.effects().is_state_modifying());
+    /// assert!(Mnemonic::LOG0.effects().is_state_modifying());
+    /// assert!(!Mnemonic::SLOAD.effects().is_state_modifying());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_state_modifying(&self) -> bool {
+        self.intersects(Self::STATE_MODIFYING)
+    }
+
+    /// Returns [`true`] if the effects are allowed inside a `STATICCALL` (EIP-214).
+    ///
+    /// `CALL` is only allowed if it does not transfer value, which can't be known statically, so
+    /// it's conservatively considered unsafe.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::Mnemonic;
+    /// assert!(Mnemonic::STATICCALL.effects().is_static_call_safe());
+    /// assert!(!Mnemonic::CALL.effects().is_static_call_safe());
+    /// assert!(!Mnemonic::TSTORE.effects().is_static_call_safe());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_static_call_safe(&self) -> bool {
+        !self.is_state_modifying()
+    }
+
+    /// Returns an iterator over the names of the set effects.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::Mnemonic;
+    /// let names: Vec<_> = Mnemonic::MLOAD.effects().names().collect();
+    /// assert_eq!(names, ["STACK_READ", "STACK_WRITE", "MEMORY_READ"]);
+    /// ```
+    pub fn names(self) -> impl Iterator<Item = &'static str> {
+        Self::NAMED
+            .iter()
+            .filter(move |(effect, _)| self.contains(*effect))
+            .map(|(_, name)| *name)
+    }
+}
+
+impl core::fmt::Debug for Effects {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Effects(")?;
+
+        for (i, name) in self.names().enumerate() {
+            if i > 0 {
+                write!(f, " | ")?;
+            }
+            write!(f, "{name}")?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl BitOr for Effects {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for Effects {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitAnd for Effects {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for Effects {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl Not for Effects {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        Self(!self.0 & Self::ALL.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Mnemonic;
+
+    #[test]
+    fn set_operations() {
+        let effects = Effects::MEMORY_READ | Effects::LOG;
+
+        assert!(effects.contains(Effects::LOG));
+        assert!(!effects.contains(Effects::LOG | Effects::HALT));
+        assert!(effects.intersects(Effects::LOG | Effects::HALT));
+        assert_eq!(effects & Effects::LOG, Effects::LOG);
+        assert_eq!(effects.difference(Effects::LOG), Effects::MEMORY_READ);
+        assert_eq!(!Effects::ALL, Effects::NONE);
+        assert_eq!(!!effects, effects);
+        assert_eq!(
+            format!("{effects:?}"),
+            "Effects(MEMORY_READ | LOG)".to_string()
+        );
+    }
+
+    #[test]
+    fn mnemonic_effects() {
+        assert_eq!(Mnemonic::JUMPDEST.effects(), Effects::NONE);
+        assert_eq!(Mnemonic::STOP.effects(), Effects::HALT);
+        assert_eq!(
+            Mnemonic::RETURN.effects(),
+            Effects::STACK_READ | Effects::MEMORY_READ | Effects::RETURN_DATA_WRITE | Effects::HALT
+        );
+        assert!(
+            Mnemonic::CREATE2
+                .effects()
+                .contains(Effects::ACCOUNT_CREATION)
+        );
+        assert!(
+            Mnemonic::DELEGATECALL
+                .effects()
+                .contains(Effects::EXTERNAL_CALL | Effects::RETURN_DATA_WRITE)
+        );
+    }
+
+    #[test]
+    fn static_call_safety() {
+        let unsafe_mnemonics: Vec<_> = Mnemonic::VARIANTS
+            .iter()
+            .filter(|mnemonic| !mnemonic.effects().is_static_call_safe())
+            .copied()
+            .collect();
+
+        #[cfg_attr(
+            not(feature = "eof"),
+            expect(unused_mut, reason = "only EOF adds mnemonics")
+        )]
+        let mut expected = vec![
+            Mnemonic::SSTORE,
+            Mnemonic::TSTORE,
+            Mnemonic::LOG0,
+            Mnemonic::LOG1,
+            Mnemonic::LOG2,
+            Mnemonic::LOG3,
+            Mnemonic::LOG4,
+            Mnemonic::CREATE,
+            Mnemonic::CALL,
+            Mnemonic::CREATE2,
+            Mnemonic::SELFDESTRUCT,
+        ];
+        #[cfg(feature = "eof")]
+        expected.extend([Mnemonic::EOFCREATE, Mnemonic::EXTCALL]);
+
+        assert_eq!(unsafe_mnemonics, expected);
+    }
+
+    #[test]
+    fn pure_mnemonics() {
+        for mnemonic in Mnemonic::VARIANTS {
+            if mnemonic.is_push() || mnemonic.is_dup() || mnemonic.is_swap() {
+                assert!(mnemonic.effects().is_pure(), "{mnemonic}");
+            }
+        }
+
+        assert!(
+            Mnemonic::KECCAK256
+                .effects()
+                .intersects(Effects::MEMORY_READ)
+        );
+        assert!(!Mnemonic::GAS.effects().is_pure());
+        assert!(!Mnemonic::PC.effects().is_pure());
+        assert!(!Mnemonic::INVALID.effects().is_pure());
+    }
+}
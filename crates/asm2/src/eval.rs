@@ -0,0 +1,700 @@
+//! Bit-exact evaluation of pure EVM operations.
+//!
+//! Every function implements the semantics of the mnemonic it is named after (`MOD` is
+//! [`rem`]), with operands in the order of [`Mnemonic::stack_operands`]: the first argument is
+//! the top of the stack. Signed operations interpret words in two's complement.
+//!
+//! [`eval`] dispatches on a mnemonic, which is useful for constant folding and interpreters.
+//!
+//! # Example
+//! ```
+//! # use oculars_asm2::{eval, Mnemonic, U256};
+//! assert_eq!(eval::sub(U256::ZERO, U256::ONE), U256::MAX);
+//! assert_eq!(eval::div(U256::ONE, U256::ZERO), U256::ZERO);
+//! assert_eq!(
+//!     eval::eval(Mnemonic::EXP, &[U256::from(2_u64), U256::from(10_u64)]),
+//!     Ok(U256::from(1024_u64))
+//! );
+//! ```
+
+use crate::{Mnemonic, U256};
+use core::fmt::Display;
+use tiny_keccak::{Hasher, Keccak};
+
+/// An error that occurs while evaluating an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EvalError {
+    /// The mnemonic is not a pure operation on stack items.
+    Unsupported(Mnemonic),
+    /// The amount of inputs does not match the amount of stack items the mnemonic reads.
+    InputCount {
+        /// Mnemonic of the operation.
+        mnemonic: Mnemonic,
+        /// Amount of stack items the mnemonic reads.
+        expected: u8,
+        /// Amount of inputs that were passed.
+        found: usize,
+    },
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Unsupported(mnemonic) => write!(f, "{mnemonic} can not be evaluated"),
+            Self::InputCount {
+                mnemonic,
+                expected,
+                found,
+            } => write!(f, "{mnemonic} expects {expected} inputs, found {found}"),
+        }
+    }
+}
+
+impl core::error::Error for EvalError {}
+
+/// Evaluates a pure operation on stack items, `inputs` start from the top of the stack.
+///
+/// The arithmetic (`ADD` to `SIGNEXTEND`), comparison and bitwise (`LT` to `SAR`) operations are
+/// supported. `KECCAK256` reads memory and is evaluated with [`keccak256`] instead.
+///
+/// # Errors
+/// Returns [`EvalError::Unsupported`] for mnemonics that are not supported and
+/// [`EvalError::InputCount`] if the amount of `inputs` does not match the mnemonic's
+/// [stack inputs](Mnemonic::stack_inputs).
+///
+/// # Example
+/// ```
+/// # use oculars_asm2::{eval::{eval, EvalError}, Mnemonic, U256};
+/// assert_eq!(eval(Mnemonic::ISZERO, &[U256::ZERO]), Ok(U256::ONE));
+/// assert_eq!(eval(Mnemonic::GAS, &[]), Err(EvalError::Unsupported(Mnemonic::GAS)));
+/// assert!(eval(Mnemonic::ADD, &[U256::ONE]).is_err());
+/// ```
+pub fn eval(mnemonic: Mnemonic, inputs: &[U256]) -> Result<U256, EvalError> {
+    let result = match (mnemonic, inputs) {
+        (Mnemonic::ADD, &[a, b]) => add(a, b),
+        (Mnemonic::MUL, &[a, b]) => mul(a, b),
+        (Mnemonic::SUB, &[a, b]) => sub(a, b),
+        (Mnemonic::DIV, &[a, b]) => div(a, b),
+        (Mnemonic::SDIV, &[a, b]) => sdiv(a, b),
+        (Mnemonic::MOD, &[a, b]) => rem(a, b),
+        (Mnemonic::SMOD, &[a, b]) => smod(a, b),
+        (Mnemonic::ADDMOD, &[a, b, n]) => addmod(a, b, n),
+        (Mnemonic::MULMOD, &[a, b, n]) => mulmod(a, b, n),
+        (Mnemonic::EXP, &[base, exponent]) => exp(base, exponent),
+        (Mnemonic::SIGNEXTEND, &[b, x]) => signextend(b, x),
+        (Mnemonic::LT, &[a, b]) => lt(a, b),
+        (Mnemonic::GT, &[a, b]) => gt(a, b),
+        (Mnemonic::SLT, &[a, b]) => slt(a, b),
+        (Mnemonic::SGT, &[a, b]) => sgt(a, b),
+        (Mnemonic::EQ, &[a, b]) => eq(a, b),
+        (Mnemonic::ISZERO, &[a]) => iszero(a),
+        (Mnemonic::AND, &[a, b]) => and(a, b),
+        (Mnemonic::OR, &[a, b]) => or(a, b),
+        (Mnemonic::XOR, &[a, b]) => xor(a, b),
+        (Mnemonic::NOT, &[a]) => not(a),
+        (Mnemonic::BYTE, &[i, x]) => byte(i, x),
+        (Mnemonic::SHL, &[shift, value]) => shl(shift, value),
+        (Mnemonic::SHR, &[shift, value]) => shr(shift, value),
+        (Mnemonic::SAR, &[shift, value]) => sar(shift, value),
+        // The arithmetic, comparison and bitwise operations.
+        (_, _) if matches!(mnemonic.into_byte(), 0x01..=0x0B | 0x10..=0x1D) => {
+            return Err(EvalError::InputCount {
+                mnemonic,
+                expected: mnemonic.stack_inputs(),
+                found: inputs.len(),
+            });
+        }
+        _ => return Err(EvalError::Unsupported(mnemonic)),
+    };
+
+    Ok(result)
+}
+
+/// Converts a boolean into the word `1` or `0`.
+const fn word(value: bool) -> U256 {
+    if value { U256::ONE } else { U256::ZERO }
+}
+
+/// Returns the absolute value of a two's complement word.
+const fn abs(value: U256) -> U256 {
+    if value.is_negative() {
+        value.wrapping_neg()
+    } else {
+        value
+    }
+}
+
+/// Returns a shift amount or a byte index, [`None`] if it is too large to matter.
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "the value is smaller than 256"
+)]
+const fn small(value: U256) -> Option<u32> {
+    match value.to_u64() {
+        Some(value) if value < 256 => Some(value as u32),
+        _ => None,
+    }
+}
+
+/// `ADD`: wrapping addition.
+#[must_use]
+pub const fn add(a: U256, b: U256) -> U256 {
+    a.wrapping_add(b)
+}
+
+/// `MUL`: wrapping multiplication.
+#[must_use]
+pub const fn mul(a: U256, b: U256) -> U256 {
+    a.wrapping_mul(b)
+}
+
+/// `SUB`: wrapping subtraction.
+#[must_use]
+pub const fn sub(a: U256, b: U256) -> U256 {
+    a.wrapping_sub(b)
+}
+
+/// `DIV`: unsigned division, dividing by zero results in zero.
+#[must_use]
+pub const fn div(a: U256, b: U256) -> U256 {
+    match a.checked_div_rem(b) {
+        Some((quotient, _)) => quotient,
+        None => U256::ZERO,
+    }
+}
+
+/// `SDIV`: signed division rounding towards zero, dividing by zero results in zero.
+///
+/// Dividing `-2^255` by `-1` overflows and results in `-2^255`.
+#[must_use]
+pub const fn sdiv(a: U256, b: U256) -> U256 {
+    let quotient = div(abs(a), abs(b));
+
+    if a.is_negative() == b.is_negative() {
+        quotient
+    } else {
+        quotient.wrapping_neg()
+    }
+}
+
+/// `MOD`: unsigned remainder, the remainder of dividing by zero is zero.
+#[must_use]
+pub const fn rem(a: U256, b: U256) -> U256 {
+    match a.checked_div_rem(b) {
+        Some((_, remainder)) => remainder,
+        None => U256::ZERO,
+    }
+}
+
+/// `SMOD`: signed remainder that takes the sign of `a`, the remainder of dividing by zero is
+/// zero.
+#[must_use]
+pub const fn smod(a: U256, b: U256) -> U256 {
+    let remainder = rem(abs(a), abs(b));
+
+    if a.is_negative() {
+        remainder.wrapping_neg()
+    } else {
+        remainder
+    }
+}
+
+/// `ADDMOD`: addition modulo `n` without wrapping the sum, the result is zero if `n` is zero.
+#[must_use]
+pub const fn addmod(a: U256, b: U256, n: U256) -> U256 {
+    match a.checked_add_mod(b, n) {
+        Some(result) => result,
+        None => U256::ZERO,
+    }
+}
+
+/// `MULMOD`: multiplication modulo `n` without wrapping the product, the result is zero if `n`
+/// is zero.
+#[must_use]
+pub const fn mulmod(a: U256, b: U256, n: U256) -> U256 {
+    match a.checked_mul_mod(b, n) {
+        Some(result) => result,
+        None => U256::ZERO,
+    }
+}
+
+/// `EXP`: wrapping exponentiation.
+#[must_use]
+pub const fn exp(base: U256, exponent: U256) -> U256 {
+    base.wrapping_pow(exponent)
+}
+
+/// `SIGNEXTEND`: extends the sign of the `b`-th byte (counted from the least significant byte)
+/// of `x`. `x` is returned unchanged if `b` is 31 or larger.
+#[must_use]
+pub fn signextend(b: U256, x: U256) -> U256 {
+    match small(b) {
+        Some(b) if b < 31 => {
+            let sign = 8 * b + 7;
+            let mask = U256::MAX >> (255 - sign);
+
+            if x.bit(sign) { x | !mask } else { x & mask }
+        }
+        _ => x,
+    }
+}
+
+/// `LT`: unsigned less-than comparison.
+#[must_use]
+pub fn lt(a: U256, b: U256) -> U256 {
+    word(a < b)
+}
+
+/// `GT`: unsigned greater-than comparison.
+#[must_use]
+pub fn gt(a: U256, b: U256) -> U256 {
+    word(a > b)
+}
+
+/// `SLT`: signed less-than comparison.
+#[must_use]
+pub fn slt(a: U256, b: U256) -> U256 {
+    match (a.is_negative(), b.is_negative()) {
+        (true, false) => U256::ONE,
+        (false, true) => U256::ZERO,
+        _ => lt(a, b),
+    }
+}
+
+/// `SGT`: signed greater-than comparison.
+#[must_use]
+pub fn sgt(a: U256, b: U256) -> U256 {
+    slt(b, a)
+}
+
+/// `EQ`: equality comparison.
+#[must_use]
+pub fn eq(a: U256, b: U256) -> U256 {
+    word(a == b)
+}
+
+/// `ISZERO`: returns `1` if `a` is zero.
+#[must_use]
+pub const fn iszero(a: U256) -> U256 {
+    word(a.is_zero())
+}
+
+/// `AND`: bitwise and.
+#[must_use]
+pub fn and(a: U256, b: U256) -> U256 {
+    a & b
+}
+
+/// `OR`: bitwise or.
+#[must_use]
+pub fn or(a: U256, b: U256) -> U256 {
+    a | b
+}
+
+/// `XOR`: bitwise exclusive or.
+#[must_use]
+pub fn xor(a: U256, b: U256) -> U256 {
+    a ^ b
+}
+
+/// `NOT`: bitwise negation.
+#[must_use]
+pub fn not(a: U256) -> U256 {
+    !a
+}
+
+/// `BYTE`: the `i`-th byte of `x`, counted from the most significant byte. Bytes past the 32nd
+/// are zero.
+#[must_use]
+pub const fn byte(i: U256, x: U256) -> U256 {
+    match small(i) {
+        Some(i) if i < 32 => U256::from_limbs([x.to_be_bytes()[i as usize] as u64, 0, 0, 0]),
+        _ => U256::ZERO,
+    }
+}
+
+/// `SHL`: left shift, shifts of 256 bits or more result in zero.
+#[must_use]
+pub const fn shl(shift: U256, value: U256) -> U256 {
+    match small(shift) {
+        Some(shift) => value.shl_bits(shift),
+        None => U256::ZERO,
+    }
+}
+
+/// `SHR`: logical right shift, shifts of 256 bits or more result in zero.
+#[must_use]
+pub const fn shr(shift: U256, value: U256) -> U256 {
+    match small(shift) {
+        Some(shift) => value.shr_bits(shift),
+        None => U256::ZERO,
+    }
+}
+
+/// `SAR`: arithmetic right shift, shifts of 256 bits or more result in zero for positive and in
+/// `-1` for negative values.
+#[must_use]
+pub fn sar(shift: U256, value: U256) -> U256 {
+    match (small(shift), value.is_negative()) {
+        (Some(shift), false) => value >> shift,
+        (Some(shift), true) => !(!value >> shift),
+        (None, false) => U256::ZERO,
+        (None, true) => U256::MAX,
+    }
+}
+
+/// `KECCAK256`: the Keccak-256 hash of `data`, the memory range the operation reads.
+///
+/// # Example
+/// ```
+/// # use oculars_asm2::{eval::keccak256, U256};
+/// assert_eq!(
+///     format!("{:x}", keccak256(b"")),
+///     "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+/// );
+/// ```
+#[must_use]
+pub fn keccak256(data: &[u8]) -> U256 {
+    let mut hash = [0; 32];
+    let mut keccak = Keccak::v256();
+    keccak.update(data);
+    keccak.finalize(&mut hash);
+
+    U256::from_be_bytes(hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses a hex word, `-` prefixed words are negated.
+    fn w(hex: &str) -> U256 {
+        let (negative, hex) = hex
+            .strip_prefix('-')
+            .map_or((false, hex), |hex| (true, hex));
+        let hex = hex.strip_prefix("0x").unwrap_or(hex);
+
+        let mut bytes = [0; 32];
+        for (i, digit) in hex.bytes().rev().enumerate() {
+            let digit = u8::try_from(char::from(digit).to_digit(16).unwrap()).unwrap();
+            bytes[31 - i / 2] |= digit << (4 * (i % 2));
+        }
+
+        let value = U256::from_be_bytes(bytes);
+        if negative {
+            value.wrapping_neg()
+        } else {
+            value
+        }
+    }
+
+    /// Asserts that evaluating `mnemonic` with each of the `vectors` inputs results in the
+    /// vector's output.
+    fn check<const N: usize>(mnemonic: Mnemonic, vectors: &[([&str; N], &str)]) {
+        for (inputs, output) in vectors {
+            let inputs = inputs.map(w);
+            assert_eq!(
+                eval(mnemonic, &inputs),
+                Ok(w(output)),
+                "{mnemonic} {inputs:x?}"
+            );
+        }
+    }
+
+    /// The largest word, `-1` in two's complement.
+    const MAX: &str = "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff";
+    /// The smallest signed word, `-2^255`.
+    const MIN: &str = "0x8000000000000000000000000000000000000000000000000000000000000000";
+    /// The largest signed word, `2^255 - 1`.
+    const SMAX: &str = "0x7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff";
+
+    // The vectors below cover the edge cases of the `VMTests` in the official `ethereum/tests`
+    // suite (`vmArithmeticTest`, `vmBitwiseLogicOperation` and `vmSha3Test`), the shift vectors
+    // are the test cases of EIP-145. All numbers are hex.
+
+    #[test]
+    fn arithmetic() {
+        check(
+            Mnemonic::ADD,
+            &[([MAX, MAX], "-2"), ([MAX, "1"], "0"), (["4", "0"], "4")],
+        );
+        check(
+            Mnemonic::MUL,
+            &[
+                (["2", "3"], "6"),
+                ([MAX, MAX], "1"),
+                (["0", MAX], "0"),
+                ([MIN, MAX], MIN),
+                ([MIN, MIN], "0"),
+                (
+                    [
+                        "0x1000000000000000000000000000000000000000000000000",
+                        "0x1000000000000000000000000000000000000000000000000",
+                    ],
+                    "0",
+                ),
+            ],
+        );
+        check(
+            Mnemonic::SUB,
+            &[(["23", "1"], "0x22"), (["0", "1"], MAX), (["1", MAX], "2")],
+        );
+        check(
+            Mnemonic::DIV,
+            &[
+                (["2", "0"], "0"),
+                (["5", "2"], "2"),
+                ([MAX, "2"], SMAX),
+                (["0xfedcba9876543210", MAX], "0"),
+                ([MAX, MAX], "1"),
+            ],
+        );
+        check(
+            Mnemonic::MOD,
+            &[
+                (["2", "3"], "2"),
+                ([MAX, "2"], "1"),
+                (["0", MAX], "0"),
+                (["3", "0"], "0"),
+            ],
+        );
+        check(
+            Mnemonic::EXP,
+            &[
+                (["2", "2"], "4"),
+                (["0", "0"], "1"),
+                (["0", "1"], "0"),
+                ([MAX, MAX], MAX),
+                (["2", "0xff"], MIN),
+                (["2", "0x100"], "0"),
+                ([MAX, "2"], "1"),
+                (["-2", "3"], "-8"),
+                (["0x10", "0x40"], "0"),
+                (
+                    ["0x10", "0x3f"],
+                    "0x1000000000000000000000000000000000000000000000000000000000000000",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn signed_arithmetic() {
+        check(
+            Mnemonic::SDIV,
+            &[
+                (["-1", "-1"], "1"),
+                ([MIN, "-1"], MIN),
+                (["-2", "2"], "-1"),
+                (["4", "-2"], "-2"),
+                (["-5", "2"], "-2"),
+                (["-1", "0"], "0"),
+                ([MIN, "1"], MIN),
+            ],
+        );
+        check(
+            Mnemonic::SMOD,
+            &[
+                (["-2", "3"], "-2"),
+                (["2", "-3"], "2"),
+                (["-8", "-3"], "-2"),
+                ([MIN, "-1"], "0"),
+                (["-1", "0"], "0"),
+            ],
+        );
+        check(
+            Mnemonic::SIGNEXTEND,
+            &[
+                (["0", "0xff"], MAX),
+                (["0", "0x7f"], "0x7f"),
+                (["0", "0x12faf4"], "-0x0c"),
+                (["1", "0x8000"], "-0x8000"),
+                (["1", "0x12347f"], "0x347f"),
+                (["0x1f", MIN], MIN),
+                (["0x20", "0xff"], "0xff"),
+                ([MAX, "0xff"], "0xff"),
+            ],
+        );
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        check(
+            Mnemonic::ADDMOD,
+            &[
+                (["1", "2", "2"], "1"),
+                ([MAX, "2", "2"], "1"),
+                ([MAX, MAX, MAX], "0"),
+                ([MAX, "1", "0"], "0"),
+                (["-1", "-2", "5"], "4"),
+            ],
+        );
+        check(
+            Mnemonic::MULMOD,
+            &[
+                (["1", "2", "3"], "2"),
+                ([MAX, MAX, "12"], "9"),
+                ([MAX, MAX, MAX], "0"),
+                ([MAX, "2", "0"], "0"),
+                (["-1", "-1", "3"], "0"),
+            ],
+        );
+    }
+
+    #[test]
+    fn comparisons() {
+        check(
+            Mnemonic::LT,
+            &[(["0", "1"], "1"), (["1", "0"], "0"), (["-1", "0"], "0")],
+        );
+        check(
+            Mnemonic::GT,
+            &[(["0", "1"], "0"), (["-1", "0"], "1"), (["1", "1"], "0")],
+        );
+        check(
+            Mnemonic::SLT,
+            &[
+                (["-1", "0"], "1"),
+                (["0", "-1"], "0"),
+                ([MIN, SMAX], "1"),
+                (["-2", "-1"], "1"),
+                (["-1", "-1"], "0"),
+            ],
+        );
+        check(
+            Mnemonic::SGT,
+            &[
+                (["-1", "0"], "0"),
+                (["0", "-1"], "1"),
+                ([SMAX, MIN], "1"),
+                (["-1", "-2"], "1"),
+            ],
+        );
+        check(Mnemonic::EQ, &[([MAX, "-1"], "1"), (["0", "1"], "0")]);
+        check(Mnemonic::ISZERO, &[(["0"], "1"), ([MIN], "0")]);
+    }
+
+    #[test]
+    fn bitwise() {
+        check(
+            Mnemonic::AND,
+            &[
+                (["2", "1"], "0"),
+                ([MAX, "0x1234"], "0x1234"),
+                (["0xff00", "0x0ff0"], "0x0f00"),
+            ],
+        );
+        check(
+            Mnemonic::OR,
+            &[(["2", "1"], "3"), (["0xf0", "0x0f"], "0xff")],
+        );
+        check(Mnemonic::XOR, &[(["3", "1"], "2"), ([MAX, SMAX], MIN)]);
+        check(Mnemonic::NOT, &[(["0"], MAX), ([SMAX], MIN)]);
+        check(
+            Mnemonic::BYTE,
+            &[
+                (["0", MIN], "0x80"),
+                (["0x1f", "0x8040201008040201"], "0x01"),
+                (["0x18", "0x8040201008040201"], "0x80"),
+                (["0x20", MAX], "0"),
+                ([MAX, MAX], "0"),
+            ],
+        );
+    }
+
+    #[test]
+    fn shifts() {
+        check(
+            Mnemonic::SHL,
+            &[
+                (["0", "1"], "1"),
+                (["1", "1"], "2"),
+                (["0xff", "1"], MIN),
+                (["0x100", "1"], "0"),
+                (["0x101", "1"], "0"),
+                (["0", MAX], MAX),
+                (["1", MAX], "-2"),
+                (["0xff", MAX], MIN),
+                (["0x100", MAX], "0"),
+                (["1", "0"], "0"),
+                (["1", SMAX], "-2"),
+            ],
+        );
+        check(
+            Mnemonic::SHR,
+            &[
+                (["0", "1"], "1"),
+                (["1", "1"], "0"),
+                (
+                    ["1", MIN],
+                    "0x4000000000000000000000000000000000000000000000000000000000000000",
+                ),
+                (["0xff", MIN], "1"),
+                (["0x100", MIN], "0"),
+                (["0x101", MIN], "0"),
+                (["0", MAX], MAX),
+                (["1", MAX], SMAX),
+                (["0xff", MAX], "1"),
+                (["0x100", MAX], "0"),
+                (["1", "0"], "0"),
+            ],
+        );
+        check(
+            Mnemonic::SAR,
+            &[
+                (["0", "1"], "1"),
+                (["1", "1"], "0"),
+                (
+                    ["1", MIN],
+                    "0xc000000000000000000000000000000000000000000000000000000000000000",
+                ),
+                (["0xff", MIN], MAX),
+                (["0x100", MIN], MAX),
+                (["0x101", MIN], MAX),
+                (["0", MAX], MAX),
+                (["1", MAX], MAX),
+                (["0xff", MAX], MAX),
+                (["0x100", MAX], MAX),
+                (["1", "0"], "0"),
+                (
+                    [
+                        "0xfe",
+                        "0x4000000000000000000000000000000000000000000000000000000000000000",
+                    ],
+                    "1",
+                ),
+                (["0xf8", SMAX], "0x7f"),
+                (["0xfe", SMAX], "1"),
+                (["0xff", SMAX], "0"),
+                (["0x100", SMAX], "0"),
+                ([MAX, MIN], MAX),
+            ],
+        );
+    }
+
+    #[test]
+    fn keccak() {
+        assert_eq!(
+            keccak256(&[0; 32]),
+            w("0x290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563")
+        );
+        assert_eq!(
+            keccak256(b"hello"),
+            w("0x1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8")
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            eval(Mnemonic::KECCAK256, &[U256::ZERO, U256::ZERO]),
+            Err(EvalError::Unsupported(Mnemonic::KECCAK256))
+        );
+        assert_eq!(
+            eval(Mnemonic::ADDMOD, &[U256::ONE; 2])
+                .unwrap_err()
+                .to_string(),
+            "ADDMOD expects 3 inputs, found 2"
+        );
+    }
+}
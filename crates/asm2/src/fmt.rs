@@ -5,9 +5,9 @@
 macro_rules! forward_byte_fmt {
     ($struct: ident, $($fmt: ident),+) => {
         $(
-            impl std::fmt::$fmt for $struct {
-                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                    std::fmt::$fmt::fmt(&u8::from(*self), f)
+            impl core::fmt::$fmt for $struct {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    core::fmt::$fmt::fmt(&u8::from(*self), f)
                 }
             }
         )+
@@ -18,18 +18,18 @@ macro_rules! forward_byte_fmt {
 macro_rules! forward_opcode_fmt {
     ($struct: ident, $($fmt: ident),+) => {
         $(
-            impl std::fmt::$fmt for $struct {
-                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                    std::fmt::$fmt::fmt(&$crate::AssemblyInstruction::opcode(self), f)
+            impl core::fmt::$fmt for $struct {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    core::fmt::$fmt::fmt(&$crate::AssemblyInstruction::opcode(self), f)
                 }
             }
         )+
     };
     (generic $struct: ident, $($fmt: ident),+) => {
         $(
-            impl<const N: u8> std::fmt::$fmt for $struct<N> {
-                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                    std::fmt::$fmt::fmt(&$crate::AssemblyInstruction::opcode(self), f)
+            impl<const N: u8> core::fmt::$fmt for $struct<N> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    core::fmt::$fmt::fmt(&$crate::AssemblyInstruction::opcode(self), f)
                 }
             }
         )+
@@ -0,0 +1,312 @@
+//! Constant opcode information table.
+
+pub use crate::defs::info::OPCODE_TABLE;
+use crate::{Effects, Mnemonic};
+
+/// Everything that is known about a single opcode byte, without decoding an instruction.
+///
+/// The information of every byte is stored in [`OPCODE_TABLE`], which makes looking it up a
+/// single array access. The table backs the predicates of [`Mnemonic`] and
+/// [`OpCode`](crate::OpCode).
+///
+/// Bytes without a known mnemonic are described by [`OpInfo::UNKNOWN`], executing them halts.
+///
+/// # Example
+/// ```
+/// # use oculars_asm2::{Effects, Mnemonic, OpInfo};
+/// let info = OpInfo::of(0x61);
+/// assert_eq!(info.mnemonic(), Some(Mnemonic::PUSH2));
+/// assert_eq!(info.immediate_size(), Some(2));
+/// assert_eq!((info.stack_inputs(), info.stack_outputs()), (0, 1));
+/// assert!(info.is_push());
+///
+/// assert_eq!(OpInfo::of(0x0C).mnemonic(), None);
+/// assert!(OpInfo::of(0x0C).is_terminator());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OpInfo {
+    /// Mnemonic of the opcode, if it is known.
+    mnemonic: Option<Mnemonic>,
+    /// Size of the immediate value, `0` if the size is variable.
+    immediate_size: u8,
+    /// Amount of stack items read.
+    stack_inputs: u8,
+    /// Amount of stack items written.
+    stack_outputs: u8,
+    /// Effects of executing the opcode.
+    effects: Effects,
+    /// Classification bits.
+    flags: u8,
+}
+
+impl OpInfo {
+    /// The opcode is a `PUSHx`.
+    const PUSH: u8 = 1;
+    /// The opcode is a `DUPx`.
+    const DUP: u8 = 1 << 1;
+    /// The opcode is a `SWAPx`.
+    const SWAP: u8 = 1 << 2;
+    /// The opcode is a `LOGx`.
+    const LOG: u8 = 1 << 3;
+    /// The opcode halts execution.
+    const TERMINATOR: u8 = 1 << 4;
+    /// The opcode jumps or is a jump destination.
+    const CONTROL_FLOW: u8 = 1 << 5;
+    /// The opcode is only valid inside of EOF containers.
+    const EOF: u8 = 1 << 6;
+    /// The size of the immediate value depends on the immediate value itself.
+    const VARIABLE_IMMEDIATE: u8 = 1 << 7;
+
+    /// Information of a byte without a known mnemonic.
+    ///
+    /// Unknown opcodes halt execution, so they are both terminators and control flow.
+    pub const UNKNOWN: Self = Self {
+        mnemonic: None,
+        immediate_size: 0,
+        stack_inputs: 0,
+        stack_outputs: 0,
+        effects: Effects::HALT,
+        flags: Self::TERMINATOR | Self::CONTROL_FLOW,
+    };
+
+    /// Creates the information of a known mnemonic.
+    ///
+    /// Used to build [`OPCODE_TABLE`].
+    pub(crate) const fn known(mnemonic: Mnemonic, eof: bool) -> Self {
+        let byte = mnemonic.into_byte();
+        let effects = mnemonic.effects();
+        let mut flags = 0;
+        let mut immediate_size = 0;
+
+        if let 0x5F..=0x7F = byte {
+            flags |= Self::PUSH;
+            immediate_size = byte - 0x5F;
+        }
+        if let 0x80..=0x8F = byte {
+            flags |= Self::DUP;
+        }
+        if let 0x90..=0x9F = byte {
+            flags |= Self::SWAP;
+        }
+        if let 0xA0..=0xA4 = byte {
+            flags |= Self::LOG;
+        }
+        if effects.contains(Effects::HALT) {
+            flags |= Self::TERMINATOR;
+        }
+        if effects.contains(Effects::JUMP) || matches!(mnemonic, Mnemonic::JUMPDEST) {
+            flags |= Self::CONTROL_FLOW;
+        }
+        if eof {
+            flags |= Self::EOF;
+        }
+
+        #[cfg(feature = "eof")]
+        match mnemonic {
+            Mnemonic::DATALOADN
+            | Mnemonic::RJUMP
+            | Mnemonic::RJUMPI
+            | Mnemonic::CALLF
+            | Mnemonic::JUMPF => immediate_size = 2,
+            Mnemonic::DUPN
+            | Mnemonic::SWAPN
+            | Mnemonic::EXCHANGE
+            | Mnemonic::EOFCREATE
+            | Mnemonic::RETURNCONTRACT => immediate_size = 1,
+            Mnemonic::RJUMPV => flags |= Self::VARIABLE_IMMEDIATE,
+            _ => {}
+        }
+
+        Self {
+            mnemonic: Some(mnemonic),
+            immediate_size,
+            stack_inputs: mnemonic.stack_inputs(),
+            stack_outputs: mnemonic.stack_outputs(),
+            effects,
+            flags,
+        }
+    }
+
+    /// Returns the information of an opcode byte.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{Mnemonic, OpInfo};
+    /// assert_eq!(OpInfo::of(0x5A).mnemonic(), Some(Mnemonic::GAS));
+    /// assert_eq!(OpInfo::of(0x0C), &OpInfo::UNKNOWN);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn of(byte: u8) -> &'static Self {
+        &OPCODE_TABLE[byte as usize]
+    }
+
+    /// Returns the mnemonic of the opcode, [`None`] if the opcode is unknown.
+    #[must_use]
+    #[inline]
+    pub const fn mnemonic(&self) -> Option<Mnemonic> {
+        self.mnemonic
+    }
+
+    /// Returns [`true`] if the opcode has a known mnemonic.
+    #[must_use]
+    #[inline]
+    pub const fn is_known(&self) -> bool {
+        self.mnemonic.is_some()
+    }
+
+    /// Returns the size of the immediate value following the opcode, [`None`] if the size
+    /// depends on the immediate value (as for `RJUMPV`).
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::OpInfo;
+    /// assert_eq!(OpInfo::of(0x7F).immediate_size(), Some(32));
+    /// assert_eq!(OpInfo::of(0x01).immediate_size(), Some(0));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn immediate_size(&self) -> Option<u8> {
+        if self.flags & Self::VARIABLE_IMMEDIATE == 0 {
+            Some(self.immediate_size)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the amount of stack items the opcode reads (pops), see
+    /// [`Mnemonic::stack_inputs`].
+    #[must_use]
+    #[inline]
+    pub const fn stack_inputs(&self) -> u8 {
+        self.stack_inputs
+    }
+
+    /// Returns the amount of stack items the opcode writes (pushes), see
+    /// [`Mnemonic::stack_outputs`].
+    #[must_use]
+    #[inline]
+    pub const fn stack_outputs(&self) -> u8 {
+        self.stack_outputs
+    }
+
+    /// Returns the effects of executing the opcode.
+    #[must_use]
+    #[inline]
+    pub const fn effects(&self) -> Effects {
+        self.effects
+    }
+
+    /// Returns [`true`] if the opcode is of the type `PUSHx`.
+    #[must_use]
+    #[inline]
+    pub const fn is_push(&self) -> bool {
+        self.flags & Self::PUSH != 0
+    }
+
+    /// Returns [`true`] if the opcode is of the type `DUPx`.
+    #[must_use]
+    #[inline]
+    pub const fn is_dup(&self) -> bool {
+        self.flags & Self::DUP != 0
+    }
+
+    /// Returns [`true`] if the opcode is of the type `SWAPx`.
+    #[must_use]
+    #[inline]
+    pub const fn is_swap(&self) -> bool {
+        self.flags & Self::SWAP != 0
+    }
+
+    /// Returns [`true`] if the opcode is of the type `LOGx`.
+    #[must_use]
+    #[inline]
+    pub const fn is_log(&self) -> bool {
+        self.flags & Self::LOG != 0
+    }
+
+    /// Returns [`true`] if the opcode terminates execution of the smart contract.
+    #[must_use]
+    #[inline]
+    pub const fn is_terminator(&self) -> bool {
+        self.flags & Self::TERMINATOR != 0
+    }
+
+    /// Returns [`true`] if the opcode jumps or is a jump destination.
+    #[must_use]
+    #[inline]
+    pub const fn is_control_flow(&self) -> bool {
+        self.flags & Self::CONTROL_FLOW != 0
+    }
+
+    /// Returns [`true`] if the opcode is only valid inside of EOF containers.
+    #[must_use]
+    #[inline]
+    pub const fn is_eof(&self) -> bool {
+        self.flags & Self::EOF != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AssemblyInstruction, Instruction, OpCode};
+
+    #[test]
+    fn table_matches_mnemonics() {
+        for byte in 0..=u8::MAX {
+            let info = OpInfo::of(byte);
+            let Some(mnemonic) = info.mnemonic() else {
+                assert_eq!(info, &OpInfo::UNKNOWN, "{byte:#04x}");
+                continue;
+            };
+
+            assert_eq!(mnemonic.into_byte(), byte);
+            assert_eq!(info.stack_inputs(), mnemonic.stack_inputs(), "{mnemonic}");
+            assert_eq!(info.stack_outputs(), mnemonic.stack_outputs(), "{mnemonic}");
+            assert_eq!(info.effects(), mnemonic.effects(), "{mnemonic}");
+        }
+
+        assert_eq!(
+            OPCODE_TABLE.iter().filter(|info| info.is_known()).count(),
+            Mnemonic::VARIANTS.len()
+        );
+    }
+
+    #[test]
+    fn immediate_sizes_match_decoding() {
+        for byte in 0..=u8::MAX {
+            let mut bytes = vec![byte];
+            bytes.extend([0; 64]);
+
+            let (instruction, _) = Instruction::decode(&bytes).unwrap();
+            if instruction.opcode() != OpCode::from_byte(byte) {
+                continue;
+            }
+
+            match OpInfo::of(byte).immediate_size() {
+                Some(size) => assert_eq!(usize::from(size), instruction.immediate_size()),
+                None => assert!(OpInfo::of(byte).is_eof(), "{byte:#04x}"),
+            }
+        }
+    }
+
+    #[test]
+    fn classification() {
+        assert_eq!(
+            OPCODE_TABLE.iter().filter(|info| info.is_push()).count(),
+            33
+        );
+        assert_eq!(OPCODE_TABLE.iter().filter(|info| info.is_dup()).count(), 16);
+        assert_eq!(
+            OPCODE_TABLE.iter().filter(|info| info.is_swap()).count(),
+            16
+        );
+        assert_eq!(OPCODE_TABLE.iter().filter(|info| info.is_log()).count(), 5);
+
+        assert!(OpInfo::of(Mnemonic::RETURN.into_byte()).is_terminator());
+        assert!(!OpInfo::of(Mnemonic::JUMP.into_byte()).is_terminator());
+        assert!(OpInfo::of(Mnemonic::JUMPDEST.into_byte()).is_control_flow());
+        assert!(!OpInfo::of(Mnemonic::CALL.into_byte()).is_control_flow());
+    }
+}
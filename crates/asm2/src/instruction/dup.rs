@@ -1,6 +1,10 @@
 //! The `DUPx` instruction.
 
-use crate::{fmt::forward_opcode_fmt, AssemblyInstruction, Mnemonic, OpCode};
+use crate::{
+    AssemblyInstruction, KnownInstruction, Mnemonic, OpCode,
+    decode::{Decode, DecodeError},
+    fmt::forward_opcode_fmt,
+};
 
 /// Duplicate `N`th stack item.
 /// The `N` constant signifies the type of the `DUP` opcode (e.g. `Dup<16>` => `DUP16`).
@@ -10,27 +14,31 @@ pub struct Dup<const N: u8> {
     _private: (),
 }
 
+impl<const N: u8> KnownInstruction for Dup<N> {
+    const MNEMONIC: Mnemonic = match N {
+        1 => Mnemonic::DUP1,
+        2 => Mnemonic::DUP2,
+        3 => Mnemonic::DUP3,
+        4 => Mnemonic::DUP4,
+        5 => Mnemonic::DUP5,
+        6 => Mnemonic::DUP6,
+        7 => Mnemonic::DUP7,
+        8 => Mnemonic::DUP8,
+        9 => Mnemonic::DUP9,
+        10 => Mnemonic::DUP10,
+        11 => Mnemonic::DUP11,
+        12 => Mnemonic::DUP12,
+        13 => Mnemonic::DUP13,
+        14 => Mnemonic::DUP14,
+        15 => Mnemonic::DUP15,
+        16 => Mnemonic::DUP16,
+        _ => panic!("only `Dup<X>` instructions where `X` >= 1 && `X` <= 16 are supported"),
+    };
+}
+
 impl<const N: u8> AssemblyInstruction for Dup<N> {
     fn opcode(&self) -> OpCode {
-        OpCode::Known(match N {
-            1 => Mnemonic::DUP1,
-            2 => Mnemonic::DUP2,
-            3 => Mnemonic::DUP3,
-            4 => Mnemonic::DUP4,
-            5 => Mnemonic::DUP5,
-            6 => Mnemonic::DUP6,
-            7 => Mnemonic::DUP7,
-            8 => Mnemonic::DUP8,
-            9 => Mnemonic::DUP9,
-            10 => Mnemonic::DUP10,
-            11 => Mnemonic::DUP11,
-            12 => Mnemonic::DUP12,
-            13 => Mnemonic::DUP13,
-            14 => Mnemonic::DUP14,
-            15 => Mnemonic::DUP15,
-            16 => Mnemonic::DUP16,
-            _ => panic!("only `Dup<X>` instructions where `X` >= 1 && `X` <= 16 are supported"),
-        })
+        OpCode::Known(Self::MNEMONIC)
     }
 }
 
@@ -71,4 +79,11 @@ impl<const N: u8> Default for Dup<N> {
     }
 }
 
+impl<const N: u8> Decode for Dup<N> {
+    #[inline]
+    fn decode(_: &[u8]) -> Result<Self, DecodeError> {
+        Ok(Self::new())
+    }
+}
+
 forward_opcode_fmt!(generic Dup, Display, LowerHex, UpperHex, Binary, Octal);
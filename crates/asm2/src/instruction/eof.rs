@@ -0,0 +1,504 @@
+//! Instructions with immediate values that are only valid inside of EOF containers.
+//!
+//! EOF instructions without an immediate value are defined alongside every other instruction.
+
+use crate::{
+    AssemblyInstruction, KnownInstruction, Mnemonic, OpCode,
+    decode::{Decode, DecodeError},
+};
+use alloc::vec::Vec;
+
+/// Returns the first `N` bytes of `available` as an immediate value.
+fn decode_immediate<const N: usize>(
+    available: &[u8],
+    mnemonic: Mnemonic,
+) -> Result<[u8; N], DecodeError> {
+    available
+        .get(..N)
+        .and_then(|immediate| immediate.try_into().ok())
+        .ok_or(DecodeError::TruncatedImmediate {
+            mnemonic,
+            expected: N,
+            available: available.len(),
+        })
+}
+
+/// Implements hexadecimal and binary formatting for an instruction with an immediate value by
+/// formatting the opcode followed by the immediate bytes.
+macro_rules! impl_immediate_fmt {
+    ($name: ident, $($fmt: ident => $byte_fmt: literal),+) => {
+        $(
+            impl core::fmt::$fmt for $name {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    write!(f, $byte_fmt, self.opcode())?;
+
+                    for byte in self.immediate_bytes() {
+                        write!(f, $byte_fmt, byte)?;
+                    }
+
+                    Ok(())
+                }
+            }
+        )+
+    };
+    ($name: ident) => {
+        impl_immediate_fmt!($name, LowerHex => "{:02x}", UpperHex => "{:02X}", Binary => "{:08b}");
+    };
+}
+
+/// Defines an instruction whose immediate value is a single big endian integer.
+macro_rules! define_immediate_instruction {
+    ($(#[$doc: meta])* $name: ident = $mnemonic: ident, $getter: ident: $ty: ty) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name {
+            /// The immediate value of this instruction.
+            immediate: [u8; size_of::<$ty>()],
+        }
+
+        impl $name {
+            #[doc = concat!("Creates a new `", stringify!($mnemonic), "` instruction.")]
+            #[must_use]
+            #[inline]
+            pub const fn new($getter: $ty) -> Self {
+                Self {
+                    immediate: $getter.to_be_bytes(),
+                }
+            }
+
+            #[doc = concat!("Returns the `", stringify!($getter), "` immediate value.")]
+            #[must_use]
+            #[inline]
+            pub const fn $getter(&self) -> $ty {
+                <$ty>::from_be_bytes(self.immediate)
+            }
+        }
+
+        impl KnownInstruction for $name {
+            const MNEMONIC: Mnemonic = Mnemonic::$mnemonic;
+        }
+
+        impl AssemblyInstruction for $name {
+            fn opcode(&self) -> OpCode {
+                OpCode::Known(Self::MNEMONIC)
+            }
+
+            #[inline]
+            fn immediate_size(&self) -> usize {
+                size_of::<$ty>()
+            }
+
+            #[inline]
+            fn immediate_bytes(&self) -> &[u8] {
+                &self.immediate
+            }
+        }
+
+        impl Decode for $name {
+            fn decode(available: &[u8]) -> Result<Self, DecodeError> {
+                decode_immediate(available, Self::MNEMONIC).map(|immediate| Self { immediate })
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{} {}", self.opcode(), self.$getter())
+            }
+        }
+
+        impl_immediate_fmt!($name);
+    };
+}
+
+define_immediate_instruction!(
+    /// Jump by a relative offset (`RJUMP`).
+    ///
+    /// The offset is relative to the end of this instruction.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{instruction::RJump, AssemblyInstruction};
+    /// let rjump = RJump::new(-5);
+    /// assert_eq!(rjump.offset(), -5);
+    /// assert_eq!(rjump.size(), 3);
+    /// assert_eq!(rjump.to_string(), "RJUMP -5");
+    /// assert_eq!(format!("{rjump:x}"), "e0fffb");
+    /// ```
+    RJump = RJUMP, offset: i16
+);
+
+define_immediate_instruction!(
+    /// Conditionally jump by a relative offset (`RJUMPI`).
+    ///
+    /// The offset is relative to the end of this instruction.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::instruction::RJumpI;
+    /// assert_eq!(RJumpI::new(16).to_string(), "RJUMPI 16");
+    /// ```
+    RJumpI = RJUMPI, offset: i16
+);
+
+define_immediate_instruction!(
+    /// Call a code section (`CALLF`).
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::instruction::CallF;
+    /// assert_eq!(CallF::new(2).section(), 2);
+    /// assert_eq!(CallF::new(2).to_string(), "CALLF 2");
+    /// ```
+    CallF = CALLF, section: u16
+);
+
+define_immediate_instruction!(
+    /// Jump to a code section without returning (`JUMPF`).
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::instruction::JumpF;
+    /// assert_eq!(JumpF::new(1).to_string(), "JUMPF 1");
+    /// ```
+    JumpF = JUMPF, section: u16
+);
+
+define_immediate_instruction!(
+    /// Load word from the data section of the current container at a static offset
+    /// (`DATALOADN`).
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::instruction::DataLoadN;
+    /// assert_eq!(DataLoadN::new(32).to_string(), "DATALOADN 32");
+    /// ```
+    DataLoadN = DATALOADN, offset: u16
+);
+
+define_immediate_instruction!(
+    /// Duplicate the stack item at an immediate depth (`DUPN`).
+    ///
+    /// The immediate value `n` duplicates the `n + 1`th stack item.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::instruction::DupN;
+    /// assert_eq!(DupN::new(17).to_string(), "DUPN 17");
+    /// ```
+    DupN = DUPN, immediate: u8
+);
+
+define_immediate_instruction!(
+    /// Exchange the 1st stack item with the stack item at an immediate depth (`SWAPN`).
+    ///
+    /// The immediate value `n` exchanges the 1st stack item with the `n + 2`th stack item.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::instruction::SwapN;
+    /// assert_eq!(SwapN::new(17).to_string(), "SWAPN 17");
+    /// ```
+    SwapN = SWAPN, immediate: u8
+);
+
+define_immediate_instruction!(
+    /// Exchange two stack items at immediate depths (`EXCHANGE`).
+    ///
+    /// See [`Exchange::n`] and [`Exchange::m`] for how the immediate value is interpreted.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::instruction::Exchange;
+    /// assert_eq!(Exchange::new(0x12).to_string(), "EXCHANGE 18");
+    /// ```
+    Exchange = EXCHANGE, immediate: u8
+);
+
+impl Exchange {
+    /// Returns the depth of the first exchanged stack item below the top of the stack, which is
+    /// stored in the high nibble of the immediate value.
+    ///
+    /// The `n + 1`th stack item is exchanged with the `n + m + 1`th stack item.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::instruction::Exchange;
+    /// assert_eq!(Exchange::new(0x12).n(), 2);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn n(&self) -> u8 {
+        (self.immediate() >> 4) + 1
+    }
+
+    /// Returns the distance between the exchanged stack items, which is stored in the low nibble
+    /// of the immediate value.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::instruction::Exchange;
+    /// assert_eq!(Exchange::new(0x12).m(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn m(&self) -> u8 {
+        (self.immediate() & 0x0F) + 1
+    }
+}
+
+define_immediate_instruction!(
+    /// Create a new account from a subcontainer of the current container (`EOFCREATE`).
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::instruction::EofCreate;
+    /// assert_eq!(EofCreate::new(0).to_string(), "EOFCREATE 0");
+    /// ```
+    EofCreate = EOFCREATE, container: u8
+);
+
+define_immediate_instruction!(
+    /// Halt execution returning a subcontainer as the deployed contract (`RETURNCONTRACT`).
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::instruction::ReturnContract;
+    /// assert_eq!(ReturnContract::new(1).to_string(), "RETURNCONTRACT 1");
+    /// ```
+    ReturnContract = RETURNCONTRACT, container: u8
+);
+
+/// Jump by one of the relative offsets of a jump table (`RJUMPV`).
+///
+/// The immediate value is the maximum index of the jump table followed by the big endian offsets
+/// of the table, which makes its size depend on the amount of offsets. The offsets are relative
+/// to the end of this instruction.
+///
+/// # Example
+/// ```
+/// # use oculars_asm2::{instruction::RJumpV, AssemblyInstruction};
+/// let rjumpv = RJumpV::new(&[3, -2]).unwrap();
+/// assert_eq!(rjumpv.max_index(), 1);
+/// assert_eq!(rjumpv.immediate_size(), 5);
+/// assert_eq!(rjumpv.to_string(), "RJUMPV [3, -2]");
+/// assert_eq!(format!("{rjumpv:x}"), "e2010003fffe");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RJumpV {
+    /// The immediate value of this instruction, including the maximum index.
+    immediate: Vec<u8>,
+}
+
+impl RJumpV {
+    /// Creates a new `RJUMPV` instruction with a jump table. Returns [`None`] if the table is
+    /// empty or has more than 256 offsets.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::instruction::RJumpV;
+    /// assert!(RJumpV::new(&[0; 256]).is_some());
+    /// assert!(RJumpV::new(&[0; 257]).is_none());
+    /// assert!(RJumpV::new(&[]).is_none());
+    /// ```
+    #[must_use]
+    pub fn new(offsets: &[i16]) -> Option<Self> {
+        let max_index = u8::try_from(offsets.len().checked_sub(1)?).ok()?;
+
+        let mut immediate = Vec::with_capacity(1 + offsets.len() * 2);
+        immediate.push(max_index);
+        immediate.extend(offsets.iter().flat_map(|offset| offset.to_be_bytes()));
+
+        Some(Self { immediate })
+    }
+
+    /// Returns the maximum index of the jump table, which is one less than the amount of offsets.
+    #[must_use]
+    #[inline]
+    pub fn max_index(&self) -> u8 {
+        self.immediate[0]
+    }
+
+    /// Returns the size of the immediate value that starts with `available`, which may be cut off
+    /// by the end of the bytes.
+    pub(crate) fn immediate_size_of(available: &[u8]) -> usize {
+        // Without the maximum index, the immediate value has at least the size of a table with
+        // a single offset.
+        available
+            .first()
+            .map_or(3, |&max_index| 1 + (usize::from(max_index) + 1) * 2)
+    }
+
+    /// Returns the offsets of the jump table.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::instruction::RJumpV;
+    /// let rjumpv = RJumpV::new(&[3, -2]).unwrap();
+    /// assert_eq!(rjumpv.offsets().collect::<Vec<_>>(), [3, -2]);
+    /// ```
+    #[must_use]
+    pub fn offsets(&self) -> impl ExactSizeIterator<Item = i16> + '_ {
+        self.immediate[1..]
+            .chunks_exact(2)
+            .map(|offset| i16::from_be_bytes([offset[0], offset[1]]))
+    }
+}
+
+impl KnownInstruction for RJumpV {
+    const MNEMONIC: Mnemonic = Mnemonic::RJUMPV;
+}
+
+impl AssemblyInstruction for RJumpV {
+    fn opcode(&self) -> OpCode {
+        OpCode::Known(Self::MNEMONIC)
+    }
+
+    #[inline]
+    fn immediate_size(&self) -> usize {
+        self.immediate.len()
+    }
+
+    #[inline]
+    fn immediate_bytes(&self) -> &[u8] {
+        &self.immediate
+    }
+}
+
+impl Decode for RJumpV {
+    fn decode(available: &[u8]) -> Result<Self, DecodeError> {
+        let expected = Self::immediate_size_of(available);
+
+        let immediate = available
+            .get(..expected)
+            .ok_or(DecodeError::TruncatedImmediate {
+                mnemonic: Self::MNEMONIC,
+                expected,
+                available: available.len(),
+            })?;
+
+        Ok(Self {
+            immediate: immediate.to_vec(),
+        })
+    }
+}
+
+impl core::fmt::Display for RJumpV {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} [", self.opcode())?;
+
+        for (i, offset) in self.offsets().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{offset}")?;
+        }
+
+        write!(f, "]")
+    }
+}
+
+impl_immediate_fmt!(RJumpV);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Instruction,
+        instruction::{DataCopy, ExtCall, RetF},
+    };
+
+    #[test]
+    fn decodes_eof_instructions() {
+        assert_eq!(
+            Instruction::decode(&[0xE0, 0xFF, 0xFB, 0x00]),
+            Ok((Instruction::RJump(RJump::new(-5)), 3))
+        );
+        assert_eq!(
+            Instruction::decode(&[0xE3, 0x00, 0x02]),
+            Ok((Instruction::CallF(CallF::new(2)), 3))
+        );
+        assert_eq!(
+            Instruction::decode(&[0xE6, 0x11]),
+            Ok((Instruction::DupN(DupN::new(17)), 2))
+        );
+        assert_eq!(
+            Instruction::decode(&[0xE4]),
+            Ok((Instruction::RetF(RetF), 1))
+        );
+        assert_eq!(
+            Instruction::decode(&[0xD3]),
+            Ok((Instruction::DataCopy(DataCopy), 1))
+        );
+        assert_eq!(
+            Instruction::decode(&[0xF8]),
+            Ok((Instruction::ExtCall(ExtCall), 1))
+        );
+    }
+
+    #[test]
+    fn decodes_jump_tables() {
+        let bytes = [0xE2, 0x02, 0x00, 0x01, 0xFF, 0xFE, 0x00, 0x10, 0x5B];
+        let (instruction, size) = Instruction::decode(&bytes).unwrap();
+
+        assert_eq!(size, 8);
+        assert_eq!(instruction.immediate_size(), 7);
+        assert_eq!(instruction.immediate_bytes(), &bytes[1..8]);
+        assert_eq!(
+            instruction,
+            Instruction::RJumpV(RJumpV::new(&[1, -2, 16]).unwrap())
+        );
+
+        let mut encoded = vec![];
+        instruction.encode_into(&mut encoded);
+        assert_eq!(encoded, bytes[..8]);
+    }
+
+    #[test]
+    fn reports_truncated_immediates() {
+        assert_eq!(
+            Instruction::decode(&[0xE0, 0x01]),
+            Err(DecodeError::TruncatedImmediate {
+                mnemonic: Mnemonic::RJUMP,
+                expected: 2,
+                available: 1
+            })
+        );
+        assert_eq!(
+            Instruction::decode(&[0xE2]),
+            Err(DecodeError::TruncatedImmediate {
+                mnemonic: Mnemonic::RJUMPV,
+                expected: 3,
+                available: 0
+            })
+        );
+        assert_eq!(
+            Instruction::decode(&[0xE2, 0xFF, 0x00, 0x01]),
+            Err(DecodeError::TruncatedImmediate {
+                mnemonic: Mnemonic::RJUMPV,
+                expected: 513,
+                available: 3
+            })
+        );
+    }
+
+    #[test]
+    fn eof_fmt_is_sane() {
+        assert_eq!(RJumpI::new(-300).to_string(), "RJUMPI -300");
+        assert_eq!(format!("{:X}", RJumpI::new(-300)), "E1FED4");
+        assert_eq!(format!("{:b}", DupN::new(1)), "1110011000000001");
+        assert_eq!(
+            RJumpV::new(&[i16::MIN, 0]).unwrap().to_string(),
+            "RJUMPV [-32768, 0]"
+        );
+        assert_eq!(RetF.to_string(), "RETF");
+    }
+
+    #[test]
+    fn jump_table_sizes() {
+        let rjumpv = RJumpV::new(&[0; 256]).unwrap();
+        assert_eq!(rjumpv.max_index(), 255);
+        assert_eq!(rjumpv.offsets().len(), 256);
+        assert_eq!(rjumpv.size(), 514);
+    }
+}
@@ -1,6 +1,10 @@
 //! The `LOGx` instruction.
 
-use crate::{fmt::forward_opcode_fmt, AssemblyInstruction, Mnemonic, OpCode};
+use crate::{
+    AssemblyInstruction, KnownInstruction, Mnemonic, OpCode,
+    decode::{Decode, DecodeError},
+    fmt::forward_opcode_fmt,
+};
 
 /// Append log record with `N` topics.
 /// The `N` constant signifies the type of the `LOG` opcode (e.g. `Log<3>` => `LOG3`).
@@ -47,16 +51,27 @@ impl<const N: u8> Default for Log<N> {
     }
 }
 
+impl<const N: u8> KnownInstruction for Log<N> {
+    const MNEMONIC: Mnemonic = match N {
+        0 => Mnemonic::LOG0,
+        1 => Mnemonic::LOG1,
+        2 => Mnemonic::LOG2,
+        3 => Mnemonic::LOG3,
+        4 => Mnemonic::LOG4,
+        _ => panic!("Only `Log<X>` instructions where `X` <= 4 are supported."),
+    };
+}
+
 impl<const N: u8> AssemblyInstruction for Log<N> {
-    fn opcode(&self) -> crate::OpCode {
-        OpCode::Known(match N {
-            0 => Mnemonic::LOG0,
-            1 => Mnemonic::LOG1,
-            2 => Mnemonic::LOG2,
-            3 => Mnemonic::LOG3,
-            4 => Mnemonic::LOG4,
-            _ => panic!("Only `Log<X>` instructions where `X` <= 4 are supported."),
-        })
+    fn opcode(&self) -> OpCode {
+        OpCode::Known(Self::MNEMONIC)
+    }
+}
+
+impl<const N: u8> Decode for Log<N> {
+    #[inline]
+    fn decode(_: &[u8]) -> Result<Self, DecodeError> {
+        Ok(Self::new())
     }
 }
 
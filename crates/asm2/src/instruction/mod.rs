@@ -1,14 +1,21 @@
 //! EVM instruction definitions.
 
 mod dup;
+#[cfg(feature = "eof")]
+mod eof;
 mod log;
 mod push;
 mod swap;
 mod unknown;
 
 pub use crate::defs::instruction::*;
-use crate::{defs::instruction::macros::match_instruction, AssemblyInstruction, OpCode};
+use crate::{AssemblyInstruction, OpCode, defs::instruction::macros::match_instruction};
 pub use dup::Dup;
+#[cfg(feature = "eof")]
+pub use eof::{
+    CallF, DataLoadN, DupN, EofCreate, Exchange, JumpF, RJump, RJumpI, RJumpV, ReturnContract,
+    SwapN,
+};
 pub use log::Log;
 pub use push::Push;
 pub use swap::Swap;
@@ -19,9 +26,13 @@ impl AssemblyInstruction for Instruction {
         match_instruction!(self, AssemblyInstruction::opcode)
     }
 
-    fn immediate_size(&self) -> u8 {
+    fn immediate_size(&self) -> usize {
         match_instruction!(self, AssemblyInstruction::immediate_size)
     }
+
+    fn immediate_bytes(&self) -> &[u8] {
+        match_instruction!(self, AssemblyInstruction::immediate_bytes)
+    }
 }
 
 #[cfg(test)]
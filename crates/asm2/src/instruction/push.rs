@@ -1,6 +1,9 @@
 //! The `PUSHx` instruction.
 
-use crate::{AssemblyInstruction, Mnemonic, OpCode};
+use crate::{
+    Address, AssemblyInstruction, Instruction, KnownInstruction, Mnemonic, OpCode, Selector, U256,
+    decode::{Decode, DecodeError},
+};
 
 /// Place `N`-byte item on stack.
 /// The `N` constant signifies the type of the `PUSH` opcode (e.g. `Push<32>` => `PUSH32`).
@@ -74,52 +77,252 @@ impl<const N: usize> Push<N> {
     }
 }
 
+impl<const N: usize> Push<N> {
+    /// Returns the immediate value as a 256-bit unsigned integer.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{instruction::Push, U256};
+    /// assert_eq!(Push::new([0x05, 0x39]).to_u256(), U256::from(1337_u64));
+    /// assert_eq!(Push::new([]).to_u256(), U256::ZERO);
+    /// ```
+    #[must_use]
+    pub fn to_u256(&self) -> U256 {
+        let mut bytes = [0; 32];
+        bytes[32 - N..].copy_from_slice(&self.immediate);
+        U256::from_be_bytes(bytes)
+    }
+
+    /// Returns the immediate value as a [`u64`] or [`None`] if the value does not fit.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::instruction::Push;
+    /// assert_eq!(Push::new([0x05, 0x39]).as_u64(), Some(1337));
+    /// assert_eq!(Push::new([0, 0, 0, 0, 0, 0, 0, 0, 0, 1]).as_u64(), Some(1));
+    /// assert_eq!(Push::new([0xFF; 9]).as_u64(), None);
+    /// ```
+    #[must_use]
+    pub fn as_u64(&self) -> Option<u64> {
+        self.to_u256().to_u64()
+    }
+
+    /// Returns the immediate value as a [`u128`] or [`None`] if the value does not fit.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::instruction::Push;
+    /// assert_eq!(Push::new([0xFF; 16]).as_u128(), Some(u128::MAX));
+    /// assert_eq!(Push::new([0xFF; 17]).as_u128(), None);
+    /// ```
+    #[must_use]
+    pub fn as_u128(&self) -> Option<u128> {
+        self.to_u256().to_u128()
+    }
+}
+
+impl Push<20> {
+    /// Returns the immediate value as an address.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{instruction::Push, Address};
+    /// assert_eq!(Push::new([0xAB; 20]).address(), Address::new([0xAB; 20]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn address(&self) -> Address {
+        Address::new(self.immediate)
+    }
+}
+
+impl Push<4> {
+    /// Returns the immediate value as a function selector.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{instruction::Push, Selector};
+    /// let push = Push::new([0xA9, 0x05, 0x9C, 0xBB]);
+    /// assert_eq!(push.selector().to_string(), "0xa9059cbb");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn selector(&self) -> Selector {
+        Selector::new(self.immediate)
+    }
+}
+
+/// Matches the length of a byte slice and creates the push of the same size.
+macro_rules! push_from_slice {
+    ($bytes: ident, $($size: literal => $name: ident),+) => {
+        match $bytes.len() {
+            $(
+                $size => <[u8; $size]>::try_from($bytes)
+                    .ok()
+                    .map(|immediate| Instruction::$name(Push::new(immediate))),
+            )+
+            _ => None,
+        }
+    };
+}
+
+impl Instruction {
+    /// Creates the smallest push that places `value` on the stack, using `PUSH0` (EIP-3855) for
+    /// zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{instruction::Push, Instruction};
+    /// assert_eq!(Instruction::push(0_u64), Instruction::Push0(Push::new([])));
+    /// assert_eq!(Instruction::push(1337_u64), Instruction::Push2(Push::new([0x05, 0x39])));
+    /// ```
+    #[must_use]
+    pub fn push<V: Into<U256>>(value: V) -> Self {
+        let value = value.into();
+        Self::push_sized(&value, value.byte_len())
+            .unwrap_or_else(|| unreachable!("a word has at most 32 bytes"))
+    }
+
+    /// Creates the smallest push that places `value` on the stack without using `PUSH0`, which is
+    /// not available before Shanghai. Zero is pushed with `PUSH1`.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{instruction::Push, Instruction};
+    /// assert_eq!(Instruction::push_without_push0(0_u64), Instruction::Push1(Push::new([0])));
+    /// assert_eq!(Instruction::push_without_push0(256_u64), Instruction::push(256_u64));
+    /// ```
+    #[must_use]
+    pub fn push_without_push0<V: Into<U256>>(value: V) -> Self {
+        let value = value.into();
+        Self::push_sized(&value, value.byte_len().max(1))
+            .unwrap_or_else(|| unreachable!("a word has at most 32 bytes"))
+    }
+
+    /// Creates a push that carries exactly `bytes` as its immediate value. Returns [`None`] if
+    /// there are more than 32 bytes.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{instruction::Push, Instruction};
+    /// assert_eq!(
+    ///     Instruction::push_from_slice(&[0, 1]),
+    ///     Some(Instruction::Push2(Push::new([0, 1])))
+    /// );
+    /// assert_eq!(Instruction::push_from_slice(&[0; 33]), None);
+    /// ```
+    #[must_use]
+    pub fn push_from_slice(bytes: &[u8]) -> Option<Self> {
+        push_from_slice!(
+            bytes, 0 => Push0, 1 => Push1, 2 => Push2, 3 => Push3, 4 => Push4, 5 => Push5,
+            6 => Push6, 7 => Push7, 8 => Push8, 9 => Push9, 10 => Push10, 11 => Push11,
+            12 => Push12, 13 => Push13, 14 => Push14, 15 => Push15, 16 => Push16, 17 => Push17,
+            18 => Push18, 19 => Push19, 20 => Push20, 21 => Push21, 22 => Push22, 23 => Push23,
+            24 => Push24, 25 => Push25, 26 => Push26, 27 => Push27, 28 => Push28, 29 => Push29,
+            30 => Push30, 31 => Push31, 32 => Push32
+        )
+    }
+
+    /// Creates a push of `size` bytes carrying `value`, left padded with zeroes. Returns [`None`]
+    /// if `value` does not fit into `size` bytes or `size` is larger than 32 bytes.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{instruction::Push, Instruction};
+    /// assert_eq!(
+    ///     Instruction::push_with_size(1337_u64, 4),
+    ///     Some(Instruction::Push4(Push::new([0, 0, 0x05, 0x39])))
+    /// );
+    /// assert_eq!(Instruction::push_with_size(256_u64, 1), None);
+    /// ```
+    #[must_use]
+    pub fn push_with_size<V: Into<U256>>(value: V, size: usize) -> Option<Self> {
+        let value = value.into();
+        if value.byte_len() > size {
+            return None;
+        }
+
+        Self::push_sized(&value, size)
+    }
+
+    /// Creates a push of `size` bytes carrying `value`, which has to fit into `size` bytes.
+    /// Returns [`None`] if `size` is larger than 32 bytes.
+    fn push_sized(value: &U256, size: usize) -> Option<Self> {
+        let bytes = value.to_be_bytes();
+
+        Self::push_from_slice(&bytes[U256::BYTES.checked_sub(size)?..])
+    }
+}
+
+impl<const N: usize> KnownInstruction for Push<N> {
+    const MNEMONIC: Mnemonic = match N {
+        0 => Mnemonic::PUSH0,
+        1 => Mnemonic::PUSH1,
+        2 => Mnemonic::PUSH2,
+        3 => Mnemonic::PUSH3,
+        4 => Mnemonic::PUSH4,
+        5 => Mnemonic::PUSH5,
+        6 => Mnemonic::PUSH6,
+        7 => Mnemonic::PUSH7,
+        8 => Mnemonic::PUSH8,
+        9 => Mnemonic::PUSH9,
+        10 => Mnemonic::PUSH10,
+        11 => Mnemonic::PUSH11,
+        12 => Mnemonic::PUSH12,
+        13 => Mnemonic::PUSH13,
+        14 => Mnemonic::PUSH14,
+        15 => Mnemonic::PUSH15,
+        16 => Mnemonic::PUSH16,
+        17 => Mnemonic::PUSH17,
+        18 => Mnemonic::PUSH18,
+        19 => Mnemonic::PUSH19,
+        20 => Mnemonic::PUSH20,
+        21 => Mnemonic::PUSH21,
+        22 => Mnemonic::PUSH22,
+        23 => Mnemonic::PUSH23,
+        24 => Mnemonic::PUSH24,
+        25 => Mnemonic::PUSH25,
+        26 => Mnemonic::PUSH26,
+        27 => Mnemonic::PUSH27,
+        28 => Mnemonic::PUSH28,
+        29 => Mnemonic::PUSH29,
+        30 => Mnemonic::PUSH30,
+        31 => Mnemonic::PUSH31,
+        32 => Mnemonic::PUSH32,
+        _ => panic!("only `Push<X>` instructions where `X` <= 32 are supported"),
+    };
+}
+
 impl<const N: usize> AssemblyInstruction for Push<N> {
     fn opcode(&self) -> OpCode {
-        OpCode::Known(match N {
-            0 => Mnemonic::PUSH0,
-            1 => Mnemonic::PUSH1,
-            2 => Mnemonic::PUSH2,
-            3 => Mnemonic::PUSH3,
-            4 => Mnemonic::PUSH4,
-            5 => Mnemonic::PUSH5,
-            6 => Mnemonic::PUSH6,
-            7 => Mnemonic::PUSH7,
-            8 => Mnemonic::PUSH8,
-            9 => Mnemonic::PUSH9,
-            10 => Mnemonic::PUSH10,
-            11 => Mnemonic::PUSH11,
-            12 => Mnemonic::PUSH12,
-            13 => Mnemonic::PUSH13,
-            14 => Mnemonic::PUSH14,
-            15 => Mnemonic::PUSH15,
-            16 => Mnemonic::PUSH16,
-            17 => Mnemonic::PUSH17,
-            18 => Mnemonic::PUSH18,
-            19 => Mnemonic::PUSH19,
-            20 => Mnemonic::PUSH20,
-            21 => Mnemonic::PUSH21,
-            22 => Mnemonic::PUSH22,
-            23 => Mnemonic::PUSH23,
-            24 => Mnemonic::PUSH24,
-            25 => Mnemonic::PUSH25,
-            26 => Mnemonic::PUSH26,
-            27 => Mnemonic::PUSH27,
-            28 => Mnemonic::PUSH28,
-            29 => Mnemonic::PUSH29,
-            30 => Mnemonic::PUSH30,
-            31 => Mnemonic::PUSH31,
-            32 => Mnemonic::PUSH32,
-            _ => panic!("only `Push<X>` instructions where `X` <= 32 are supported"),
-        })
+        OpCode::Known(Self::MNEMONIC)
     }
 
-    #[expect(
-        clippy::cast_possible_truncation,
-        reason = "only `Push<X>` instructions where `X` <= 32 are supported"
-    )]
-    fn immediate_size(&self) -> u8 {
-        N as u8
+    #[inline]
+    fn immediate_size(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn immediate_bytes(&self) -> &[u8] {
+        &self.immediate
+    }
+}
+
+impl<const N: usize> Decode for Push<N> {
+    fn decode(available: &[u8]) -> Result<Self, DecodeError> {
+        let mut immediate = [0; N];
+
+        let Some(bytes) = available.get(..N) else {
+            return Err(DecodeError::TruncatedImmediate {
+                mnemonic: Self::MNEMONIC,
+                expected: N,
+                available: available.len(),
+            });
+        };
+
+        immediate.copy_from_slice(bytes);
+        Ok(Self::new(immediate))
     }
 }
 
@@ -127,8 +330,8 @@ impl<const N: usize> AssemblyInstruction for Push<N> {
 /// the immediate value.
 macro_rules! impl_push_fmt {
     ($fmt: ident, $opcode_fmt: literal, $byte_fmt: literal) => {
-        impl<const N: usize> std::fmt::$fmt for Push<N> {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        impl<const N: usize> core::fmt::$fmt for Push<N> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 write!(f, $opcode_fmt, self.opcode())?;
 
                 for byte in self.immediate() {
@@ -162,6 +365,42 @@ mod tests {
         assert_eq!(push.opcode(), Mnemonic::PUSH4);
     }
 
+    #[test]
+    fn numeric_views() {
+        let push = Push::new([0xFF; 32]);
+        assert_eq!(push.to_u256(), U256::MAX);
+        assert_eq!(push.as_u64(), None);
+
+        let push = Push::new([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x2A]);
+        assert_eq!(push.as_u64(), Some(42));
+        assert_eq!(push.as_u128(), Some(42));
+    }
+
+    #[test]
+    fn minimal_pushes() {
+        assert_eq!(
+            Instruction::push(0xFF_u64),
+            Instruction::Push1(Push::new([0xFF]))
+        );
+        assert_eq!(
+            Instruction::push(0x100_u64),
+            Instruction::Push2(Push::new([0x01, 0x00]))
+        );
+        assert_eq!(
+            Instruction::push(U256::MAX),
+            Instruction::Push32(Push::new([0xFF; 32]))
+        );
+        assert_eq!(
+            Instruction::push(u128::MAX),
+            Instruction::Push16(Push::new([0xFF; 16]))
+        );
+
+        for size in 0..=32 {
+            let push = Instruction::push_from_slice(&vec![0x01; size]).unwrap();
+            assert_eq!(push.immediate_size(), size);
+        }
+    }
+
     #[test]
     fn push_fmt_is_sane() {
         let push = Push::new([0xA, 0xB, 0xC]);
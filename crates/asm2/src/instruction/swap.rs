@@ -1,6 +1,10 @@
 //! The `SWAPx` instruction.
 
-use crate::{fmt::forward_opcode_fmt, AssemblyInstruction, Mnemonic, OpCode};
+use crate::{
+    AssemblyInstruction, KnownInstruction, Mnemonic, OpCode,
+    decode::{Decode, DecodeError},
+    fmt::forward_opcode_fmt,
+};
 
 /// Exchange 1st and `N+1`th stack items.
 /// The `N` constant signifies the type of the `SWAP` opcode (e.g. `Swap<16>` => `SWAP16`).
@@ -47,27 +51,38 @@ impl<const N: u8> Default for Swap<N> {
     }
 }
 
+impl<const N: u8> KnownInstruction for Swap<N> {
+    const MNEMONIC: Mnemonic = match N {
+        1 => Mnemonic::SWAP1,
+        2 => Mnemonic::SWAP2,
+        3 => Mnemonic::SWAP3,
+        4 => Mnemonic::SWAP4,
+        5 => Mnemonic::SWAP5,
+        6 => Mnemonic::SWAP6,
+        7 => Mnemonic::SWAP7,
+        8 => Mnemonic::SWAP8,
+        9 => Mnemonic::SWAP9,
+        10 => Mnemonic::SWAP10,
+        11 => Mnemonic::SWAP11,
+        12 => Mnemonic::SWAP12,
+        13 => Mnemonic::SWAP13,
+        14 => Mnemonic::SWAP14,
+        15 => Mnemonic::SWAP15,
+        16 => Mnemonic::SWAP16,
+        _ => panic!("only `Swap<X>` instructions where `X` >= 1 && `X` <= 16 are supported"),
+    };
+}
+
 impl<const N: u8> AssemblyInstruction for Swap<N> {
     fn opcode(&self) -> OpCode {
-        OpCode::Known(match N {
-            1 => Mnemonic::SWAP1,
-            2 => Mnemonic::SWAP2,
-            3 => Mnemonic::SWAP3,
-            4 => Mnemonic::SWAP4,
-            5 => Mnemonic::SWAP5,
-            6 => Mnemonic::SWAP6,
-            7 => Mnemonic::SWAP7,
-            8 => Mnemonic::SWAP8,
-            9 => Mnemonic::SWAP9,
-            10 => Mnemonic::SWAP10,
-            11 => Mnemonic::SWAP11,
-            12 => Mnemonic::SWAP12,
-            13 => Mnemonic::SWAP13,
-            14 => Mnemonic::SWAP14,
-            15 => Mnemonic::SWAP15,
-            16 => Mnemonic::SWAP16,
-            _ => panic!("only `Swap<X>` instructions where `X` >= 1 && `X` <= 16 are supported"),
-        })
+        OpCode::Known(Self::MNEMONIC)
+    }
+}
+
+impl<const N: u8> Decode for Swap<N> {
+    #[inline]
+    fn decode(_: &[u8]) -> Result<Self, DecodeError> {
+        Ok(Self::new())
     }
 }
 
@@ -1,6 +1,6 @@
 //! An unknown instruction.
 
-use crate::{fmt::forward_opcode_fmt, AssemblyInstruction, OpCode};
+use crate::{AssemblyInstruction, OpCode, fmt::forward_opcode_fmt};
 
 /// An unidentified instruction.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
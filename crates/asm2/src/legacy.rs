@@ -0,0 +1,125 @@
+//! Conversions from and to the legacy `oculars-asm` instruction model.
+//!
+//! Every mnemonic, opcode and instruction converts losslessly in both directions, which allows
+//! migrating from `oculars-asm` one module at a time. The only exception are EOF instructions,
+//! which have no legacy counterpart: their mnemonics fail to convert and their instructions are
+//! converted to unknown instructions.
+//!
+//! # Example
+//! ```
+//! # use oculars_asm2::{instruction::Push, Instruction, Mnemonic};
+//! let legacy = asm::instruction::Instruction::Push2(asm::instruction::Push::new([0x13, 0x37]));
+//! let instruction = Instruction::from(legacy);
+//! assert_eq!(instruction, Instruction::Push2(Push::new([0x13, 0x37])));
+//! assert_eq!(asm::instruction::Instruction::from(instruction), legacy);
+//! assert_eq!(Mnemonic::from(asm::opcode::Mnemonic::GAS), Mnemonic::GAS);
+//! ```
+
+use crate::{
+    OpCode,
+    instruction::{Dup, Log, Push, Swap, Unknown},
+};
+
+impl From<OpCode> for asm::opcode::OpCode {
+    #[inline]
+    fn from(opcode: OpCode) -> Self {
+        Self::from_byte(opcode.into_byte())
+    }
+}
+
+impl From<asm::opcode::OpCode> for OpCode {
+    #[inline]
+    fn from(opcode: asm::opcode::OpCode) -> Self {
+        Self::from_byte(opcode.into_byte())
+    }
+}
+
+impl From<Unknown> for asm::instruction::Unknown {
+    #[inline]
+    fn from(unknown: Unknown) -> Self {
+        Self(unknown.byte())
+    }
+}
+
+impl From<asm::instruction::Unknown> for Unknown {
+    #[inline]
+    fn from(unknown: asm::instruction::Unknown) -> Self {
+        Self::new(unknown.0)
+    }
+}
+
+impl<const N: usize> From<Push<N>> for asm::instruction::Push<N> {
+    #[inline]
+    fn from(push: Push<N>) -> Self {
+        Self::new(*push.immediate())
+    }
+}
+
+impl<const N: usize> From<asm::instruction::Push<N>> for Push<N> {
+    #[inline]
+    fn from(push: asm::instruction::Push<N>) -> Self {
+        Self::new(*push.immediate())
+    }
+}
+
+/// Implements conversions for instructions that are generic over a `u8` constant.
+macro_rules! impl_generic_conversions {
+    ($($name: ident),+) => {
+        $(
+            impl<const N: u8> From<$name<N>> for asm::instruction::$name<N> {
+                #[inline]
+                fn from(_: $name<N>) -> Self {
+                    Self::new()
+                }
+            }
+
+            impl<const N: u8> From<asm::instruction::$name<N>> for $name<N> {
+                #[inline]
+                fn from(_: asm::instruction::$name<N>) -> Self {
+                    Self::new()
+                }
+            }
+        )+
+    };
+}
+
+impl_generic_conversions!(Dup, Swap, Log);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AssemblyInstruction, Instruction, Mnemonic};
+    use asm::instruction::InstructionMeta;
+
+    #[test]
+    fn mnemonic_roundtrip() {
+        for mnemonic in Mnemonic::VARIANTS {
+            let Ok(legacy) = asm::opcode::Mnemonic::try_from(*mnemonic) else {
+                assert!(mnemonic.is_eof(), "{mnemonic}");
+                continue;
+            };
+
+            assert_eq!(legacy as u8, mnemonic.into_byte());
+            assert_eq!(Mnemonic::from(legacy), *mnemonic);
+        }
+    }
+
+    #[test]
+    fn instruction_roundtrip() {
+        for byte in 0..=u8::MAX {
+            let mut bytes = vec![byte];
+            bytes.extend(1..=32);
+
+            let (instruction, _) = Instruction::decode(&bytes).unwrap();
+            let legacy = asm::instruction::Instruction::from(instruction.clone());
+
+            assert_eq!(OpCode::from(legacy.opcode()), instruction.opcode());
+
+            if matches!(instruction.opcode(), OpCode::Known(mnemonic) if mnemonic.is_eof()) {
+                assert!(legacy.opcode().is_unknown(), "{instruction:?}");
+            } else {
+                assert_eq!(Instruction::from(legacy), instruction);
+            }
+        }
+    }
+}
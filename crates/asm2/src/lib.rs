@@ -1,15 +1,37 @@
 //! EVM instructions, opcodes and mnemonics.
+//!
+//! This crate is `no_std` and does not allocate, except for the `eof` (`RJUMPV` jump tables) and
+//! `serde` features, which require `alloc`.
 
+#![cfg_attr(not(test), no_std)]
 #![deny(unsafe_code)]
 
+#[cfg(any(feature = "eof", feature = "serde"))]
+extern crate alloc;
+
 mod assembly;
+mod compact;
+mod decode;
 mod defs;
+mod effects;
+pub mod eval;
 mod fmt;
+mod info;
 pub mod instruction;
+#[cfg(feature = "legacy")]
+mod legacy;
 mod mnemonic;
 mod opcode;
+mod primitives;
+#[cfg(feature = "serde")]
+mod serialization;
 
-pub use assembly::AssemblyInstruction;
+pub use assembly::{AssemblyInstruction, KnownInstruction};
+pub use compact::CompactInstruction;
+pub use decode::DecodeError;
+pub use effects::Effects;
+pub use info::{OPCODE_TABLE, OpInfo};
 pub use instruction::Instruction;
-pub use mnemonic::Mnemonic;
+pub use mnemonic::{Mnemonic, ParseMnemonicError};
 pub use opcode::OpCode;
+pub use primitives::{Address, Selector, U256};
@@ -1,8 +1,8 @@
 //! EVM mnemonics.
 
 pub use crate::defs::mnemonic::Mnemonic;
-use crate::fmt::forward_byte_fmt;
-use std::cmp::Ordering;
+use crate::{fmt::forward_byte_fmt, info::OpInfo};
+use core::{cmp::Ordering, fmt::Display, str::FromStr};
 
 impl Mnemonic {
     /// Converts mnemonic into its byte representation.
@@ -18,6 +18,34 @@ impl Mnemonic {
         self as u8
     }
 
+    /// Attempts to parse a byte as a mnemonic. Returns [`None`] if the byte is not a known
+    /// mnemonic.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::Mnemonic;
+    /// assert_eq!(Mnemonic::from_byte(0x5A), Some(Mnemonic::GAS));
+    /// assert_eq!(Mnemonic::from_byte(0xF), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_byte(byte: u8) -> Option<Self> {
+        OpInfo::of(byte).mnemonic()
+    }
+
+    /// Returns the [opcode information](OpInfo) of this mnemonic.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::Mnemonic;
+    /// assert_eq!(Mnemonic::PUSH4.info().immediate_size(), Some(4));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn info(&self) -> &'static OpInfo {
+        OpInfo::of(self.into_byte())
+    }
+
     /// Returns [`true`] if this mnemonic is of the type `PUSHx`.
     ///
     /// # Example
@@ -29,42 +57,7 @@ impl Mnemonic {
     #[must_use]
     #[inline]
     pub const fn is_push(&self) -> bool {
-        matches!(
-            self,
-            Self::PUSH0
-                | Self::PUSH1
-                | Self::PUSH2
-                | Self::PUSH3
-                | Self::PUSH4
-                | Self::PUSH5
-                | Self::PUSH6
-                | Self::PUSH7
-                | Self::PUSH8
-                | Self::PUSH9
-                | Self::PUSH10
-                | Self::PUSH11
-                | Self::PUSH12
-                | Self::PUSH13
-                | Self::PUSH14
-                | Self::PUSH15
-                | Self::PUSH16
-                | Self::PUSH17
-                | Self::PUSH18
-                | Self::PUSH19
-                | Self::PUSH20
-                | Self::PUSH21
-                | Self::PUSH22
-                | Self::PUSH23
-                | Self::PUSH24
-                | Self::PUSH25
-                | Self::PUSH26
-                | Self::PUSH27
-                | Self::PUSH28
-                | Self::PUSH29
-                | Self::PUSH30
-                | Self::PUSH31
-                | Self::PUSH32
-        )
+        self.info().is_push()
     }
 
     /// Returns [`true`] if this mnemonic is of the type `DUPx`.
@@ -78,25 +71,7 @@ impl Mnemonic {
     #[must_use]
     #[inline]
     pub const fn is_dup(&self) -> bool {
-        matches!(
-            self,
-            Self::DUP1
-                | Self::DUP2
-                | Self::DUP3
-                | Self::DUP4
-                | Self::DUP5
-                | Self::DUP6
-                | Self::DUP7
-                | Self::DUP8
-                | Self::DUP9
-                | Self::DUP10
-                | Self::DUP11
-                | Self::DUP12
-                | Self::DUP13
-                | Self::DUP14
-                | Self::DUP15
-                | Self::DUP16
-        )
+        self.info().is_dup()
     }
 
     /// Returns [`true`] if this mnemonic is of the type `SWAPx`.
@@ -110,25 +85,7 @@ impl Mnemonic {
     #[must_use]
     #[inline]
     pub const fn is_swap(&self) -> bool {
-        matches!(
-            self,
-            Self::SWAP1
-                | Self::SWAP2
-                | Self::SWAP3
-                | Self::SWAP4
-                | Self::SWAP5
-                | Self::SWAP6
-                | Self::SWAP7
-                | Self::SWAP8
-                | Self::SWAP9
-                | Self::SWAP10
-                | Self::SWAP11
-                | Self::SWAP12
-                | Self::SWAP13
-                | Self::SWAP14
-                | Self::SWAP15
-                | Self::SWAP16
-        )
+        self.info().is_swap()
     }
 
     /// Returns [`true`] if this mnemonic is of the type `LOGx`.
@@ -142,13 +99,12 @@ impl Mnemonic {
     #[must_use]
     #[inline]
     pub const fn is_log(&self) -> bool {
-        matches!(
-            self,
-            Self::LOG0 | Self::LOG1 | Self::LOG2 | Self::LOG3 | Self::LOG4
-        )
+        self.info().is_log()
     }
 
     /// Returns [`true`] if this mnemonic terminates execution of the smart contract.
+    ///
+    /// With the `eof` feature, `RETURNCONTRACT` terminates execution as well.
     /// # Example
     /// ```
     /// # use oculars_asm2::Mnemonic;
@@ -160,13 +116,13 @@ impl Mnemonic {
     #[must_use]
     #[inline]
     pub const fn is_terminator(&self) -> bool {
-        matches!(
-            self,
-            Self::STOP | Self::RETURN | Self::REVERT | Self::INVALID | Self::SELFDESTRUCT
-        )
+        self.info().is_terminator()
     }
 
     /// Returns [`true`] if this mnemonic is a `JUMP`, `JUMPI` or a `JUMPDEST`.
+    ///
+    /// With the `eof` feature, the relative jumps (`RJUMP`, `RJUMPI` and `RJUMPV`) and the code
+    /// section instructions (`CALLF`, `RETF` and `JUMPF`) are control flow as well.
     /// # Example
     /// ```
     /// # use oculars_asm2::Mnemonic;
@@ -177,7 +133,24 @@ impl Mnemonic {
     #[must_use]
     #[inline]
     pub const fn is_control_flow(&self) -> bool {
-        matches!(self, Self::JUMP | Self::JUMPI | Self::JUMPDEST)
+        self.info().is_control_flow()
+    }
+
+    /// Returns [`true`] if this mnemonic is only valid inside of EOF containers.
+    ///
+    /// EOF mnemonics are only available with the `eof` feature.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::Mnemonic;
+    /// assert_eq!(Mnemonic::JUMP.is_eof(), false);
+    /// # #[cfg(feature = "eof")]
+    /// assert_eq!(Mnemonic::RJUMP.is_eof(), true);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_eof(&self) -> bool {
+        self.info().is_eof()
     }
 }
 
@@ -218,6 +191,93 @@ impl PartialOrd<Mnemonic> for u8 {
     }
 }
 
+/// An error signifying that a string is not a known mnemonic.
+///
+/// Only the first [`ParseMnemonicError::MAX_NAME_LEN`] bytes of the string are kept, so that the
+/// error does not need to allocate.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseMnemonicError {
+    /// The (possibly truncated) string that failed to parse.
+    name: [u8; Self::MAX_NAME_LEN],
+    /// Length of the kept part of the string.
+    len: usize,
+    /// Whether the string was truncated.
+    truncated: bool,
+}
+
+impl ParseMnemonicError {
+    /// Maximum amount of bytes of the string that failed to parse that are kept.
+    pub const MAX_NAME_LEN: usize = 32;
+
+    /// Creates a new error for the string that failed to parse.
+    pub(crate) fn new(name: &str) -> Self {
+        let mut len = name.len().min(Self::MAX_NAME_LEN);
+        while !name.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        let mut bytes = [0; Self::MAX_NAME_LEN];
+        bytes[..len].copy_from_slice(&name.as_bytes()[..len]);
+
+        Self {
+            name: bytes,
+            len,
+            truncated: len < name.len(),
+        }
+    }
+
+    /// Returns the (possibly truncated) string that failed to parse.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::Mnemonic;
+    /// let error = "PAY_VITALIK".parse::<Mnemonic>().unwrap_err();
+    /// assert_eq!(error.name(), "PAY_VITALIK");
+    /// ```
+    #[must_use]
+    pub fn name(&self) -> &str {
+        // The kept part always ends on a character boundary.
+        core::str::from_utf8(&self.name[..self.len]).unwrap_or_default()
+    }
+}
+
+impl Display for ParseMnemonicError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let ellipsis = if self.truncated { "..." } else { "" };
+        write!(f, "unknown mnemonic `{}{ellipsis}`", self.name())
+    }
+}
+
+impl core::error::Error for ParseMnemonicError {}
+
+impl FromStr for Mnemonic {
+    type Err = ParseMnemonicError;
+
+    /// Parses a mnemonic by its (case-insensitive) name or one of its [`Mnemonic::aliases`].
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::Mnemonic;
+    /// assert_eq!("PUSH1".parse(), Ok(Mnemonic::PUSH1));
+    /// assert_eq!("mstore8".parse(), Ok(Mnemonic::MSTORE8));
+    /// assert_eq!("suicide".parse(), Ok(Mnemonic::SELFDESTRUCT));
+    /// assert!("PAY_VITALIK".parse::<Mnemonic>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::VARIANTS
+            .iter()
+            .find(|mnemonic| {
+                mnemonic.name().eq_ignore_ascii_case(s)
+                    || mnemonic
+                        .aliases()
+                        .iter()
+                        .any(|alias| alias.eq_ignore_ascii_case(s))
+            })
+            .copied()
+            .ok_or_else(|| ParseMnemonicError::new(s))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -233,6 +293,26 @@ mod tests {
         assert_eq!(format!("{GAS:b}"), "1011010");
     }
 
+    #[test]
+    fn mnemonic_parse() {
+        for mnemonic in Mnemonic::VARIANTS {
+            assert_eq!(mnemonic.to_string().parse(), Ok(*mnemonic));
+        }
+
+        assert_eq!("SHA3".parse(), Ok(Mnemonic::KECCAK256));
+        assert_eq!("Difficulty".parse(), Ok(Mnemonic::PREVRANDAO));
+        assert_eq!("SUICIDE".parse(), Ok(Mnemonic::SELFDESTRUCT));
+
+        assert_eq!(
+            "SHA256".parse::<Mnemonic>().unwrap_err().to_string(),
+            "unknown mnemonic `SHA256`"
+        );
+        assert_eq!(
+            "Ä".repeat(20).parse::<Mnemonic>().unwrap_err().to_string(),
+            format!("unknown mnemonic `{}...`", "Ä".repeat(16))
+        );
+    }
+
     #[test]
     fn mnemonic_eq() {
         assert_eq!(GAS, 0x5A);
@@ -1,7 +1,10 @@
 //! EVM operation code.
 
-use crate::{defs::mnemonic::Mnemonic, fmt::forward_byte_fmt};
-use std::fmt::Display;
+use crate::{
+    Effects, defs::mnemonic::Mnemonic, fmt::forward_byte_fmt, info::OpInfo,
+    mnemonic::ParseMnemonicError,
+};
+use core::{fmt::Display, str::FromStr};
 
 /// EVM operation code.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
@@ -53,7 +56,7 @@ impl OpCode {
     #[must_use]
     #[inline]
     pub const fn from_byte(byte: u8) -> Self {
-        match Mnemonic::from_byte(byte) {
+        match OpInfo::of(byte).mnemonic() {
             Some(mnemonic) => Self::Known(mnemonic),
             None => Self::Unknown(byte),
         }
@@ -71,7 +74,7 @@ impl OpCode {
     #[must_use]
     #[inline]
     pub const fn try_from_byte(byte: u8) -> Option<Self> {
-        if let Some(mnemonic) = Mnemonic::from_byte(byte) {
+        if let Some(mnemonic) = OpInfo::of(byte).mnemonic() {
             Some(Self::Known(mnemonic))
         } else {
             None
@@ -95,6 +98,26 @@ impl OpCode {
         }
     }
 
+    /// Returns the [opcode information](OpInfo) of this opcode.
+    ///
+    /// Unknown opcodes are described by [`OpInfo::UNKNOWN`], even if their byte belongs to a
+    /// known mnemonic.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{Mnemonic, OpCode, OpInfo};
+    /// assert_eq!(OpCode::Known(Mnemonic::PUSH0).info().stack_outputs(), 1);
+    /// assert_eq!(OpCode::Unknown(0x5F).info(), &OpInfo::UNKNOWN);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn info(&self) -> &'static OpInfo {
+        match self {
+            OpCode::Known(mnemonic) => mnemonic.info(),
+            OpCode::Unknown(_) => &OpInfo::UNKNOWN,
+        }
+    }
+
     /// Returns [`true`] if this opcode is of the type `PUSHx`.
     ///
     /// # Example
@@ -106,10 +129,7 @@ impl OpCode {
     #[must_use]
     #[inline]
     pub const fn is_push(&self) -> bool {
-        match self {
-            OpCode::Known(mnemonic) => mnemonic.is_push(),
-            OpCode::Unknown(_) => false,
-        }
+        self.info().is_push()
     }
 
     /// Returns [`true`] if this opcode is of the type `DUPx`.
@@ -123,10 +143,7 @@ impl OpCode {
     #[must_use]
     #[inline]
     pub const fn is_dup(&self) -> bool {
-        match self {
-            OpCode::Known(mnemonic) => mnemonic.is_dup(),
-            OpCode::Unknown(_) => false,
-        }
+        self.info().is_dup()
     }
 
     /// Returns [`true`] if this opcode is of the type `SWAPx`.
@@ -140,10 +157,7 @@ impl OpCode {
     #[must_use]
     #[inline]
     pub const fn is_swap(&self) -> bool {
-        match self {
-            OpCode::Known(mnemonic) => mnemonic.is_swap(),
-            OpCode::Unknown(_) => false,
-        }
+        self.info().is_swap()
     }
 
     /// Returns [`true`] if this opcode is of the type `LOGx`.
@@ -157,10 +171,7 @@ impl OpCode {
     #[must_use]
     #[inline]
     pub const fn is_log(&self) -> bool {
-        match self {
-            OpCode::Known(mnemonic) => mnemonic.is_log(),
-            OpCode::Unknown(_) => false,
-        }
+        self.info().is_log()
     }
 
     /// Returns [`true`] if this mnemonic terminates execution of the smart contract.
@@ -175,10 +186,7 @@ impl OpCode {
     #[must_use]
     #[inline]
     pub const fn is_terminator(&self) -> bool {
-        match self {
-            OpCode::Known(mnemonic) => mnemonic.is_terminator(),
-            OpCode::Unknown(_) => true,
-        }
+        self.info().is_terminator()
     }
 
     /// Returns [`true`] if this mnemonic is a `JUMP`, `JUMPI` or a `JUMPDEST`.
@@ -192,17 +200,74 @@ impl OpCode {
     #[must_use]
     #[inline]
     pub const fn is_control_flow(&self) -> bool {
+        self.info().is_control_flow()
+    }
+
+    /// Returns the amount of stack items this opcode reads (pops). Unknown opcodes read none.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{Mnemonic, OpCode};
+    /// assert_eq!(OpCode::Known(Mnemonic::MSTORE).stack_inputs(), 2);
+    /// assert_eq!(OpCode::Unknown(0xF).stack_inputs(), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn stack_inputs(&self) -> u8 {
+        self.info().stack_inputs()
+    }
+
+    /// Returns the amount of stack items this opcode writes (pushes). Unknown opcodes write none.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{Mnemonic, OpCode};
+    /// assert_eq!(OpCode::Known(Mnemonic::MLOAD).stack_outputs(), 1);
+    /// assert_eq!(OpCode::Unknown(0xF).stack_outputs(), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn stack_outputs(&self) -> u8 {
+        self.info().stack_outputs()
+    }
+
+    /// Returns the names of the stack items this opcode reads, starting from the top of the
+    /// stack.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{Mnemonic, OpCode};
+    /// assert_eq!(OpCode::Known(Mnemonic::MSTORE).stack_operands(), &["offset", "value"]);
+    /// assert!(OpCode::Unknown(0xF).stack_operands().is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn stack_operands(&self) -> &'static [&'static str] {
         match self {
-            OpCode::Known(mnemonic) => mnemonic.is_control_flow(),
-            OpCode::Unknown(_) => true,
+            OpCode::Known(mnemonic) => mnemonic.stack_operands(),
+            OpCode::Unknown(_) => &[],
         }
     }
+
+    /// Returns the effects of executing this opcode. Unknown opcodes halt execution.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{Effects, Mnemonic, OpCode};
+    /// assert!(OpCode::Known(Mnemonic::MSTORE).effects().contains(Effects::MEMORY_WRITE));
+    /// assert_eq!(OpCode::Unknown(0xF).effects(), Effects::HALT);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn effects(&self) -> Effects {
+        self.info().effects()
+    }
 }
 
 forward_byte_fmt!(OpCode, LowerHex, UpperHex, Octal, Binary);
 
 impl Display for OpCode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Known(mnemonic) => write!(f, "{mnemonic}"),
             Self::Unknown(_) => write!(f, "UNKNOWN"),
@@ -210,6 +275,29 @@ impl Display for OpCode {
     }
 }
 
+impl FromStr for OpCode {
+    type Err = ParseMnemonicError;
+
+    /// Parses an opcode from a mnemonic name or a hex byte (like `0x0C`).
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::{Mnemonic, OpCode};
+    /// assert_eq!("GAS".parse(), Ok(OpCode::Known(Mnemonic::GAS)));
+    /// assert_eq!("0x5a".parse(), Ok(OpCode::Known(Mnemonic::GAS)));
+    /// assert_eq!("0x0C".parse(), Ok(OpCode::Unknown(0x0C)));
+    /// assert!("0x100".parse::<OpCode>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            Some(hex) => u8::from_str_radix(hex, 16)
+                .map(Self::from_byte)
+                .map_err(|_| ParseMnemonicError::new(s)),
+            None => s.parse().map(Self::Known),
+        }
+    }
+}
+
 impl From<u8> for OpCode {
     #[inline]
     fn from(byte: u8) -> Self {
@@ -245,13 +333,13 @@ impl PartialEq<OpCode> for u8 {
 }
 
 impl PartialOrd<u8> for OpCode {
-    fn partial_cmp(&self, other: &u8) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &u8) -> Option<core::cmp::Ordering> {
         self.into_byte().partial_cmp(other)
     }
 }
 
 impl PartialOrd<OpCode> for u8 {
-    fn partial_cmp(&self, other: &OpCode) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &OpCode) -> Option<core::cmp::Ordering> {
         self.partial_cmp(&other.into_byte())
     }
 }
@@ -275,7 +363,7 @@ impl PartialEq<OpCode> for Mnemonic {
 }
 
 impl PartialOrd<Mnemonic> for OpCode {
-    fn partial_cmp(&self, other: &Mnemonic) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Mnemonic) -> Option<core::cmp::Ordering> {
         match self {
             OpCode::Known(mnemonic) => mnemonic.partial_cmp(other),
             OpCode::Unknown(_) => None,
@@ -284,7 +372,7 @@ impl PartialOrd<Mnemonic> for OpCode {
 }
 
 impl PartialOrd<OpCode> for Mnemonic {
-    fn partial_cmp(&self, other: &OpCode) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &OpCode) -> Option<core::cmp::Ordering> {
         match other {
             OpCode::Known(other) => self.partial_cmp(other),
             OpCode::Unknown(_) => None,
@@ -0,0 +1,478 @@
+//! Wrapping arithmetic and bitwise operations of [`U256`].
+
+use super::U256;
+use core::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
+
+impl U256 {
+    /// Adds `other`, returning the wrapped sum and whether the addition overflowed.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::U256;
+    /// assert_eq!(U256::MAX.overflowing_add(U256::ONE), (U256::ZERO, true));
+    /// ```
+    #[must_use]
+    pub const fn overflowing_add(self, other: Self) -> (Self, bool) {
+        let (a, b) = (self.as_limbs(), other.as_limbs());
+        let mut limbs = [0; 4];
+        let mut carry = false;
+        let mut i = 0;
+
+        while i < 4 {
+            let (sum, overflow) = a[i].overflowing_add(b[i]);
+            let (sum, carry_overflow) = sum.overflowing_add(carry as u64);
+            limbs[i] = sum;
+            carry = overflow || carry_overflow;
+            i += 1;
+        }
+
+        (Self::from_limbs(limbs), carry)
+    }
+
+    /// Subtracts `other`, returning the wrapped difference and whether the subtraction overflowed.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::U256;
+    /// assert_eq!(U256::ZERO.overflowing_sub(U256::ONE), (U256::MAX, true));
+    /// ```
+    #[must_use]
+    pub const fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        let (a, b) = (self.as_limbs(), other.as_limbs());
+        let mut limbs = [0; 4];
+        let mut borrow = false;
+        let mut i = 0;
+
+        while i < 4 {
+            let (difference, overflow) = a[i].overflowing_sub(b[i]);
+            let (difference, borrow_overflow) = difference.overflowing_sub(borrow as u64);
+            limbs[i] = difference;
+            borrow = overflow || borrow_overflow;
+            i += 1;
+        }
+
+        (Self::from_limbs(limbs), borrow)
+    }
+
+    /// Adds `other`, wrapping around at `2^256`.
+    #[must_use]
+    #[inline]
+    pub const fn wrapping_add(self, other: Self) -> Self {
+        self.overflowing_add(other).0
+    }
+
+    /// Subtracts `other`, wrapping around at `2^256`.
+    #[must_use]
+    #[inline]
+    pub const fn wrapping_sub(self, other: Self) -> Self {
+        self.overflowing_sub(other).0
+    }
+
+    /// Negates the integer in two's complement.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::U256;
+    /// assert_eq!(U256::ONE.wrapping_neg(), U256::MAX);
+    /// assert_eq!(U256::ZERO.wrapping_neg(), U256::ZERO);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn wrapping_neg(self) -> Self {
+        Self::ZERO.wrapping_sub(self)
+    }
+
+    /// Multiplies by `other`, returning the full 512-bit product as little-endian limbs.
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "the product is split into limbs"
+    )]
+    const fn widening_mul(self, other: Self) -> [u64; 8] {
+        let (a, b) = (self.as_limbs(), other.as_limbs());
+        let mut limbs = [0; 8];
+        let mut i = 0;
+
+        while i < 4 {
+            let mut carry = 0_u128;
+            let mut j = 0;
+
+            while j < 4 {
+                let product = a[i] as u128 * b[j] as u128 + limbs[i + j] as u128 + carry;
+                limbs[i + j] = product as u64;
+                carry = product >> 64;
+                j += 1;
+            }
+
+            limbs[i + 4] = carry as u64;
+            i += 1;
+        }
+
+        limbs
+    }
+
+    /// Multiplies by `other`, wrapping around at `2^256`.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::U256;
+    /// assert_eq!(U256::from(6_u64).wrapping_mul(U256::from(7_u64)), U256::from(42_u64));
+    /// assert_eq!(U256::MAX.wrapping_mul(U256::MAX), U256::ONE);
+    /// ```
+    #[must_use]
+    pub const fn wrapping_mul(self, other: Self) -> Self {
+        let limbs = self.widening_mul(other);
+        Self::from_limbs([limbs[0], limbs[1], limbs[2], limbs[3]])
+    }
+
+    /// Raises the integer to the power of `exponent`, wrapping around at `2^256`.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::U256;
+    /// assert_eq!(U256::from(3_u64).wrapping_pow(U256::from(4_u64)), U256::from(81_u64));
+    /// assert_eq!(U256::from(2_u64).wrapping_pow(U256::from(256_u64)), U256::ZERO);
+    /// ```
+    #[must_use]
+    pub const fn wrapping_pow(self, exponent: Self) -> Self {
+        let mut result = Self::ONE;
+        let mut bit = exponent.bits();
+
+        while bit > 0 {
+            bit -= 1;
+
+            result = result.wrapping_mul(result);
+            if exponent.bit(bit) {
+                result = result.wrapping_mul(self);
+            }
+        }
+
+        result
+    }
+
+    /// Divides by `divisor`, returning the quotient and the remainder or [`None`] if `divisor` is
+    /// zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::U256;
+    /// assert_eq!(
+    ///     U256::from(43_u64).checked_div_rem(U256::from(5_u64)),
+    ///     Some((U256::from(8_u64), U256::from(3_u64)))
+    /// );
+    /// assert_eq!(U256::ONE.checked_div_rem(U256::ZERO), None);
+    /// ```
+    #[must_use]
+    pub const fn checked_div_rem(self, divisor: Self) -> Option<(Self, Self)> {
+        if divisor.is_zero() {
+            return None;
+        }
+
+        if let Some(divisor) = divisor.to_u64() {
+            let (quotient, remainder) = self.div_rem_u64(divisor);
+            return Some((quotient, Self::from_limbs([remainder, 0, 0, 0])));
+        }
+
+        let mut quotient = [0; 4];
+        let mut remainder = Self::ZERO;
+        let mut bit = self.bits();
+
+        while bit > 0 {
+            bit -= 1;
+
+            // Shifting can carry out of the top bit, the remainder is larger than the divisor
+            // then.
+            let overflow = remainder.bit(255);
+            remainder = remainder.shl_bits(1).with_bit(0, self.bit(bit));
+
+            if overflow || !remainder.lt(&divisor) {
+                remainder = remainder.wrapping_sub(divisor);
+                quotient[(bit / 64) as usize] |= 1 << (bit % 64);
+            }
+        }
+
+        Some((Self::from_limbs(quotient), remainder))
+    }
+
+    /// Returns the remainder of dividing the little-endian `limbs` of a wide integer by
+    /// `modulus`, which must not be zero.
+    const fn wide_rem(limbs: &[u64], modulus: Self) -> Self {
+        let mut remainder = Self::ZERO;
+        let mut bit = limbs.len() * 64;
+
+        while bit > 0 {
+            bit -= 1;
+
+            // See `checked_div_rem`.
+            let overflow = remainder.bit(255);
+            let set = (limbs[bit / 64] >> (bit % 64)) & 1 == 1;
+            remainder = remainder.shl_bits(1).with_bit(0, set);
+
+            if overflow || !remainder.lt(&modulus) {
+                remainder = remainder.wrapping_sub(modulus);
+            }
+        }
+
+        remainder
+    }
+
+    /// Adds `other` modulo `modulus` without the intermediate sum wrapping around, returns
+    /// [`None`] if `modulus` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::U256;
+    /// assert_eq!(U256::MAX.checked_add_mod(U256::from(2_u64), U256::MAX), Some(U256::from(2_u64)));
+    /// ```
+    #[must_use]
+    pub const fn checked_add_mod(self, other: Self, modulus: Self) -> Option<Self> {
+        if modulus.is_zero() {
+            return None;
+        }
+
+        let (sum, carry) = self.overflowing_add(other);
+        let limbs = sum.as_limbs();
+
+        Some(Self::wide_rem(
+            &[limbs[0], limbs[1], limbs[2], limbs[3], carry as u64],
+            modulus,
+        ))
+    }
+
+    /// Multiplies by `other` modulo `modulus` without the intermediate product wrapping around,
+    /// returns [`None`] if `modulus` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::U256;
+    /// assert_eq!(U256::MAX.checked_mul_mod(U256::MAX, U256::from(12_u64)), Some(U256::from(9_u64)));
+    /// ```
+    #[must_use]
+    pub const fn checked_mul_mod(self, other: Self, modulus: Self) -> Option<Self> {
+        if modulus.is_zero() {
+            return None;
+        }
+
+        Some(Self::wide_rem(&self.widening_mul(other), modulus))
+    }
+
+    /// Returns [`true`] if the bit at `index` (counted from the least significant bit) is set.
+    /// Bits past the 256th are never set.
+    #[must_use]
+    #[inline]
+    pub const fn bit(&self, index: u32) -> bool {
+        index < 256 && (self.as_limbs()[(index / 64) as usize] >> (index % 64)) & 1 == 1
+    }
+
+    /// Returns the integer with the bit at `index` (which must be smaller than 256) set to
+    /// `value`.
+    const fn with_bit(self, index: u32, value: bool) -> Self {
+        let mut limbs = *self.as_limbs();
+        let mask = 1 << (index % 64);
+
+        if value {
+            limbs[(index / 64) as usize] |= mask;
+        } else {
+            limbs[(index / 64) as usize] &= !mask;
+        }
+
+        Self::from_limbs(limbs)
+    }
+
+    /// Returns [`true`] if the integer is negative when interpreted in two's complement.
+    #[must_use]
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.bit(255)
+    }
+
+    /// Compares the integers in a `const` context.
+    const fn lt(&self, other: &Self) -> bool {
+        let (a, b) = (self.as_limbs(), other.as_limbs());
+        let mut i = 4;
+
+        while i > 0 {
+            i -= 1;
+
+            if a[i] != b[i] {
+                return a[i] < b[i];
+            }
+        }
+
+        false
+    }
+
+    /// Shifts the integer left by `shift` bits, shifts of 256 bits or more result in zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::U256;
+    /// assert_eq!(U256::ONE.shl_bits(255).bits(), 256);
+    /// assert_eq!(U256::ONE.shl_bits(256), U256::ZERO);
+    /// ```
+    #[must_use]
+    pub const fn shl_bits(self, shift: u32) -> Self {
+        if shift >= 256 {
+            return Self::ZERO;
+        }
+
+        let limbs = self.as_limbs();
+        let (limb_shift, bit_shift) = ((shift / 64) as usize, shift % 64);
+        let mut shifted = [0; 4];
+        let mut i = 4;
+
+        while i > limb_shift {
+            i -= 1;
+
+            shifted[i] = limbs[i - limb_shift] << bit_shift;
+            if bit_shift > 0 && i > limb_shift {
+                shifted[i] |= limbs[i - limb_shift - 1] >> (64 - bit_shift);
+            }
+        }
+
+        Self::from_limbs(shifted)
+    }
+
+    /// Shifts the integer right by `shift` bits, shifts of 256 bits or more result in zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::U256;
+    /// assert_eq!(U256::MAX.shr_bits(255), U256::ONE);
+    /// assert_eq!(U256::MAX.shr_bits(256), U256::ZERO);
+    /// ```
+    #[must_use]
+    pub const fn shr_bits(self, shift: u32) -> Self {
+        if shift >= 256 {
+            return Self::ZERO;
+        }
+
+        let limbs = self.as_limbs();
+        let (limb_shift, bit_shift) = ((shift / 64) as usize, shift % 64);
+        let mut shifted = [0; 4];
+        let mut i = 0;
+
+        while i + limb_shift < 4 {
+            shifted[i] = limbs[i + limb_shift] >> bit_shift;
+            if bit_shift > 0 && i + limb_shift + 1 < 4 {
+                shifted[i] |= limbs[i + limb_shift + 1] << (64 - bit_shift);
+            }
+
+            i += 1;
+        }
+
+        Self::from_limbs(shifted)
+    }
+
+    /// Applies `op` to every pair of limbs.
+    fn map_limbs(self, other: Self, op: fn(u64, u64) -> u64) -> Self {
+        let (a, b) = (self.as_limbs(), other.as_limbs());
+        Self::from_limbs([
+            op(a[0], b[0]),
+            op(a[1], b[1]),
+            op(a[2], b[2]),
+            op(a[3], b[3]),
+        ])
+    }
+}
+
+impl BitAnd for U256 {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        self.map_limbs(other, |a, b| a & b)
+    }
+}
+
+impl BitOr for U256 {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        self.map_limbs(other, |a, b| a | b)
+    }
+}
+
+impl BitXor for U256 {
+    type Output = Self;
+
+    #[inline]
+    fn bitxor(self, other: Self) -> Self {
+        self.map_limbs(other, |a, b| a ^ b)
+    }
+}
+
+impl Not for U256 {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        self.map_limbs(Self::ZERO, |a, _| !a)
+    }
+}
+
+impl Shl<u32> for U256 {
+    type Output = Self;
+
+    #[inline]
+    fn shl(self, shift: u32) -> Self {
+        self.shl_bits(shift)
+    }
+}
+
+impl Shr<u32> for U256 {
+    type Output = Self;
+
+    #[inline]
+    fn shr(self, shift: u32) -> Self {
+        self.shr_bits(shift)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn carries_across_limbs() {
+        let low = U256::from(u64::MAX);
+        assert_eq!(low.wrapping_add(U256::ONE), U256::from_limbs([0, 1, 0, 0]));
+        assert_eq!(U256::from_limbs([0, 1, 0, 0]).wrapping_sub(U256::ONE), low);
+        assert_eq!(
+            low.wrapping_mul(low),
+            U256::from(u128::from(u64::MAX) * u128::from(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn division() {
+        let dividend = U256::from_limbs([7, 0, 3, 0x8000_0000_0000_0000]);
+        let divisor = U256::from_limbs([0, 5, 1, 0]);
+        let (quotient, remainder) = dividend.checked_div_rem(divisor).unwrap();
+
+        assert!(remainder < divisor);
+        assert_eq!(
+            quotient.wrapping_mul(divisor).wrapping_add(remainder),
+            dividend
+        );
+        assert_eq!(
+            U256::MAX.checked_div_rem(U256::MAX),
+            Some((U256::ONE, U256::ZERO))
+        );
+    }
+
+    #[test]
+    fn shifts() {
+        let value = U256::from_limbs([0x0123_4567_89AB_CDEF, 0, 0, 0]);
+        for shift in 0..256 {
+            assert_eq!(
+                (value << shift) >> shift,
+                value & (U256::MAX >> shift),
+                "{shift}"
+            );
+        }
+
+        assert_eq!(U256::ONE << 64, U256::from_limbs([0, 1, 0, 0]));
+        assert_eq!(!U256::ZERO, U256::MAX);
+    }
+}
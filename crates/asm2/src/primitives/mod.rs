@@ -0,0 +1,116 @@
+//! Primitive EVM types.
+
+mod arith;
+mod u256;
+
+pub use u256::U256;
+
+/// Defines a fixed size byte array newtype that formats as a hex string.
+macro_rules! define_fixed_bytes {
+    ($(#[$meta: meta])* $name: ident, $size: literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $name([u8; $size]);
+
+        impl $name {
+            /// Size in bytes.
+            pub const SIZE: usize = $size;
+
+            /// Creates a new value from its bytes.
+            #[must_use]
+            #[inline]
+            pub const fn new(bytes: [u8; $size]) -> Self {
+                Self(bytes)
+            }
+
+            /// Returns a reference to the bytes.
+            #[must_use]
+            #[inline]
+            pub const fn as_bytes(&self) -> &[u8; $size] {
+                &self.0
+            }
+
+            /// Returns the bytes.
+            #[must_use]
+            #[inline]
+            pub const fn into_bytes(self) -> [u8; $size] {
+                self.0
+            }
+        }
+
+        impl From<[u8; $size]> for $name {
+            #[inline]
+            fn from(bytes: [u8; $size]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl From<$name> for [u8; $size] {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            #[inline]
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "0x")?;
+                core::fmt::LowerHex::fmt(self, f)
+            }
+        }
+
+        impl core::fmt::LowerHex for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                for byte in self.0 {
+                    write!(f, "{byte:02x}")?;
+                }
+
+                Ok(())
+            }
+        }
+
+        impl core::fmt::UpperHex for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                for byte in self.0 {
+                    write!(f, "{byte:02X}")?;
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+define_fixed_bytes!(
+    /// A 20 byte account address.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::Address;
+    /// let address = Address::new([0xAB; 20]);
+    /// assert_eq!(address.to_string(), format!("0x{}", "ab".repeat(20)));
+    /// ```
+    Address,
+    20
+);
+
+define_fixed_bytes!(
+    /// A 4 byte function selector.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::Selector;
+    /// let selector = Selector::new([0xA9, 0x05, 0x9C, 0xBB]);
+    /// assert_eq!(selector.to_string(), "0xa9059cbb");
+    /// assert_eq!(format!("{selector:X}"), "A9059CBB");
+    /// ```
+    Selector,
+    4
+);
@@ -0,0 +1,408 @@
+//! 256-bit unsigned integer.
+
+use core::{
+    cmp::Ordering,
+    fmt::{Display, Write},
+};
+
+/// A 256-bit unsigned integer, the EVM word.
+///
+/// # Example
+/// ```
+/// # use oculars_asm2::U256;
+/// let value = U256::from(1337_u64);
+/// assert_eq!(value.to_string(), "1337");
+/// assert_eq!(format!("{value:x}"), "539");
+/// assert_eq!(value.to_be_bytes()[30..], [0x05, 0x39]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct U256 {
+    /// Little-endian 64-bit limbs.
+    limbs: [u64; 4],
+}
+
+impl U256 {
+    /// Size of the integer in bytes.
+    pub const BYTES: usize = 32;
+
+    /// The value `0`.
+    pub const ZERO: Self = Self::from_limbs([0; 4]);
+
+    /// The value `1`.
+    pub const ONE: Self = Self::from_limbs([1, 0, 0, 0]);
+
+    /// The largest value, `2^256 - 1`.
+    pub const MAX: Self = Self::from_limbs([u64::MAX; 4]);
+
+    /// Creates an integer from little-endian 64-bit limbs.
+    #[must_use]
+    #[inline]
+    pub const fn from_limbs(limbs: [u64; 4]) -> Self {
+        Self { limbs }
+    }
+
+    /// Returns the little-endian 64-bit limbs of the integer.
+    #[must_use]
+    #[inline]
+    pub const fn as_limbs(&self) -> &[u64; 4] {
+        &self.limbs
+    }
+
+    /// Creates an integer from its big-endian byte representation.
+    #[must_use]
+    pub const fn from_be_bytes(bytes: [u8; 32]) -> Self {
+        let mut limbs = [0; 4];
+        let mut i = 0;
+
+        while i < 4 {
+            let mut limb = [0; 8];
+            let mut j = 0;
+
+            while j < 8 {
+                limb[j] = bytes[32 - (i + 1) * 8 + j];
+                j += 1;
+            }
+
+            limbs[i] = u64::from_be_bytes(limb);
+            i += 1;
+        }
+
+        Self { limbs }
+    }
+
+    /// Creates an integer from a big-endian byte slice of up to 32 bytes. Returns [`None`] if the
+    /// slice is longer.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::U256;
+    /// assert_eq!(U256::from_be_slice(&[0x05, 0x39]), Some(U256::from(1337_u64)));
+    /// assert_eq!(U256::from_be_slice(&[]), Some(U256::ZERO));
+    /// assert_eq!(U256::from_be_slice(&[0; 33]), None);
+    /// ```
+    #[must_use]
+    pub fn from_be_slice(slice: &[u8]) -> Option<Self> {
+        let padding = Self::BYTES.checked_sub(slice.len())?;
+        let mut bytes = [0; 32];
+        bytes[padding..].copy_from_slice(slice);
+
+        Some(Self::from_be_bytes(bytes))
+    }
+
+    /// Returns the big-endian byte representation of the integer.
+    #[must_use]
+    pub const fn to_be_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        let mut i = 0;
+
+        while i < 4 {
+            let limb = self.limbs[i].to_be_bytes();
+            let mut j = 0;
+
+            while j < 8 {
+                bytes[32 - (i + 1) * 8 + j] = limb[j];
+                j += 1;
+            }
+
+            i += 1;
+        }
+
+        bytes
+    }
+
+    /// Returns [`true`] if the integer is zero.
+    #[must_use]
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.limbs[0] == 0 && self.limbs[1] == 0 && self.limbs[2] == 0 && self.limbs[3] == 0
+    }
+
+    /// Returns the amount of bits required to represent the integer.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::U256;
+    /// assert_eq!(U256::ZERO.bits(), 0);
+    /// assert_eq!(U256::from(255_u64).bits(), 8);
+    /// assert_eq!(U256::MAX.bits(), 256);
+    /// ```
+    #[must_use]
+    pub const fn bits(&self) -> u32 {
+        let mut i: u32 = 4;
+
+        while i > 0 {
+            i -= 1;
+
+            let limb = self.limbs[i as usize];
+            if limb != 0 {
+                return (i + 1) * 64 - limb.leading_zeros();
+            }
+        }
+
+        0
+    }
+
+    /// Returns the amount of bytes required to represent the integer.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm2::U256;
+    /// assert_eq!(U256::ZERO.byte_len(), 0);
+    /// assert_eq!(U256::from(256_u64).byte_len(), 2);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn byte_len(&self) -> usize {
+        self.bits().div_ceil(8) as usize
+    }
+
+    /// Returns the integer as a [`u64`] or [`None`] if it does not fit.
+    #[must_use]
+    #[inline]
+    pub const fn to_u64(&self) -> Option<u64> {
+        if self.limbs[1] == 0 && self.limbs[2] == 0 && self.limbs[3] == 0 {
+            Some(self.limbs[0])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the integer as a [`u128`] or [`None`] if it does not fit.
+    #[must_use]
+    #[inline]
+    pub const fn to_u128(&self) -> Option<u128> {
+        if self.limbs[2] == 0 && self.limbs[3] == 0 {
+            Some(((self.limbs[1] as u128) << 64) | self.limbs[0] as u128)
+        } else {
+            None
+        }
+    }
+
+    /// Divides the integer by `divisor`, returning the quotient and the remainder.
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "the quotient of a limb and the remainder always fit into a limb"
+    )]
+    pub(super) const fn div_rem_u64(self, divisor: u64) -> (Self, u64) {
+        let mut quotient = [0; 4];
+        let mut remainder = 0_u128;
+        let mut i = 4;
+
+        while i > 0 {
+            i -= 1;
+
+            let dividend = (remainder << 64) | self.limbs[i] as u128;
+            let divisor = divisor as u128;
+
+            quotient[i] = (dividend / divisor) as u64;
+            remainder = dividend % divisor;
+        }
+
+        (Self::from_limbs(quotient), remainder as u64)
+    }
+}
+
+impl From<u64> for U256 {
+    #[inline]
+    fn from(value: u64) -> Self {
+        Self::from_limbs([value, 0, 0, 0])
+    }
+}
+
+impl From<u128> for U256 {
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "the value is split into limbs"
+    )]
+    #[inline]
+    fn from(value: u128) -> Self {
+        Self::from_limbs([value as u64, (value >> 64) as u64, 0, 0])
+    }
+}
+
+impl From<[u8; 32]> for U256 {
+    #[inline]
+    fn from(bytes: [u8; 32]) -> Self {
+        Self::from_be_bytes(bytes)
+    }
+}
+
+impl From<U256> for [u8; 32] {
+    #[inline]
+    fn from(value: U256) -> Self {
+        value.to_be_bytes()
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs.iter().rev().cmp(other.limbs.iter().rev())
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A buffer for the digits of a formatted [`U256`], which avoids allocating while formatting.
+struct DigitBuffer {
+    /// The written digits.
+    digits: [u8; 78],
+    /// Amount of written digits.
+    len: usize,
+}
+
+impl DigitBuffer {
+    /// Creates an empty buffer.
+    const fn new() -> Self {
+        Self {
+            digits: [0; 78],
+            len: 0,
+        }
+    }
+
+    /// Returns [`true`] if no digits were written.
+    const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the written digits.
+    fn as_str(&self) -> &str {
+        // Only `str`s are written into the buffer.
+        core::str::from_utf8(&self.digits[..self.len]).unwrap_or_default()
+    }
+}
+
+impl Write for DigitBuffer {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let digits = self
+            .digits
+            .get_mut(self.len..self.len + s.len())
+            .ok_or(core::fmt::Error)?;
+
+        digits.copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+impl Display for U256 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        /// Largest power of ten that fits into a limb.
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+
+        if let Some(value) = self.to_u64() {
+            return Display::fmt(&value, f);
+        }
+
+        // A 78 digit word is made up of at most 5 chunks.
+        let mut chunks = [0; 5];
+        let mut count = 0;
+        let mut value = *self;
+
+        while !value.is_zero() {
+            let (quotient, remainder) = value.div_rem_u64(CHUNK);
+            chunks[count] = remainder;
+            count += 1;
+            value = quotient;
+        }
+
+        let mut digits = DigitBuffer::new();
+        for (i, chunk) in chunks[..count].iter().rev().enumerate() {
+            if i == 0 {
+                write!(digits, "{chunk}")?;
+            } else {
+                write!(digits, "{chunk:019}")?;
+            }
+        }
+
+        f.pad_integral(true, "", digits.as_str())
+    }
+}
+
+/// Implements hexadecimal formatting for [`U256`].
+macro_rules! impl_hex_fmt {
+    ($fmt: ident, $limb_fmt: literal, $prefix: literal) => {
+        impl core::fmt::$fmt for U256 {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut digits = DigitBuffer::new();
+
+                for limb in self.limbs.iter().rev() {
+                    if digits.is_empty() {
+                        if *limb != 0 {
+                            write!(digits, $limb_fmt, limb, width = 0)?;
+                        }
+                    } else {
+                        write!(digits, $limb_fmt, limb, width = 16)?;
+                    }
+                }
+
+                if digits.is_empty() {
+                    digits.write_char('0')?;
+                }
+
+                f.pad_integral(true, $prefix, digits.as_str())
+            }
+        }
+    };
+}
+
+impl_hex_fmt!(LowerHex, "{:0width$x}", "0x");
+impl_hex_fmt!(UpperHex, "{:0width$X}", "0x");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn byte_conversions() {
+        let mut bytes = [0; 32];
+        bytes[0] = 0x80;
+        bytes[31] = 0x01;
+
+        let value = U256::from_be_bytes(bytes);
+        assert_eq!(value.as_limbs(), &[1, 0, 0, 0x8000_0000_0000_0000]);
+        assert_eq!(value.to_be_bytes(), bytes);
+        assert_eq!(value.bits(), 256);
+        assert_eq!(value.byte_len(), 32);
+        assert_eq!(value.to_u128(), None);
+    }
+
+    #[test]
+    fn integer_conversions() {
+        assert_eq!(U256::from(u64::MAX).to_u64(), Some(u64::MAX));
+        assert_eq!(U256::from(u128::MAX).to_u64(), None);
+        assert_eq!(U256::from(u128::MAX).to_u128(), Some(u128::MAX));
+        assert_eq!(U256::from(u128::MAX).bits(), 128);
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(U256::from(u128::MAX) < U256::MAX);
+        assert!(U256::from_limbs([0, 0, 0, 1]) > U256::from_limbs([u64::MAX, u64::MAX, 1, 0]));
+        assert_eq!(U256::ONE.cmp(&U256::ONE), Ordering::Equal);
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!(U256::ZERO.to_string(), "0");
+        assert_eq!(
+            U256::MAX.to_string(),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        assert_eq!(
+            U256::from(10_u128.pow(19)).to_string(),
+            "10000000000000000000"
+        );
+        assert_eq!(format!("{:x}", U256::ZERO), "0");
+        assert_eq!(format!("{:#x}", U256::from(255_u64)), "0xff");
+        assert_eq!(format!("{:X}", U256::MAX), "F".repeat(64));
+        assert_eq!(
+            format!("{:x}", U256::from_limbs([1, 0, 2, 0])),
+            "200000000000000000000000000000001"
+        );
+        assert_eq!(format!("{:>6}", U256::from(42_u64)), "    42");
+    }
+}
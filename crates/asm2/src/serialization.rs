@@ -0,0 +1,398 @@
+//! [`serde`] support.
+//!
+//! Mnemonics serialize as their name (`"PUSH1"`) and opcodes as their mnemonic's name, or as a
+//! hex byte (`"0x0c"`) if they are unknown. Instructions serialize as a map with the `mnemonic`
+//! opcode and the `immediate` value as a hex string, which is omitted if the instruction has no
+//! immediate value:
+//!
+//! ```json
+//! { "mnemonic": "PUSH2", "immediate": "0x1337" }
+//! { "mnemonic": "GAS" }
+//! { "mnemonic": "0x0c" }
+//! ```
+
+use crate::{
+    AssemblyInstruction, Instruction, Mnemonic, OpCode,
+    decode::{Decode, DecodeError},
+    instruction::{Dup, Log, Push, Swap, Unknown},
+};
+#[cfg(feature = "eof")]
+use crate::{
+    KnownInstruction,
+    instruction::{
+        CallF, DataLoadN, DupN, EofCreate, Exchange, JumpF, RJump, RJumpI, RJumpV, ReturnContract,
+        SwapN,
+    },
+};
+use alloc::{format, string::String, vec, vec::Vec};
+use core::fmt::Write;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as _, ser::SerializeMap};
+
+impl Serialize for Mnemonic {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Mnemonic {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for OpCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Known(mnemonic) => mnemonic.serialize(serializer),
+            Self::Unknown(byte) => serializer.serialize_str(&format!("0x{byte:02x}")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for OpCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Serialized form of an instruction.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawInstruction {
+    /// Opcode of the instruction.
+    mnemonic: OpCode,
+    /// Hex encoded immediate value.
+    #[serde(default)]
+    immediate: Option<String>,
+}
+
+/// Serializes an instruction as a map of its opcode and immediate value.
+pub(crate) fn serialize_instruction<I: AssemblyInstruction, S: Serializer>(
+    instruction: &I,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let immediate = instruction.immediate_bytes();
+    let mut map = serializer.serialize_map(Some(if immediate.is_empty() { 1 } else { 2 }))?;
+
+    map.serialize_entry("mnemonic", &instruction.opcode())?;
+
+    if !immediate.is_empty() {
+        let mut hex = String::from("0x");
+        for byte in immediate {
+            write!(hex, "{byte:02x}").map_err(serde::ser::Error::custom)?;
+        }
+
+        map.serialize_entry("immediate", &hex)?;
+    }
+
+    map.end()
+}
+
+/// Deserializes the bytecode of an instruction.
+fn deserialize_bytes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let raw = RawInstruction::deserialize(deserializer)?;
+    let mut bytes = vec![raw.mnemonic.into_byte()];
+
+    if let Some(immediate) = raw.immediate {
+        let immediate = immediate
+            .strip_prefix("0x")
+            .and_then(|hex| hex::decode(hex).ok())
+            .ok_or_else(|| {
+                D::Error::custom("immediate value must be a `0x` prefixed hex string")
+            })?;
+        bytes.extend(immediate);
+    }
+
+    Ok(bytes)
+}
+
+/// Converts a [`DecodeError`] into a deserialization error.
+fn decode_error<E: serde::de::Error>(error: DecodeError) -> E {
+    match error {
+        DecodeError::TruncatedImmediate {
+            mnemonic,
+            expected,
+            available,
+        } => E::custom(format!(
+            "immediate value of `{mnemonic}` must be {expected} bytes long, found {available}"
+        )),
+        DecodeError::Empty => E::custom(error),
+    }
+}
+
+/// Checks that a decoded instruction used up all of the deserialized bytes.
+fn check_size<I: AssemblyInstruction, E: serde::de::Error>(
+    instruction: &I,
+    bytes: &[u8],
+) -> Result<(), E> {
+    let expected = instruction.immediate_size();
+    let found = bytes.len() - 1;
+
+    if expected == found {
+        Ok(())
+    } else {
+        Err(E::custom(format!(
+            "immediate value of `{}` must be {expected} bytes long, found {found}",
+            instruction.opcode()
+        )))
+    }
+}
+
+/// Deserializes an instruction that must have the `expected` opcode.
+pub(crate) fn deserialize_instruction<
+    'de,
+    D: Deserializer<'de>,
+    I: Decode + AssemblyInstruction,
+>(
+    deserializer: D,
+    expected: OpCode,
+) -> Result<I, D::Error> {
+    let bytes = deserialize_bytes(deserializer)?;
+    let found = OpCode::from_byte(bytes[0]);
+
+    if found != expected {
+        return Err(D::Error::custom(format!(
+            "expected `{expected}`, found `{found}`"
+        )));
+    }
+
+    let instruction = I::decode(&bytes[1..]).map_err(decode_error)?;
+    check_size(&instruction, &bytes)?;
+
+    Ok(instruction)
+}
+
+impl Serialize for Instruction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_instruction(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Instruction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = deserialize_bytes(deserializer)?;
+        let (instruction, _) = Self::decode(&bytes).map_err(decode_error)?;
+        check_size(&instruction, &bytes)?;
+
+        Ok(instruction)
+    }
+}
+
+impl Serialize for Unknown {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_instruction(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Unknown {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = deserialize_bytes(deserializer)?;
+        let opcode = OpCode::from_byte(bytes[0]);
+
+        if opcode.is_known() {
+            return Err(D::Error::custom(format!(
+                "expected an unknown opcode, found `{opcode}`"
+            )));
+        }
+
+        let instruction = Self::new(bytes[0]);
+        check_size(&instruction, &bytes)?;
+
+        Ok(instruction)
+    }
+}
+
+impl<const N: usize> Serialize for Push<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_instruction(self, serializer)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for Push<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_instruction(deserializer, Self::new([0; N]).opcode())
+    }
+}
+
+/// Implements serialization for instructions that are generic over a `u8` constant.
+macro_rules! impl_generic_serde {
+    ($($name: ident),+) => {
+        $(
+            impl<const N: u8> Serialize for $name<N> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize_instruction(self, serializer)
+                }
+            }
+
+            impl<'de, const N: u8> Deserialize<'de> for $name<N> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize_instruction(deserializer, Self::new().opcode())
+                }
+            }
+        )+
+    };
+}
+
+impl_generic_serde!(Dup, Swap, Log);
+
+/// Implements serialization for instructions with an immediate value.
+#[cfg(feature = "eof")]
+macro_rules! impl_immediate_serde {
+    ($($name: ident),+) => {
+        $(
+            impl Serialize for $name {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize_instruction(self, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize_instruction(deserializer, OpCode::Known(Self::MNEMONIC))
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "eof")]
+impl_immediate_serde!(
+    RJump,
+    RJumpI,
+    RJumpV,
+    CallF,
+    JumpF,
+    DataLoadN,
+    DupN,
+    SwapN,
+    Exchange,
+    EofCreate,
+    ReturnContract
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruction::{Add, Gas};
+    use serde_json::{from_str, json, to_value};
+
+    #[test]
+    fn mnemonic_and_opcode_shape() {
+        assert_eq!(to_value(Mnemonic::PUSH1).unwrap(), json!("PUSH1"));
+        assert_eq!(
+            to_value(OpCode::Known(Mnemonic::GAS)).unwrap(),
+            json!("GAS")
+        );
+        assert_eq!(to_value(OpCode::Unknown(0x0C)).unwrap(), json!("0x0c"));
+
+        assert_eq!(from_str::<Mnemonic>("\"CALL\"").unwrap(), Mnemonic::CALL);
+        assert_eq!(
+            from_str::<OpCode>("\"0x0c\"").unwrap(),
+            OpCode::Unknown(0x0C)
+        );
+        assert!(from_str::<Mnemonic>("\"PAY_VITALIK\"").is_err());
+    }
+
+    #[test]
+    fn instruction_shape() {
+        assert_eq!(to_value(Add).unwrap(), json!({ "mnemonic": "ADD" }));
+        assert_eq!(
+            to_value(Push::new([0x13, 0x37])).unwrap(),
+            json!({ "mnemonic": "PUSH2", "immediate": "0x1337" })
+        );
+        assert_eq!(
+            to_value(Push::new([])).unwrap(),
+            json!({ "mnemonic": "PUSH0" })
+        );
+        assert_eq!(
+            to_value(Instruction::Unknown(Unknown::new(0x0C))).unwrap(),
+            json!({ "mnemonic": "0x0c" })
+        );
+    }
+
+    #[test]
+    fn instruction_roundtrip() {
+        for byte in 0..=u8::MAX {
+            let mut bytes = vec![byte];
+            bytes.extend(1..=32);
+
+            let (instruction, _) = Instruction::decode(&bytes).unwrap();
+            let json = serde_json::to_string(&instruction).unwrap();
+            assert_eq!(
+                from_str::<Instruction>(&json).unwrap(),
+                instruction,
+                "{json}"
+            );
+        }
+
+        let dup = Dup::<3>::new();
+        assert_eq!(
+            from_str::<Dup<3>>(&serde_json::to_string(&dup).unwrap()).unwrap(),
+            dup
+        );
+        assert_eq!(from_str::<Gas>(r#"{"mnemonic":"GAS"}"#).unwrap(), Gas);
+    }
+
+    #[test]
+    fn invalid_instructions() {
+        let error = |json| from_str::<Instruction>(json).unwrap_err().to_string();
+
+        assert!(
+            error(r#"{"mnemonic":"PUSH2","immediate":"0x13"}"#)
+                .contains("immediate value of `PUSH2` must be 2 bytes long, found 1")
+        );
+        assert!(
+            error(r#"{"mnemonic":"GAS","immediate":"0x13"}"#)
+                .contains("immediate value of `GAS` must be 0 bytes long, found 1")
+        );
+        assert!(
+            error(r#"{"mnemonic":"PUSH1","immediate":"1337"}"#)
+                .contains("immediate value must be a `0x` prefixed hex string")
+        );
+        assert!(
+            error(r#"{"mnemonic":"PUSH1","immediate":"0xZZ"}"#)
+                .contains("immediate value must be a `0x` prefixed hex string")
+        );
+        assert!(
+            error(r#"{"mnemonic":"PUSH1","immediate":"0x+1"}"#)
+                .contains("immediate value must be a `0x` prefixed hex string")
+        );
+
+        assert!(
+            from_str::<Gas>(r#"{"mnemonic":"ADD"}"#)
+                .unwrap_err()
+                .to_string()
+                .contains("expected `GAS`, found `ADD`")
+        );
+        assert!(from_str::<Push<1>>(r#"{"mnemonic":"PUSH2","immediate":"0x1337"}"#).is_err());
+        assert!(from_str::<Unknown>(r#"{"mnemonic":"GAS"}"#).is_err());
+    }
+
+    #[cfg(feature = "eof")]
+    #[test]
+    fn eof_instruction_shape() {
+        let rjumpv = RJumpV::new(&[3, -2]).unwrap();
+        assert_eq!(
+            to_value(&rjumpv).unwrap(),
+            json!({ "mnemonic": "RJUMPV", "immediate": "0x010003fffe" })
+        );
+        assert_eq!(
+            from_str::<Instruction>(r#"{"mnemonic":"RJUMPV","immediate":"0x010003fffe"}"#).unwrap(),
+            Instruction::RJumpV(rjumpv)
+        );
+        assert_eq!(
+            to_value(RJump::new(-5)).unwrap(),
+            json!({ "mnemonic": "RJUMP", "immediate": "0xfffb" })
+        );
+        assert!(
+            from_str::<RJumpV>(r#"{"mnemonic":"RJUMPV","immediate":"0x010003fffe00"}"#)
+                .unwrap_err()
+                .to_string()
+                .contains("immediate value of `RJUMPV` must be 5 bytes long, found 6")
+        );
+    }
+}
@@ -1,5 +1,7 @@
 //! Ethereum chains.
 
+#![no_std]
+
 /// Chain information.
 pub trait Chain {
     /// Chain id.
@@ -1,15 +1,180 @@
 //! EVM assembly.
+//!
+//! With the `serde` feature enabled, an [`Assembly`] serializes as a list of its instructions,
+//! each carrying its `offset` in the bytecode next to the instruction's `mnemonic` and hex
+//! `immediate` value:
+//!
+//! ```json
+//! [
+//!     { "offset": 0, "mnemonic": "PUSH1", "immediate": "0x80" },
+//!     { "offset": 2, "mnemonic": "CALLVALUE" }
+//! ]
+//! ```
+//!
+//! [`BorrowedAssembly`] is the zero-copy counterpart of [`Assembly`], its instructions borrow their
+//! immediate values from the disassembled bytecode.
 
-use asm::instruction::Instruction;
+use alloc::vec::Vec;
+use asm2::{CompactInstruction, Instruction};
 
 /// An instruction with a specified position in the bytecode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PositionedInstruction {
     /// Position of this instruction in the bytecode.
+    #[cfg_attr(feature = "serde", serde(rename = "offset"))]
     pub position: usize,
 
     /// The instruction at this position in the bytecode.
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub instruction: Instruction,
 }
 
 /// Disassembled EVM bytecode.
-pub struct Assembly(/* Vec<PositionedInstruction> */);
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Assembly(Vec<PositionedInstruction>);
+
+impl Assembly {
+    /// Returns the positioned instructions of the assembly.
+    #[must_use]
+    pub fn instructions(&self) -> &[PositionedInstruction] {
+        &self.0
+    }
+
+    /// Returns an iterator over the positioned instructions of the assembly.
+    pub fn iter(&self) -> core::slice::Iter<'_, PositionedInstruction> {
+        self.0.iter()
+    }
+}
+
+impl From<Vec<PositionedInstruction>> for Assembly {
+    fn from(instructions: Vec<PositionedInstruction>) -> Self {
+        Self(instructions)
+    }
+}
+
+impl<'a> IntoIterator for &'a Assembly {
+    type Item = &'a PositionedInstruction;
+    type IntoIter = core::slice::Iter<'a, PositionedInstruction>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A [`PositionedInstruction`] that borrows its immediate value from the bytecode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BorrowedPositionedInstruction<'a> {
+    /// Position of this instruction in the bytecode.
+    pub position: usize,
+
+    /// The instruction at this position in the bytecode.
+    pub instruction: CompactInstruction<'a>,
+}
+
+impl BorrowedPositionedInstruction<'_> {
+    /// Converts this instruction into a [`PositionedInstruction`] that owns its immediate value.
+    #[must_use]
+    pub fn into_owned(self) -> PositionedInstruction {
+        PositionedInstruction {
+            position: self.position,
+            instruction: self.instruction.to_instruction(),
+        }
+    }
+}
+
+/// Disassembled EVM bytecode that borrows immediate values from the bytecode.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BorrowedAssembly<'a>(Vec<BorrowedPositionedInstruction<'a>>);
+
+impl<'a> BorrowedAssembly<'a> {
+    /// Returns the positioned instructions of the assembly.
+    #[must_use]
+    pub fn instructions(&self) -> &[BorrowedPositionedInstruction<'a>] {
+        &self.0
+    }
+
+    /// Returns an iterator over the positioned instructions of the assembly.
+    pub fn iter(&self) -> core::slice::Iter<'_, BorrowedPositionedInstruction<'a>> {
+        self.0.iter()
+    }
+
+    /// Converts this assembly into an [`Assembly`] that owns its immediate values.
+    #[must_use]
+    pub fn into_owned(self) -> Assembly {
+        Assembly(
+            self.0
+                .into_iter()
+                .map(BorrowedPositionedInstruction::into_owned)
+                .collect(),
+        )
+    }
+}
+
+impl<'a> From<Vec<BorrowedPositionedInstruction<'a>>> for BorrowedAssembly<'a> {
+    fn from(instructions: Vec<BorrowedPositionedInstruction<'a>>) -> Self {
+        Self(instructions)
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b BorrowedAssembly<'a> {
+    type Item = &'b BorrowedPositionedInstruction<'a>;
+    type IntoIter = core::slice::Iter<'b, BorrowedPositionedInstruction<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use asm2::instruction::{CallValue, Push, Unknown};
+    use serde_json::json;
+
+    /// Returns a small assembly.
+    fn assembly() -> Assembly {
+        Assembly::from(vec![
+            PositionedInstruction {
+                position: 0,
+                instruction: Instruction::Push1(Push::new([0x80])),
+            },
+            PositionedInstruction {
+                position: 2,
+                instruction: Instruction::CallValue(CallValue),
+            },
+            PositionedInstruction {
+                position: 3,
+                instruction: Instruction::Unknown(Unknown::new(0x0C)),
+            },
+        ])
+    }
+
+    #[test]
+    fn json_shape() {
+        assert_eq!(
+            serde_json::to_value(assembly()).unwrap(),
+            json!([
+                { "offset": 0, "mnemonic": "PUSH1", "immediate": "0x80" },
+                { "offset": 2, "mnemonic": "CALLVALUE" },
+                { "offset": 3, "mnemonic": "0x0c" },
+            ])
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let json = serde_json::to_string(&assembly()).unwrap();
+        assert_eq!(serde_json::from_str::<Assembly>(&json).unwrap(), assembly());
+
+        assert!(
+            serde_json::from_str::<Assembly>(r#"[{ "offset": 0, "mnemonic": "PUSH1" }]"#).is_err()
+        );
+    }
+}
@@ -1,5 +1,7 @@
 //! EVM bytecode.
 
+use alloc::vec::Vec;
+
 /// EVM bytecode.
 #[derive(Debug)]
 pub struct Bytecode(Vec<u8>);
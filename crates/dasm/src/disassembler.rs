@@ -1,23 +1,51 @@
 //! EVM disassembler.
 
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
-use asm::{instruction::Stop, opcode::Mnemonic};
+use alloc::vec::Vec;
+use asm2::{CompactInstruction, DecodeError, Instruction, OpCode, instruction::Unknown};
 use thiserror::Error;
 use upgrades::execution::ExecutionUpgrade;
 
-use crate::{assembly::Assembly, bytecode::Bytecode, source::BytecodeSource};
+use crate::{
+    assembly::{Assembly, BorrowedAssembly, BorrowedPositionedInstruction, PositionedInstruction},
+    bytecode::Bytecode,
+    source::BytecodeSource,
+};
+
+/// Decodes the first instruction of some bytes, returning the instruction and its size.
+type DecodeFn<'a, I> = fn(&'a [u8]) -> Result<(I, usize), DecodeError>;
 
 /// EVM disassembler.
-#[derive(Default)]
+///
+/// Like the EVM, the disassembler zero pads a `PUSHx` immediate value that is cut off by the end
+/// of the bytecode, unless it is created with [`Disassembler::strict`].
 pub struct Disassembler<E: ExecutionUpgrade> {
+    /// Whether truncated immediate values are errors instead of being zero padded.
+    strict: bool,
     /// Marker for storing the `ExecutionUpgrade` generic.
     _marker: PhantomData<E>,
 }
 
+// Implemented manually because deriving would require `E: Default`.
+impl<E: ExecutionUpgrade> Default for Disassembler<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Errors that can happen when disassembling bytecode.
-#[derive(Debug, Error)]
-pub enum DasmError {}
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum DasmError {
+    /// An instruction could not be decoded.
+    #[error("failed to decode instruction at offset {offset}: {source}")]
+    Decode {
+        /// Offset of the instruction in the bytecode.
+        offset: usize,
+        /// The decoding error.
+        source: DecodeError,
+    },
+}
 
 /// An error that can happen when disassembling from source.
 #[derive(Debug, Error)]
@@ -32,22 +60,137 @@ pub enum SourceDasmError<E> {
 }
 
 impl<E: ExecutionUpgrade> Disassembler<E> {
+    /// Creates a disassembler for the execution upgrade `E`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            strict: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a disassembler for the execution upgrade `E` that fails on immediate values cut
+    /// off by the end of the bytecode instead of zero padding them.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::disassembler::Disassembler;
+    /// # use upgrades::forks::shanghai::Shanghai;
+    /// assert!(Disassembler::<Shanghai>::new().disassemble_bytes([0x61, 0x01]).is_ok());
+    /// assert!(Disassembler::<Shanghai>::strict().disassemble_bytes([0x61, 0x01]).is_err());
+    /// ```
+    #[must_use]
+    pub const fn strict() -> Self {
+        Self {
+            strict: true,
+            _marker: PhantomData,
+        }
+    }
+
     /// Disassemble EVM bytecode into an instruction list.
     ///
+    /// Opcodes that are not supported by the execution upgrade `E` are disassembled as
+    /// [`Unknown`] instructions. An immediate value cut off by the end of the bytecode is zero
+    /// padded, see [`Instruction::decode_padded`].
+    ///
     /// # Errors
-    /// TODO
+    /// Returns [`DasmError::Decode`] if the disassembler is [strict](Disassembler::strict) and the
+    /// bytecode ends in the middle of an immediate value.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::disassembler::Disassembler;
+    /// # use upgrades::forks::{paris::Paris, shanghai::Shanghai};
+    /// # use asm2::{AssemblyInstruction, Mnemonic, OpCode};
+    /// let assembly = Disassembler::<Shanghai>::new().disassemble_bytes([0x5F, 0x00]).unwrap();
+    /// assert_eq!(assembly.instructions()[0].instruction.opcode(), OpCode::Known(Mnemonic::PUSH0));
+    ///
+    /// let assembly = Disassembler::<Paris>::new().disassemble_bytes([0x5F, 0x00]).unwrap();
+    /// assert_eq!(assembly.instructions()[0].instruction.opcode(), OpCode::Unknown(0x5F));
+    /// ```
     pub fn disassemble(&self, bytecode: &Bytecode) -> Result<Assembly, DasmError> {
-        for byte in bytecode.as_ref() {
-            match *byte {
-                byte if byte == Mnemonic::STOP as u8 && E::supports_instruction::<Stop>() => {
-                    todo!()
-                }
-                _ => todo!(),
-            }
-            //
+        Self::disassemble_with(
+            bytecode.as_ref(),
+            if self.strict {
+                Instruction::decode
+            } else {
+                Instruction::decode_padded
+            },
+            |byte| Instruction::Unknown(Unknown::new(byte)),
+            |position, instruction| PositionedInstruction {
+                position,
+                instruction,
+            },
+        )
+        .map(Assembly::from)
+    }
+
+    /// Disassemble EVM bytecode into an instruction list that borrows immediate values from the
+    /// bytecode instead of copying them.
+    ///
+    /// Accepts anything that can be viewed as bytes, like [`Bytecode`] or a memory-mapped file. A
+    /// truncated immediate value is borrowed as is, see [`CompactInstruction::decode_padded`].
+    ///
+    /// # Errors
+    /// Returns the same errors as [`Disassembler::disassemble`].
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::{bytecode::Bytecode, disassembler::Disassembler};
+    /// # use upgrades::forks::shanghai::Shanghai;
+    /// # use asm2::AssemblyInstruction;
+    /// let bytecode = Bytecode::from(vec![0x61, 0x13, 0x37, 0x00]);
+    /// let disassembler = Disassembler::<Shanghai>::new();
+    ///
+    /// let assembly = disassembler.disassemble_borrowed(&bytecode).unwrap();
+    /// assert_eq!(assembly.instructions()[0].instruction.immediate_bytes(), &[0x13, 0x37]);
+    /// assert_eq!(assembly.into_owned(), disassembler.disassemble(&bytecode).unwrap());
+    /// ```
+    pub fn disassemble_borrowed<'a, T: AsRef<[u8]> + ?Sized>(
+        &self,
+        bytecode: &'a T,
+    ) -> Result<BorrowedAssembly<'a>, DasmError> {
+        Self::disassemble_with(
+            bytecode.as_ref(),
+            if self.strict {
+                CompactInstruction::decode
+            } else {
+                CompactInstruction::decode_padded
+            },
+            CompactInstruction::unknown,
+            |position, instruction| BorrowedPositionedInstruction {
+                position,
+                instruction,
+            },
+        )
+        .map(BorrowedAssembly::from)
+    }
+
+    /// Disassembles `bytes` into positioned instructions.
+    ///
+    /// Supported opcodes are decoded with `decode`, unsupported ones are turned into unknown
+    /// instructions with `unknown`.
+    fn disassemble_with<'a, I, P>(
+        bytes: &'a [u8],
+        decode: DecodeFn<'a, I>,
+        unknown: fn(u8) -> I,
+        positioned: fn(usize, I) -> P,
+    ) -> Result<Vec<P>, DasmError> {
+        let mut instructions = Vec::new();
+        let mut offset = 0;
+
+        while offset < bytes.len() {
+            let (instruction, size) = if E::supports_opcode(OpCode::from_byte(bytes[offset])) {
+                decode(&bytes[offset..]).map_err(|source| DasmError::Decode { offset, source })?
+            } else {
+                (unknown(bytes[offset]), 1)
+            };
+
+            instructions.push(positioned(offset, instruction));
+            offset += size;
         }
 
-        todo!()
+        Ok(instructions)
     }
 
     /// Disassembles any source that provides [`Bytecode`] into EVM assembly.
@@ -84,3 +227,124 @@ impl<E: ExecutionUpgrade> Disassembler<E> {
         self.disassemble_from_source(bytes.as_ref())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::ToString, vec};
+    use asm2::{
+        AssemblyInstruction,
+        instruction::{CallValue, MStore, Push, Stop},
+    };
+    use upgrades::forks::{frontier::Frontier, prague::Prague};
+
+    #[test]
+    fn disassembles_bytecode() {
+        let assembly = Disassembler::<Prague>::new()
+            .disassemble_hex("0x608060405234005f0c")
+            .unwrap();
+
+        let instructions: Vec<_> = assembly
+            .iter()
+            .map(|positioned| (positioned.position, positioned.instruction.clone()))
+            .collect();
+
+        assert_eq!(
+            instructions,
+            [
+                (0, Instruction::Push1(Push::new([0x80]))),
+                (2, Instruction::Push1(Push::new([0x40]))),
+                (4, Instruction::MStore(MStore)),
+                (5, Instruction::CallValue(CallValue)),
+                (6, Instruction::Stop(Stop)),
+                (7, Instruction::Push0(Push::new([]))),
+                (8, Instruction::Unknown(Unknown::new(0x0C))),
+            ]
+        );
+    }
+
+    #[test]
+    fn unsupported_opcodes_are_unknown() {
+        let assembly = Disassembler::<Frontier>::new()
+            .disassemble_bytes([0xF4, 0x5F, 0x60, 0x01])
+            .unwrap();
+
+        let instructions: Vec<_> = assembly.iter().map(|p| p.instruction.clone()).collect();
+        assert_eq!(
+            instructions,
+            [
+                Instruction::Unknown(Unknown::new(0xF4)),
+                Instruction::Unknown(Unknown::new(0x5F)),
+                Instruction::Push1(Push::new([0x01])),
+            ]
+        );
+    }
+
+    #[test]
+    fn borrowed_disassembly() {
+        let bytecode = Bytecode::from(vec![0x60, 0x80, 0xF4, 0x5F, 0x7F, 0xAB]);
+        let disassembler = Disassembler::<Frontier>::new();
+
+        let assembly = disassembler.disassemble_borrowed(&bytecode).unwrap();
+        let instructions: Vec<_> = assembly
+            .iter()
+            .map(|positioned| (positioned.position, positioned.instruction))
+            .collect();
+
+        assert_eq!(
+            instructions,
+            [
+                (0, CompactInstruction::decode(&[0x60, 0x80]).unwrap().0),
+                (2, CompactInstruction::unknown(0xF4)),
+                (3, CompactInstruction::unknown(0x5F)),
+                (
+                    4,
+                    CompactInstruction::decode_padded(&[0x7F, 0xAB]).unwrap().0
+                ),
+            ]
+        );
+        assert_eq!(
+            instructions[0].1.immediate_bytes().as_ptr(),
+            bytecode.as_ref()[1..].as_ptr()
+        );
+        assert_eq!(
+            assembly.into_owned(),
+            disassembler.disassemble(&bytecode).unwrap()
+        );
+
+        let error = Disassembler::<Frontier>::strict()
+            .disassemble_borrowed(&bytecode)
+            .unwrap_err();
+        assert!(matches!(error, DasmError::Decode { offset: 4, .. }));
+    }
+
+    #[test]
+    fn truncated_push() {
+        let bytecode = Bytecode::from(vec![0x00, 0x61, 0x01]);
+
+        let assembly = Disassembler::<Prague>::new()
+            .disassemble(&bytecode)
+            .unwrap();
+        let instructions: Vec<_> = assembly
+            .iter()
+            .map(|positioned| (positioned.position, positioned.instruction.clone()))
+            .collect();
+        assert_eq!(
+            instructions,
+            [
+                (0, Instruction::Stop(Stop)),
+                (1, Instruction::Push2(Push::new([0x01, 0x00]))),
+            ]
+        );
+
+        let error = Disassembler::<Prague>::strict()
+            .disassemble(&bytecode)
+            .unwrap_err();
+
+        assert!(matches!(error, DasmError::Decode { offset: 1, .. }));
+        assert_eq!(
+            error.to_string(),
+            "failed to decode instruction at offset 1: immediate value of PUSH2 is truncated (expected 2 bytes, 1 available)"
+        );
+    }
+}
@@ -1,10 +1,14 @@
-#![allow(incomplete_features, reason = "it is what it is")]
-#![feature(generic_const_exprs)]
-#![feature(specialization)]
-
 //! EVM disassembler.
+//!
+//! This crate is `no_std` (but requires `alloc`) if the default `std` feature is disabled, which
+//! also disables extracting bytecode from files.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub mod assembly;
 pub mod bytecode;
 pub mod disassembler;
-pub mod instruction;
 pub mod source;
+pub mod syntax;
@@ -2,7 +2,8 @@
 
 use super::BytecodeSource;
 use crate::bytecode::Bytecode;
-use std::convert::Infallible;
+use alloc::vec::Vec;
+use core::convert::Infallible;
 
 impl BytecodeSource for Vec<u8> {
     type Error = Infallible;
@@ -31,6 +32,7 @@ impl<const N: usize> BytecodeSource for [u8; N] {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec;
 
     #[test]
     fn byte_vec_extraction() {
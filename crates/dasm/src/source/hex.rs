@@ -2,6 +2,7 @@
 
 use super::BytecodeSource;
 use crate::bytecode::Bytecode;
+use alloc::string::String;
 use hex::FromHexError;
 
 pub use hex;
@@ -1,6 +1,7 @@
 //! Sources from which bytecode can be extracted.
 
 pub mod bytes;
+#[cfg(feature = "std")]
 pub mod file;
 pub mod hex;
 
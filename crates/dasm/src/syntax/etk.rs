@@ -0,0 +1,96 @@
+//! Syntax of the EVM Toolkit (etk).
+
+use asm2::{AssemblyInstruction, Instruction, OpCode, instruction::Unknown};
+use core::fmt::{self, Write};
+
+use super::{
+    MnemonicNames, ParseErrorKind, ParseSyntax, Syntax, parse_mnemonic_line, write_hex,
+    write_lowercase,
+};
+use crate::assembly::PositionedInstruction;
+
+/// Syntax of the EVM Toolkit (etk) assembler.
+///
+/// Mnemonics are lowercase, unknown opcodes are written as `invalid_XX`:
+///
+/// ```text
+/// push3 0x0a0b0c
+/// invalid_0c
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Etk;
+
+impl Syntax for Etk {
+    fn write_named_instruction<W: Write>(
+        &self,
+        w: &mut W,
+        positioned: &PositionedInstruction,
+        names: MnemonicNames,
+    ) -> fmt::Result {
+        let instruction = &positioned.instruction;
+
+        match instruction.opcode() {
+            OpCode::Known(mnemonic) => write_lowercase(w, names(mnemonic))?,
+            OpCode::Unknown(byte) => return write!(w, "invalid_{byte:02x}"),
+        }
+
+        if !instruction.immediate_bytes().is_empty() {
+            w.write_char(' ')?;
+            write_hex(w, instruction.immediate_bytes())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ParseSyntax for Etk {
+    fn parse_instruction(&self, line: &str) -> Result<Instruction, ParseErrorKind> {
+        let unknown = line
+            .strip_prefix("invalid_")
+            .filter(|byte| byte.len() == 2 && byte.bytes().all(|c| c.is_ascii_hexdigit()))
+            .and_then(|byte| u8::from_str_radix(byte, 16).ok());
+
+        match unknown {
+            Some(byte) => Ok(Instruction::Unknown(Unknown::new(byte))),
+            None => parse_mnemonic_line(line, false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::tests::assembly;
+    use alloc::string::ToString;
+    use asm2::instruction::Invalid;
+
+    /// `assembly()` in etk's syntax.
+    const ETK: &str = "\
+push1 0x80
+push2 0x0008
+jumpi
+push0
+stop
+jumpdest
+keccak256
+invalid_0c
+prevrandao
+";
+
+    #[test]
+    fn etk_roundtrip() {
+        assert_eq!(assembly().display_with(&Etk).to_string(), ETK);
+        assert_eq!(Etk.parse(ETK).unwrap(), assembly());
+    }
+
+    #[test]
+    fn etk_parsing() {
+        assert_eq!(
+            Etk.parse_instruction("invalid"),
+            Ok(Instruction::Invalid(Invalid))
+        );
+        assert!(Etk.parse_instruction("invalid_0").is_err());
+        assert!(Etk.parse_instruction("invalid_+c").is_err());
+        assert!(Etk.parse_instruction("push2 0x010203").is_err());
+    }
+}
@@ -0,0 +1,113 @@
+//! Syntax of geth's `evm disasm`.
+
+use asm2::{AssemblyInstruction, Instruction, OpCode, instruction::Unknown};
+use core::fmt::{self, Write};
+
+use super::{MnemonicNames, ParseErrorKind, ParseSyntax, Syntax, parse_mnemonic_line, write_hex};
+use crate::assembly::PositionedInstruction;
+
+/// Syntax of geth's `evm disasm` command.
+///
+/// Every instruction is prefixed with its hex position, unknown opcodes are reported as not
+/// defined:
+///
+/// ```text
+/// 00000: PUSH1 0x80
+/// 00002: opcode 0xc not defined
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Geth;
+
+impl Syntax for Geth {
+    fn write_named_instruction<W: Write>(
+        &self,
+        w: &mut W,
+        positioned: &PositionedInstruction,
+        names: MnemonicNames,
+    ) -> fmt::Result {
+        let instruction = &positioned.instruction;
+        write!(w, "{:05x}: ", positioned.position)?;
+
+        match instruction.opcode() {
+            OpCode::Known(mnemonic) => w.write_str(names(mnemonic))?,
+            OpCode::Unknown(byte) => return write!(w, "opcode {byte:#x} not defined"),
+        }
+
+        if !instruction.immediate_bytes().is_empty() {
+            w.write_char(' ')?;
+            write_hex(w, instruction.immediate_bytes())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ParseSyntax for Geth {
+    fn parse_instruction(&self, line: &str) -> Result<Instruction, ParseErrorKind> {
+        // The position prefix is optional, positions are recomputed from instruction sizes.
+        let line = line
+            .split_once(':')
+            .filter(|(position, _)| position.chars().all(|c| c.is_ascii_hexdigit()))
+            .map_or(line, |(_, instruction)| instruction.trim());
+
+        match line
+            .split_whitespace()
+            .collect::<alloc::vec::Vec<_>>()
+            .as_slice()
+        {
+            ["opcode", byte, "not", "defined"] => byte
+                .strip_prefix("0x")
+                .filter(|byte| byte.bytes().all(|c| c.is_ascii_hexdigit()))
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .map(|byte| Instruction::Unknown(Unknown::new(byte)))
+                .ok_or_else(|| ParseErrorKind::Immediate((*byte).into())),
+            _ => parse_mnemonic_line(line, false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::tests::assembly;
+    use alloc::string::ToString;
+
+    /// `assembly()` in geth's syntax.
+    const GETH: &str = "\
+00000: PUSH1 0x80
+00002: PUSH2 0x0008
+00005: JUMPI
+00006: PUSH0
+00007: STOP
+00008: JUMPDEST
+00009: KECCAK256
+0000a: opcode 0xc not defined
+0000b: PREVRANDAO
+";
+
+    #[test]
+    fn geth_roundtrip() {
+        assert_eq!(assembly().display_with(&Geth).to_string(), GETH);
+        assert_eq!(Geth.parse(GETH).unwrap(), assembly());
+    }
+
+    #[test]
+    fn geth_parsing() {
+        assert_eq!(
+            Geth.parse_instruction("PUSH1 0x80"),
+            Geth.parse_instruction("00000: PUSH1 0x80")
+        );
+        assert_eq!(
+            Geth.parse_instruction("opcode 0xzz not defined"),
+            Err(ParseErrorKind::Immediate("0xzz".to_string()))
+        );
+        assert_eq!(
+            Geth.parse_instruction("opcode 0x+c not defined"),
+            Err(ParseErrorKind::Immediate("0x+c".to_string()))
+        );
+        assert_eq!(
+            Geth.parse_instruction("00000:"),
+            Err(ParseErrorKind::MissingMnemonic)
+        );
+    }
+}
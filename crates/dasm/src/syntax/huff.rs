@@ -0,0 +1,136 @@
+//! Syntax of Huff.
+
+use alloc::collections::BTreeSet;
+use asm2::{AssemblyInstruction, Mnemonic, OpCode};
+use core::fmt::{self, Write};
+
+use super::{MnemonicNames, Syntax, write_hex, write_lowercase};
+use crate::assembly::{Assembly, PositionedInstruction};
+
+/// Syntax of the body of a Huff macro.
+///
+/// Mnemonics are lowercase and pushes are written as bare literals. When an assembly is written,
+/// `JUMPDEST`s become labels and a push of a `JUMPDEST`'s position that feeds a `JUMP` or `JUMPI`
+/// refers to its label:
+///
+/// ```text
+/// jumpdest_8
+/// jumpi
+///     ...
+/// jumpdest_8:
+/// ```
+///
+/// Huff has no way of writing unknown opcodes, they are written as comments. Huff assembly cannot
+/// be parsed, since pushes do not keep their size.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Huff;
+
+impl Huff {
+    /// Returns the position a push jumps to, if it pushes the position of a `JUMPDEST` and is
+    /// followed by a `JUMP` or `JUMPI`.
+    fn jump_target(
+        push: &PositionedInstruction,
+        next: Option<&PositionedInstruction>,
+        jumpdests: &BTreeSet<usize>,
+    ) -> Option<usize> {
+        let jumps = next.is_some_and(|next| {
+            matches!(
+                next.instruction.opcode(),
+                OpCode::Known(Mnemonic::JUMP | Mnemonic::JUMPI)
+            )
+        });
+        let immediate = push.instruction.immediate_bytes();
+
+        if !push.instruction.is_push() || !jumps || immediate.len() > size_of::<usize>() {
+            return None;
+        }
+
+        let target = immediate
+            .iter()
+            .fold(0, |target, &byte| (target << 8) | usize::from(byte));
+        jumpdests.contains(&target).then_some(target)
+    }
+}
+
+impl Syntax for Huff {
+    fn write_named_instruction<W: Write>(
+        &self,
+        w: &mut W,
+        positioned: &PositionedInstruction,
+        names: MnemonicNames,
+    ) -> fmt::Result {
+        let instruction = &positioned.instruction;
+        let immediate = instruction.immediate_bytes();
+
+        match instruction.opcode() {
+            OpCode::Unknown(byte) => write!(w, "// unknown opcode {byte:#04x}"),
+            OpCode::Known(_) if instruction.is_push() && !immediate.is_empty() => {
+                write_hex(w, immediate)
+            }
+            OpCode::Known(mnemonic) => {
+                write_lowercase(w, names(mnemonic))?;
+
+                if !immediate.is_empty() {
+                    w.write_char(' ')?;
+                    write_hex(w, immediate)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    fn write_named_assembly<W: Write>(
+        &self,
+        w: &mut W,
+        assembly: &Assembly,
+        names: MnemonicNames,
+    ) -> fmt::Result {
+        let jumpdests: BTreeSet<_> = assembly
+            .iter()
+            .filter(|positioned| positioned.instruction.opcode() == Mnemonic::JUMPDEST)
+            .map(|positioned| positioned.position)
+            .collect();
+
+        let mut instructions = assembly.iter().peekable();
+        while let Some(positioned) = instructions.next() {
+            if positioned.instruction.opcode() == Mnemonic::JUMPDEST {
+                writeln!(w, "jumpdest_{:x}:", positioned.position)?;
+                continue;
+            }
+
+            w.write_str("    ")?;
+            match Self::jump_target(positioned, instructions.peek().copied(), &jumpdests) {
+                Some(target) => write!(w, "jumpdest_{target:x}")?,
+                None => self.write_named_instruction(w, positioned, names)?,
+            }
+            w.write_char('\n')?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::tests::assembly;
+    use alloc::string::ToString;
+
+    #[test]
+    fn huff_labels() {
+        assert_eq!(
+            assembly().display_with(&Huff).to_string(),
+            "    0x80
+    jumpdest_8
+    jumpi
+    push0
+    stop
+jumpdest_8:
+    keccak256
+    // unknown opcode 0x0c
+    prevrandao
+"
+        );
+    }
+}
@@ -0,0 +1,396 @@
+//! Assembly syntaxes of different tools.
+//!
+//! Every [`Syntax`] can print an [`Assembly`], the ones that implement [`ParseSyntax`] can parse
+//! their output back into an [`Assembly`].
+//!
+//! ```
+//! # use oculars_dasm::{disassembler::Disassembler, syntax::{Etk, Geth, ParseSyntax}};
+//! # use upgrades::forks::shanghai::Shanghai;
+//! let assembly = Disassembler::<Shanghai>::new().disassemble_hex("0x620a0b0c5f").unwrap();
+//!
+//! assert_eq!(
+//!     assembly.display_with(&Geth).to_string(),
+//!     "00000: PUSH3 0x0a0b0c\n00004: PUSH0\n"
+//! );
+//! assert_eq!(assembly.display_with(&Etk).to_string(), "push3 0x0a0b0c\npush0\n");
+//! assert_eq!(Etk.parse("push3 0x0a0b0c\npush0").unwrap(), assembly);
+//! ```
+
+mod etk;
+mod geth;
+mod huff;
+mod pyevmasm;
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use asm2::{AssemblyInstruction, DecodeError, Instruction, Mnemonic, OpInfo, ParseMnemonicError};
+use core::fmt::{self, Display, Write};
+use thiserror::Error;
+use upgrades::execution::ExecutionUpgrade;
+
+use crate::assembly::{Assembly, PositionedInstruction};
+
+pub use etk::Etk;
+pub use geth::Geth;
+pub use huff::Huff;
+pub use pyevmasm::Pyevmasm;
+
+/// Returns the name of a mnemonic, like [`Mnemonic::name`] or the name it had in an execution
+/// upgrade ([`ExecutionUpgrade::mnemonic_name`]).
+pub type MnemonicNames = fn(Mnemonic) -> &'static str;
+
+/// Returns the current name of a mnemonic.
+fn current_name(mnemonic: Mnemonic) -> &'static str {
+    mnemonic.name()
+}
+
+/// A textual syntax for EVM assembly.
+pub trait Syntax {
+    /// Writes a single instruction, without a trailing newline, naming its mnemonic with `names`.
+    ///
+    /// # Errors
+    /// Returns an error if writing to `w` fails.
+    fn write_named_instruction<W: Write>(
+        &self,
+        w: &mut W,
+        instruction: &PositionedInstruction,
+        names: MnemonicNames,
+    ) -> fmt::Result;
+
+    /// Writes a single instruction with the current name of its mnemonic, without a trailing
+    /// newline.
+    ///
+    /// # Errors
+    /// Returns an error if writing to `w` fails.
+    fn write_instruction<W: Write>(
+        &self,
+        w: &mut W,
+        instruction: &PositionedInstruction,
+    ) -> fmt::Result {
+        self.write_named_instruction(w, instruction, current_name)
+    }
+
+    /// Writes an assembly, one instruction per line, naming mnemonics with `names`.
+    ///
+    /// # Errors
+    /// Returns an error if writing to `w` fails.
+    fn write_named_assembly<W: Write>(
+        &self,
+        w: &mut W,
+        assembly: &Assembly,
+        names: MnemonicNames,
+    ) -> fmt::Result {
+        for instruction in assembly {
+            self.write_named_instruction(w, instruction, names)?;
+            w.write_char('\n')?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes an assembly with the current names of mnemonics, one instruction per line.
+    ///
+    /// # Errors
+    /// Returns an error if writing to `w` fails.
+    fn write_assembly<W: Write>(&self, w: &mut W, assembly: &Assembly) -> fmt::Result {
+        self.write_named_assembly(w, assembly, current_name)
+    }
+}
+
+/// A [`Syntax`] that can be parsed back into an [`Assembly`].
+pub trait ParseSyntax: Syntax {
+    /// Parses a single, non-empty line holding one instruction.
+    ///
+    /// # Errors
+    /// Returns an error if the line is not a valid instruction in this syntax.
+    fn parse_instruction(&self, line: &str) -> Result<Instruction, ParseErrorKind>;
+
+    /// Parses an assembly with one instruction per line, skipping empty lines.
+    ///
+    /// Positions of the instructions are computed from their sizes.
+    ///
+    /// # Errors
+    /// Returns an error pointing at the first line that is not a valid instruction.
+    fn parse(&self, source: &str) -> Result<Assembly, ParseError> {
+        let mut instructions = Vec::new();
+        let mut position = 0;
+
+        for (index, line) in source.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let instruction = self.parse_instruction(line).map_err(|kind| ParseError {
+                line: index + 1,
+                kind,
+            })?;
+            let size = instruction.size();
+
+            instructions.push(PositionedInstruction {
+                position,
+                instruction,
+            });
+            position += size;
+        }
+
+        Ok(Assembly::from(instructions))
+    }
+}
+
+/// An error that occurs while parsing assembly.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("line {line}: {kind}")]
+pub struct ParseError {
+    /// The (1-based) line on which the error occurred.
+    pub line: usize,
+    /// What went wrong.
+    pub kind: ParseErrorKind,
+}
+
+/// The reason a line of assembly could not be parsed.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The line does not contain a mnemonic.
+    #[error("expected a mnemonic")]
+    MissingMnemonic,
+
+    /// The mnemonic is not known.
+    #[error(transparent)]
+    Mnemonic(#[from] ParseMnemonicError),
+
+    /// The immediate value is not a valid `0x` prefixed hex value.
+    #[error("invalid immediate value `{0}`")]
+    Immediate(String),
+
+    /// The immediate value does not have the size the instruction expects.
+    #[error("`{mnemonic}` expects {expected} immediate bytes, found {found}")]
+    ImmediateSize {
+        /// Mnemonic of the instruction.
+        mnemonic: Mnemonic,
+        /// Expected size of the immediate value.
+        expected: usize,
+        /// Size of the parsed immediate value.
+        found: usize,
+    },
+
+    /// The line continues after the instruction.
+    #[error("unexpected `{0}`")]
+    UnexpectedToken(String),
+}
+
+/// Displays an [`Assembly`] in a specific [`Syntax`], see [`Assembly::display_with`].
+pub struct SyntaxDisplay<'a, S> {
+    /// Syntax to display the assembly in.
+    syntax: &'a S,
+    /// The displayed assembly.
+    assembly: &'a Assembly,
+    /// Names of the mnemonics.
+    names: MnemonicNames,
+}
+
+impl<S: Syntax> Display for SyntaxDisplay<'_, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.syntax
+            .write_named_assembly(f, self.assembly, self.names)
+    }
+}
+
+impl Assembly {
+    /// Returns a value that displays this assembly in `syntax`.
+    #[must_use]
+    pub fn display_with<'a, S: Syntax>(&'a self, syntax: &'a S) -> SyntaxDisplay<'a, S> {
+        SyntaxDisplay {
+            syntax,
+            assembly: self,
+            names: current_name,
+        }
+    }
+
+    /// Returns a value that displays this assembly in `syntax`, naming mnemonics like the
+    /// execution upgrade `E` did.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::{disassembler::Disassembler, syntax::Geth};
+    /// # use upgrades::forks::{byzantium::Byzantium, paris::Paris};
+    /// let assembly = Disassembler::<Byzantium>::new().disassemble_hex("0x4420").unwrap();
+    ///
+    /// assert_eq!(
+    ///     assembly.display_for::<Byzantium, _>(&Geth).to_string(),
+    ///     "00000: DIFFICULTY\n00001: SHA3\n"
+    /// );
+    /// assert_eq!(
+    ///     assembly.display_for::<Paris, _>(&Geth).to_string(),
+    ///     "00000: PREVRANDAO\n00001: KECCAK256\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn display_for<'a, E: ExecutionUpgrade, S: Syntax>(
+        &'a self,
+        syntax: &'a S,
+    ) -> SyntaxDisplay<'a, S> {
+        SyntaxDisplay {
+            syntax,
+            assembly: self,
+            names: E::mnemonic_name,
+        }
+    }
+}
+
+/// Writes `name` in lowercase.
+fn write_lowercase<W: Write>(w: &mut W, name: &str) -> fmt::Result {
+    name.chars()
+        .try_for_each(|c| w.write_char(c.to_ascii_lowercase()))
+}
+
+/// Writes `bytes` as a `0x` prefixed hex value.
+fn write_hex<W: Write>(w: &mut W, bytes: &[u8]) -> fmt::Result {
+    w.write_str("0x")?;
+    bytes.iter().try_for_each(|byte| write!(w, "{byte:02x}"))
+}
+
+/// Returns the size of the immediate value of a `PUSHx` mnemonic.
+fn push_size(mnemonic: Mnemonic) -> Option<usize> {
+    let info = OpInfo::of(mnemonic.into_byte());
+
+    info.immediate_size()
+        .filter(|_| info.is_push())
+        .map(usize::from)
+}
+
+/// Parses a `0x` prefixed hex value, left padding it with zeroes to `size` bytes.
+fn parse_hex(value: &str, size: usize) -> Result<Vec<u8>, ParseErrorKind> {
+    let invalid = || ParseErrorKind::Immediate(value.to_string());
+
+    let digits = value
+        .strip_prefix("0x")
+        .filter(|digits| !digits.is_empty())
+        .ok_or_else(invalid)?;
+
+    let mut padded = String::from("0").repeat(size.saturating_mul(2).saturating_sub(digits.len()));
+    if (padded.len() + digits.len()) % 2 == 1 {
+        padded.push('0');
+    }
+    padded.push_str(digits);
+
+    hex::decode(padded).map_err(|_| invalid())
+}
+
+/// Builds the instruction with a specific mnemonic and immediate value.
+fn build_instruction(mnemonic: Mnemonic, immediate: &[u8]) -> Result<Instruction, ParseErrorKind> {
+    let mut bytes = vec![mnemonic.into_byte()];
+    bytes.extend_from_slice(immediate);
+
+    let expected = match Instruction::decode(&bytes) {
+        Ok((instruction, size)) if size == bytes.len() => return Ok(instruction),
+        Ok((instruction, _)) => instruction.immediate_size(),
+        Err(DecodeError::TruncatedImmediate { expected, .. }) => expected,
+        Err(DecodeError::Empty) => unreachable!("the bytes always hold an opcode"),
+    };
+
+    Err(ParseErrorKind::ImmediateSize {
+        mnemonic,
+        expected,
+        found: immediate.len(),
+    })
+}
+
+/// Parses the common `MNEMONIC [IMMEDIATE]` form of an instruction.
+///
+/// `pad_push` left pads the immediate values of `PUSHx` instructions to their full size.
+fn parse_mnemonic_line(line: &str, pad_push: bool) -> Result<Instruction, ParseErrorKind> {
+    let mut tokens = line.split_whitespace();
+    let mnemonic: Mnemonic = tokens
+        .next()
+        .ok_or(ParseErrorKind::MissingMnemonic)?
+        .parse()?;
+
+    let immediate = match tokens.next() {
+        Some(value) => {
+            let size = push_size(mnemonic).filter(|_| pad_push).unwrap_or_default();
+            parse_hex(value, size)?
+        }
+        None => Vec::new(),
+    };
+
+    if let Some(token) = tokens.next() {
+        return Err(ParseErrorKind::UnexpectedToken(token.to_string()));
+    }
+
+    build_instruction(mnemonic, &immediate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bytecode::Bytecode, disassembler::Disassembler};
+    use alloc::string::ToString;
+    use upgrades::forks::shanghai::Shanghai;
+
+    /// Returns an assembly that covers pushes, jumps and unknown opcodes.
+    pub(super) fn assembly() -> Assembly {
+        Disassembler::<Shanghai>::new()
+            .disassemble(&Bytecode::from(vec![
+                0x60, 0x80, 0x61, 0x00, 0x08, 0x57, 0x5F, 0x00, 0x5B, 0x20, 0x0C, 0x44,
+            ]))
+            .unwrap()
+    }
+
+    #[test]
+    fn hex_parsing() {
+        assert_eq!(parse_hex("0x0a0b0c", 0), Ok(vec![0x0A, 0x0B, 0x0C]));
+        assert_eq!(parse_hex("0xa0b0c", 0), Ok(vec![0x0A, 0x0B, 0x0C]));
+        assert_eq!(parse_hex("0x1", 4), Ok(vec![0, 0, 0, 1]));
+        assert_eq!(
+            parse_hex("0x", 0),
+            Err(ParseErrorKind::Immediate("0x".to_string()))
+        );
+        assert_eq!(
+            parse_hex("80", 1),
+            Err(ParseErrorKind::Immediate("80".to_string()))
+        );
+        assert_eq!(
+            parse_hex("0xzz", 1),
+            Err(ParseErrorKind::Immediate("0xzz".to_string()))
+        );
+    }
+
+    #[test]
+    fn instruction_building() {
+        assert_eq!(
+            build_instruction(Mnemonic::PUSH2, &[0x01]),
+            Err(ParseErrorKind::ImmediateSize {
+                mnemonic: Mnemonic::PUSH2,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            build_instruction(Mnemonic::GAS, &[0x01])
+                .unwrap_err()
+                .to_string(),
+            "`GAS` expects 0 immediate bytes, found 1"
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Geth.parse("00000: PUSH1 0x80\n\n00002: PAY_VITALIK")
+                .unwrap_err()
+                .to_string(),
+            "line 3: unknown mnemonic `PAY_VITALIK`"
+        );
+        assert_eq!(
+            Etk.parse("push1 0x80 0x40").unwrap_err(),
+            ParseError {
+                line: 1,
+                kind: ParseErrorKind::UnexpectedToken("0x40".to_string())
+            }
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Syntax of pyevmasm.
+
+use asm2::{AssemblyInstruction, Instruction, Mnemonic, OpCode};
+use core::fmt::{self, Write};
+
+use super::{MnemonicNames, ParseErrorKind, ParseSyntax, Syntax, parse_mnemonic_line};
+use crate::assembly::PositionedInstruction;
+
+/// Syntax of the pyevmasm assembler and disassembler.
+///
+/// Mnemonics use pyevmasm's pre-merge names (`SHA3`, `DIFFICULTY`) and immediate values are
+/// written as numbers without leading zeroes:
+///
+/// ```text
+/// PUSH2 0x8
+/// SHA3
+/// ```
+///
+/// Like in pyevmasm, unknown opcodes are written as `INVALID`, so they are parsed back as the
+/// `INVALID` (`0xFE`) instruction.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Pyevmasm;
+
+impl Pyevmasm {
+    /// Returns pyevmasm's name for a mnemonic, which keeps the pre-merge names even if `names`
+    /// does not.
+    fn name(mnemonic: Mnemonic, names: MnemonicNames) -> &'static str {
+        match mnemonic {
+            Mnemonic::KECCAK256 => "SHA3",
+            Mnemonic::PREVRANDAO => "DIFFICULTY",
+            _ => names(mnemonic),
+        }
+    }
+}
+
+impl Syntax for Pyevmasm {
+    fn write_named_instruction<W: Write>(
+        &self,
+        w: &mut W,
+        positioned: &PositionedInstruction,
+        names: MnemonicNames,
+    ) -> fmt::Result {
+        let instruction = &positioned.instruction;
+
+        let OpCode::Known(mnemonic) = instruction.opcode() else {
+            return w.write_str(Self::name(Mnemonic::INVALID, names));
+        };
+        w.write_str(Self::name(mnemonic, names))?;
+
+        let immediate = instruction.immediate_bytes();
+        if immediate.is_empty() {
+            return Ok(());
+        }
+
+        let significant = immediate
+            .iter()
+            .position(|&byte| byte != 0)
+            .map_or(&immediate[immediate.len() - 1..], |start| {
+                &immediate[start..]
+            });
+
+        write!(w, " {:#x}", significant[0])?;
+        significant[1..]
+            .iter()
+            .try_for_each(|byte| write!(w, "{byte:02x}"))
+    }
+}
+
+impl ParseSyntax for Pyevmasm {
+    fn parse_instruction(&self, line: &str) -> Result<Instruction, ParseErrorKind> {
+        parse_mnemonic_line(line, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assembly::Assembly, syntax::tests::assembly};
+    use alloc::{string::ToString, vec::Vec};
+    use asm2::instruction::{Invalid, Push, Unknown};
+
+    /// `assembly()` in pyevmasm's syntax.
+    const PYEVMASM: &str = "\
+PUSH1 0x80
+PUSH2 0x8
+JUMPI
+PUSH0
+STOP
+JUMPDEST
+SHA3
+INVALID
+DIFFICULTY
+";
+
+    #[test]
+    fn pyevmasm_roundtrip() {
+        assert_eq!(assembly().display_with(&Pyevmasm).to_string(), PYEVMASM);
+
+        // Unknown opcodes are parsed back as `INVALID`.
+        let expected: Vec<_> = assembly()
+            .iter()
+            .cloned()
+            .map(|mut positioned| {
+                if positioned.instruction == Instruction::Unknown(Unknown::new(0x0C)) {
+                    positioned.instruction = Instruction::Invalid(Invalid);
+                }
+                positioned
+            })
+            .collect();
+        assert_eq!(Pyevmasm.parse(PYEVMASM).unwrap(), Assembly::from(expected));
+    }
+
+    #[test]
+    fn pyevmasm_immediates() {
+        assert_eq!(
+            Pyevmasm.parse_instruction("PUSH4 0x0"),
+            Ok(Instruction::Push4(Push::new([0; 4])))
+        );
+        assert_eq!(
+            Pyevmasm.parse_instruction("push3 0xa0b0c"),
+            Ok(Instruction::Push3(Push::new([0x0A, 0x0B, 0x0C])))
+        );
+        assert_eq!(
+            Pyevmasm.parse_instruction("PUSH1 0x100"),
+            Err(ParseErrorKind::ImmediateSize {
+                mnemonic: Mnemonic::PUSH1,
+                expected: 1,
+                found: 2
+            })
+        );
+    }
+}
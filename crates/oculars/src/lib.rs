@@ -1,4 +1,6 @@
 //! EVM analysis toolkit.
 
+pub use analysis;
 pub use asm;
+pub use asm2;
 pub use dasm;
@@ -1,7 +1,7 @@
 //! Ethereum Improvement Proposals.
 
-use asm::instruction::InstructionMeta;
-use std::any::TypeId;
+use asm2::{KnownInstruction, Mnemonic};
+use core::any::TypeId;
 
 /// An Ethereum Improvement Proposal.
 pub trait Eip {
@@ -14,6 +14,17 @@ pub trait Eip {
     /// ```
     const NUMBER: u32;
 
+    /// Mnemonics of the instructions this EIP introduces.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::{eips::{eip2::Eip2, eip145::Eip145}, eip::Eip};
+    /// # use asm2::Mnemonic;
+    /// assert!(Eip2::INSTRUCTIONS.is_empty());
+    /// assert_eq!(Eip145::INSTRUCTIONS, &[Mnemonic::SHL, Mnemonic::SHR, Mnemonic::SAR]);
+    /// ```
+    const INSTRUCTIONS: &'static [Mnemonic] = &[];
+
     /// Return the EIP's number.
     ///
     /// # Example
@@ -32,32 +43,29 @@ pub trait Eip {
     /// # Example
     /// ```
     /// # use oculars_upgrades::{execution::ExecutionUpgrade, eip::Eip, eips::{eip2::Eip2, eip7::Eip7}};
-    /// # use asm::instruction::DelegateCall;
+    /// # use asm2::instruction::DelegateCall;
     /// assert!(!Eip2::introduces_instruction::<DelegateCall>());
     /// assert!(Eip7::introduces_instruction::<DelegateCall>());
     /// ```
     #[must_use]
     #[inline]
-    fn introduces_instruction<I>() -> bool
-    where
-        I: InstructionMeta,
-        Self: IntroducesInstruction<I>,
-    {
-        Self::eip_introduces_instruction()
+    fn introduces_instruction<I: KnownInstruction>() -> bool {
+        Self::introduces_mnemonic(I::MNEMONIC)
     }
-}
 
-/// Trait that indicates whether EIP introduces an instruction.
-pub trait IntroducesInstruction<I: InstructionMeta> {
-    /// Returns whether this EIP introduced an instruction.
+    /// Returns whether this EIP introduced the instruction with a specific mnemonic.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::{eip::Eip, eips::eip7::Eip7};
+    /// # use asm2::Mnemonic;
+    /// assert!(Eip7::introduces_mnemonic(Mnemonic::DELEGATECALL));
+    /// assert!(!Eip7::introduces_mnemonic(Mnemonic::CALL));
+    /// ```
     #[must_use]
-    fn eip_introduces_instruction() -> bool;
-}
-
-// Make every [`Eip`] introduce no instructions by default.
-impl<I: InstructionMeta, E: Eip> IntroducesInstruction<I> for E {
-    default fn eip_introduces_instruction() -> bool {
-        false
+    #[inline]
+    fn introduces_mnemonic(mnemonic: Mnemonic) -> bool {
+        Self::INSTRUCTIONS.contains(&mnemonic)
     }
 }
 
@@ -66,8 +74,19 @@ pub trait EipSet {
     /// Returns whether this set contains a specific [`Eip`].
     fn includes_eip<E: Eip + 'static>() -> bool;
 
+    /// Returns whether this set supports the instruction with a specific mnemonic.
+    fn supports_mnemonic(mnemonic: Mnemonic) -> bool;
+
+    /// Returns the number of the [`Eip`] in this set that introduced the instruction with a
+    /// specific mnemonic.
+    fn introducing_eip(mnemonic: Mnemonic) -> Option<u32>;
+
     /// Returns whether this set supports an instruction.
-    fn supports_instruction<I: InstructionMeta>() -> bool;
+    #[must_use]
+    #[inline]
+    fn supports_instruction<I: KnownInstruction>() -> bool {
+        Self::supports_mnemonic(I::MNEMONIC)
+    }
 }
 
 // An empty tuple is considered an [`EipSet`].
@@ -76,9 +95,13 @@ impl EipSet for () {
         false
     }
 
-    fn supports_instruction<I: InstructionMeta>() -> bool {
+    fn supports_mnemonic(_: Mnemonic) -> bool {
         false
     }
+
+    fn introducing_eip(_: Mnemonic) -> Option<u32> {
+        None
+    }
 }
 
 // A tuple of an [`Eip`] and a [`EipSet`] is considered an [`EipSet`].
@@ -87,31 +110,16 @@ impl<A: Eip + 'static, B: EipSet> EipSet for (A, B) {
         TypeId::of::<A>() == TypeId::of::<E>() || B::includes_eip::<E>()
     }
 
-    fn supports_instruction<I: InstructionMeta>() -> bool {
-        <A as IntroducesInstruction<I>>::eip_introduces_instruction()
-            || B::supports_instruction::<I>()
-    }
-}
-
-// Implement [`IntroducesInstruction`] for [`EipSet`]s.
-impl<A, B, I> IntroducesInstruction<I> for (A, B)
-where
-    A: IntroducesInstruction<I>,
-    B: IntroducesInstruction<I>,
-    I: InstructionMeta,
-{
-    fn eip_introduces_instruction() -> bool {
-        A::eip_introduces_instruction() || B::eip_introduces_instruction()
+    fn supports_mnemonic(mnemonic: Mnemonic) -> bool {
+        A::introduces_mnemonic(mnemonic) || B::supports_mnemonic(mnemonic)
     }
-}
 
-// An empty [`EipSet`] introduces no instructions.
-impl<I> IntroducesInstruction<I> for ()
-where
-    I: InstructionMeta,
-{
-    fn eip_introduces_instruction() -> bool {
-        false
+    fn introducing_eip(mnemonic: Mnemonic) -> Option<u32> {
+        if A::introduces_mnemonic(mnemonic) {
+            Some(A::NUMBER)
+        } else {
+            B::introducing_eip(mnemonic)
+        }
     }
 }
 
@@ -134,14 +142,12 @@ pub mod macros {
         };
     }
 
-    /// Specifies that this EIP introduces a new instruction.
+    /// Specifies the instructions an EIP introduces, used inside of the EIP's [`super::Eip`]
+    /// implementation.
     macro_rules! introduces_instructions {
-        ($eip: ident, $($instruction: path),+) => {
-            $(impl $crate::eip::IntroducesInstruction<$instruction> for $eip {
-                fn eip_introduces_instruction() -> bool {
-                    true
-                }
-            })+
+        ($($instruction: ty),+ $(,)?) => {
+            const INSTRUCTIONS: &'static [asm2::Mnemonic] =
+                &[$(<$instruction as asm2::KnownInstruction>::MNEMONIC),+];
         };
     }
 
@@ -100,7 +100,7 @@
 //!
 //! Vitalik Buterin (@vbuterin), "EIP-1014: Skinny CREATE2," Ethereum Improvement Proposals, no. 1014, April 2018. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-1014>.
 
-use asm::instruction::Create2;
+use asm2::instruction::Create2;
 
 use crate::eip::{Eip, macros::introduces_instructions};
 
@@ -109,6 +109,6 @@ pub struct Eip1014;
 
 impl Eip for Eip1014 {
     const NUMBER: u32 = 1014;
-}
 
-introduces_instructions!(Eip1014, Create2);
+    introduces_instructions!(Create2);
+}
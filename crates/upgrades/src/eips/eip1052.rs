@@ -62,7 +62,7 @@
 //!
 //! Nick Johnson <arachnid@notdot.net>, Paweł Bylica <pawel@ethereum.org>, "EIP-1052: EXTCODEHASH opcode," Ethereum Improvement Proposals, no. 1052, May 2018. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-1052>.
 
-use asm::instruction::ExtCodeHash;
+use asm2::instruction::ExtCodeHash;
 
 use crate::eip::{Eip, macros::introduces_instructions};
 
@@ -71,6 +71,6 @@ pub struct Eip1052;
 
 impl Eip for Eip1052 {
     const NUMBER: u32 = 1052;
-}
 
-introduces_instructions!(Eip1052, ExtCodeHash);
+    introduces_instructions!(ExtCodeHash);
+}
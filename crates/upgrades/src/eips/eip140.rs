@@ -47,7 +47,7 @@
 //!
 //! Alex Beregszaszi (@axic), Nikolai Mushegian <nikolai@nexusdev.us>, "EIP-140: REVERT instruction," Ethereum Improvement Proposals, no. 140, February 2017. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-140>.
 
-use asm::instruction::Revert;
+use asm2::instruction::Revert;
 
 use crate::eip::{Eip, macros::introduces_instructions};
 
@@ -56,6 +56,6 @@ pub struct Eip140;
 
 impl Eip for Eip140 {
     const NUMBER: u32 = 140;
-}
 
-introduces_instructions!(Eip140, Revert);
+    introduces_instructions!(Revert);
+}
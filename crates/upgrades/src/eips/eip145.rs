@@ -381,7 +381,7 @@
 //!
 //! Alex Beregszaszi (@axic), Paweł Bylica (@chfast), "EIP-145: Bitwise shifting instructions in EVM," Ethereum Improvement Proposals, no. 145, February 2017. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-145>.
 
-use asm::instruction::{Sar, Shl, Shr};
+use asm2::instruction::{Sar, Shl, Shr};
 
 use crate::eip::{Eip, macros::introduces_instructions};
 
@@ -390,6 +390,6 @@ pub struct Eip145;
 
 impl Eip for Eip145 {
     const NUMBER: u32 = 145;
-}
 
-introduces_instructions!(Eip145, Shl, Shr, Sar);
+    introduces_instructions!(Shl, Shr, Sar);
+}
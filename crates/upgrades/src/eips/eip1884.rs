@@ -99,7 +99,7 @@
 //!
 //! Martin Holst Swende (@holiman), "EIP-1884: Repricing for trie-size-dependent opcodes," Ethereum Improvement Proposals, no. 1884, March 2019. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-1884>.
 
-use asm::instruction::SelfBalance;
+use asm2::instruction::SelfBalance;
 
 use crate::eip::{Eip, macros::introduces_instructions};
 
@@ -107,7 +107,7 @@ use crate::eip::{Eip, macros::introduces_instructions};
 pub struct Eip1884;
 
 impl Eip for Eip1884 {
-    const NUMBER: u32 = 1844;
-}
+    const NUMBER: u32 = 1884;
 
-introduces_instructions!(Eip1884, SelfBalance);
+    introduces_instructions!(SelfBalance);
+}
@@ -49,7 +49,7 @@
 //!
 //! Christian Reitwiessner <chris@ethereum.org>, "EIP-211: New opcodes: RETURNDATASIZE and RETURNDATACOPY," Ethereum Improvement Proposals, no. 211, February 2017. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-211>.
 
-use asm::instruction::{ReturnDataCopy, ReturnDataSize};
+use asm2::instruction::{ReturnDataCopy, ReturnDataSize};
 
 use crate::eip::{Eip, macros::introduces_instructions};
 
@@ -58,6 +58,6 @@ pub struct Eip211;
 
 impl Eip for Eip211 {
     const NUMBER: u32 = 211;
-}
 
-introduces_instructions!(Eip211, ReturnDataSize, ReturnDataCopy);
+    introduces_instructions!(ReturnDataSize, ReturnDataCopy);
+}
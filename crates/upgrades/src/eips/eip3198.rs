@@ -52,7 +52,7 @@
 //!
 //! Abdelhamid Bakhta (@abdelhamidbakhta), Vitalik Buterin (@vbuterin), "EIP-3198: BASEFEE opcode," Ethereum Improvement Proposals, no. 3198, January 2021. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-3198>.
 
-use asm::instruction::BaseFee;
+use asm2::instruction::BaseFee;
 
 use crate::eip::{Eip, macros::introduces_instructions};
 
@@ -61,6 +61,6 @@ pub struct Eip3198;
 
 impl Eip for Eip3198 {
     const NUMBER: u32 = 3198;
-}
 
-introduces_instructions!(Eip3198, BaseFee);
+    introduces_instructions!(BaseFee);
+}
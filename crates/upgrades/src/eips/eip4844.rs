@@ -425,7 +425,7 @@
 //!
 //! Vitalik Buterin (@vbuterin), Dankrad Feist (@dankrad), Diederik Loerakker (@protolambda), George Kadianakis (@asn-d6), Matt Garnett (@lightclient), Mofi Taiwo (@Inphi), Ansgar Dietrichs (@adietrichs), "EIP-4844: Shard Blob Transactions," Ethereum Improvement Proposals, no. 4844, February 2022. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-4844>.
 
-use asm::instruction::BlobHash;
+use asm2::instruction::BlobHash;
 
 use crate::eip::{Eip, macros::introduces_instructions};
 
@@ -434,6 +434,6 @@ pub struct Eip4844;
 
 impl Eip for Eip4844 {
     const NUMBER: u32 = 4844;
-}
 
-introduces_instructions!(Eip4844, BlobHash);
+    introduces_instructions!(BlobHash);
+}
@@ -171,7 +171,7 @@
 //!
 //! Alex Beregszaszi (@axic), Paul Dworzanski (@poemm), Jared Wasinger (@jwasinger), Casey Detrio (@cdetrio), Pawel Bylica (@chfast), Charles Cooper (@charles-cooper), "EIP-5656: MCOPY - Memory copying instruction," Ethereum Improvement Proposals, no. 5656, February 2021. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-5656>.
 
-use asm::instruction::MCopy;
+use asm2::instruction::MCopy;
 
 use crate::eip::{Eip, macros::introduces_instructions};
 
@@ -180,6 +180,6 @@ pub struct Eip5656;
 
 impl Eip for Eip5656 {
     const NUMBER: u32 = 5656;
-}
 
-introduces_instructions!(Eip5656, MCopy);
+    introduces_instructions!(MCopy);
+}
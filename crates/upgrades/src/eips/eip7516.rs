@@ -59,7 +59,7 @@
 //!
 //! Carl Beekhuizen (@carlbeek), "EIP-7516: BLOBBASEFEE instruction," Ethereum Improvement Proposals, no. 7516, September 2023. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-7516>.
 
-use asm::instruction::BlobBaseFee;
+use asm2::instruction::BlobBaseFee;
 
 use crate::eip::{Eip, macros::introduces_instructions};
 
@@ -68,6 +68,6 @@ pub struct Eip7516;
 
 impl Eip for Eip7516 {
     const NUMBER: u32 = 7516;
-}
 
-introduces_instructions!(Eip7516, BlobBaseFee);
+    introduces_instructions!(BlobBaseFee);
+}
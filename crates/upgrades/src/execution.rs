@@ -1,8 +1,12 @@
 //! Ethereum Execution layer upgrades.
 
-use asm::instruction::InstructionMeta;
+use asm2::{KnownInstruction, Mnemonic, OpCode};
 
-use crate::eip::{Eip, EipSet};
+use crate::{
+    eip::{Eip, EipSet},
+    eips::{eip7::Eip7, eip3198::Eip3198, eip4399::Eip4399},
+    gas::GasSchedule,
+};
 
 /// Ethereum execution layer upgrade.
 pub trait ExecutionUpgrade {
@@ -14,16 +18,73 @@ pub trait ExecutionUpgrade {
     /// # Example
     /// ```
     /// # use oculars_upgrades::{execution::ExecutionUpgrade, forks::{homestead::Homestead, frontier::Frontier}};
-    /// # use asm::instruction::DelegateCall;
+    /// # use asm2::instruction::DelegateCall;
     /// assert!(!Frontier::supports_instruction::<DelegateCall>());
     /// assert!(Homestead::supports_instruction::<DelegateCall>());
     /// ```
     #[must_use]
     #[inline]
-    fn supports_instruction<I: InstructionMeta>() -> bool {
+    fn supports_instruction<I: KnownInstruction>() -> bool {
         Self::EipSet::supports_instruction::<I>()
     }
 
+    /// Returns whether this execution upgrade supports the instruction with a specific mnemonic.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::{execution::ExecutionUpgrade, forks::{shanghai::Shanghai, paris::Paris}};
+    /// # use asm2::Mnemonic;
+    /// assert!(!Paris::supports_mnemonic(Mnemonic::PUSH0));
+    /// assert!(Shanghai::supports_mnemonic(Mnemonic::PUSH0));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn supports_mnemonic(mnemonic: Mnemonic) -> bool {
+        Self::EipSet::supports_mnemonic(mnemonic)
+    }
+
+    /// Returns whether this execution upgrade supports the instruction with a specific opcode.
+    ///
+    /// Unknown opcodes are always supported, as [`Instruction::Unknown`](asm2::Instruction::Unknown)
+    /// represents them in every upgrade.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::{execution::ExecutionUpgrade, forks::{shanghai::Shanghai, paris::Paris}};
+    /// # use asm2::{Mnemonic, OpCode};
+    /// assert!(!Paris::supports_opcode(OpCode::Known(Mnemonic::PUSH0)));
+    /// assert!(Shanghai::supports_opcode(OpCode::Known(Mnemonic::PUSH0)));
+    /// assert!(Paris::supports_opcode(OpCode::Unknown(0x0C)));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn supports_opcode(opcode: OpCode) -> bool {
+        match opcode {
+            OpCode::Known(mnemonic) => Self::supports_mnemonic(mnemonic),
+            OpCode::Unknown(_) => true,
+        }
+    }
+
+    /// Returns the number of the EIP in this execution upgrade that introduced the instruction
+    /// with a specific mnemonic.
+    ///
+    /// Instructions that have been available since genesis are attributed to
+    /// [`Genesis`](crate::eips::genesis::Genesis), whose number is `0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::{execution::ExecutionUpgrade, forks::{byzantium::Byzantium, constantinople::Constantinople}};
+    /// # use asm2::Mnemonic;
+    /// assert_eq!(Constantinople::introducing_eip(Mnemonic::SHL), Some(145));
+    /// assert_eq!(Constantinople::introducing_eip(Mnemonic::ADD), Some(0));
+    /// assert_eq!(Byzantium::introducing_eip(Mnemonic::SHL), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn introducing_eip(mnemonic: Mnemonic) -> Option<u32> {
+        Self::EipSet::introducing_eip(mnemonic)
+    }
+
     /// Returns whether an EIP is included in this execution upgrade.
     ///
     /// # Example
@@ -37,4 +98,56 @@ pub trait ExecutionUpgrade {
     fn includes<E: Eip + 'static>() -> bool {
         Self::EipSet::includes_eip::<E>()
     }
+
+    /// Returns the name a mnemonic had in this execution upgrade.
+    ///
+    /// `SUICIDE` was renamed to `SELFDESTRUCT` (EIP-6) with Homestead, `SHA3` to `KECCAK256` by
+    /// London and `DIFFICULTY` to `PREVRANDAO` (EIP-4399) with Paris. The former names are the
+    /// [aliases](Mnemonic::aliases) of a mnemonic, which are all accepted when parsing it.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::{execution::ExecutionUpgrade, forks::{byzantium::Byzantium, frontier::Frontier, paris::Paris}};
+    /// # use asm2::Mnemonic;
+    /// assert_eq!(Byzantium::mnemonic_name(Mnemonic::PREVRANDAO), "DIFFICULTY");
+    /// assert_eq!(Paris::mnemonic_name(Mnemonic::PREVRANDAO), "PREVRANDAO");
+    /// assert_eq!(Byzantium::mnemonic_name(Mnemonic::KECCAK256), "SHA3");
+    /// assert_eq!(Frontier::mnemonic_name(Mnemonic::SELFDESTRUCT), "SUICIDE");
+    /// assert_eq!(Byzantium::mnemonic_name(Mnemonic::GAS), "GAS");
+    /// ```
+    #[must_use]
+    #[inline]
+    fn mnemonic_name(mnemonic: Mnemonic) -> &'static str {
+        let renamed = match mnemonic {
+            Mnemonic::SELFDESTRUCT => Self::includes::<Eip7>(),
+            // The rename of `SHA3` has no EIP of its own, clients adopted `KECCAK256` with London.
+            // EIP-3198 is the EIP London introduced an instruction with, so it marks London here.
+            Mnemonic::KECCAK256 => Self::includes::<Eip3198>(),
+            Mnemonic::PREVRANDAO => Self::includes::<Eip4399>(),
+            _ => true,
+        };
+
+        match mnemonic.aliases() {
+            [.., previous] if !renamed => previous,
+            _ => mnemonic.name(),
+        }
+    }
+
+    /// Returns the gas schedule of this execution upgrade.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::{execution::ExecutionUpgrade, forks::{tangerine_whistle::TangerineWhistle, berlin::Berlin}};
+    /// # use asm2::Mnemonic;
+    /// assert_eq!(TangerineWhistle::gas_schedule().static_cost(Mnemonic::SLOAD), Some(200));
+    /// assert_eq!(Berlin::gas_schedule().static_cost(Mnemonic::SLOAD), Some(100));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn gas_schedule() -> GasSchedule
+    where
+        Self: Sized,
+    {
+        GasSchedule::new::<Self>()
+    }
 }
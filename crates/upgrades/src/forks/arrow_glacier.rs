@@ -1,35 +1,28 @@
 //! Arrow Glacier network upgrade.
 
-use chains::Mainnet;
+use chains::{Chain, Mainnet};
 
 use crate::{
-    eip::macros::eip_set,
-    eips::eip4345::Eip4345,
-    execution::ExecutionUpgrade,
-    forks::london::London,
-    network::{NetworkUpgrade, UpgradeActivation},
+    eip::macros::eip_set, eips::eip4345::Eip4345, execution::ExecutionUpgrade,
+    forks::london::London, network::NetworkUpgrade,
 };
 
 /// Arrow Glacier network upgrade.
 pub struct ArrowGlacier;
 
-impl NetworkUpgrade for ArrowGlacier {}
+impl NetworkUpgrade for ArrowGlacier {
+    const ACTIVATIONS: &'static [(u64, u64)] = &[(Mainnet::CHAIN_ID, 13_773_000)];
+}
 
 impl ExecutionUpgrade for ArrowGlacier {
     type EipSet = eip_set!(London + Eip4345);
 }
 
-impl UpgradeActivation<Mainnet> for ArrowGlacier {
-    fn block() -> u64 {
-        13_773_000
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::eips::eip2::Eip2;
-    use asm::instruction::Add;
+    use asm2::instruction::Add;
     use chains::Mainnet;
 
     #[test]
@@ -1,53 +1,36 @@
 //! Berlin network upgrade.
 
-use chains::{Goerli, Mainnet, Rinkeby, Ropsten};
+use chains::{Chain, Goerli, Mainnet, Rinkeby, Ropsten};
 
 use crate::{
     eip::macros::eip_set,
     eips::{eip2565::Eip2565, eip2718::Eip2718, eip2929::Eip2929, eip2930::Eip2930},
     execution::ExecutionUpgrade,
     forks::muir_glacier::MuirGlacier,
-    network::{NetworkUpgrade, UpgradeActivation},
+    network::NetworkUpgrade,
 };
 
 /// Berlin network upgrade.
 pub struct Berlin;
 
-impl NetworkUpgrade for Berlin {}
+impl NetworkUpgrade for Berlin {
+    const ACTIVATIONS: &'static [(u64, u64)] = &[
+        (Mainnet::CHAIN_ID, 12_244_000),
+        (Rinkeby::CHAIN_ID, 8_290_928),
+        (Goerli::CHAIN_ID, 4_460_644),
+        (Ropsten::CHAIN_ID, 9_812_189),
+    ];
+}
 
 impl ExecutionUpgrade for Berlin {
     type EipSet = eip_set!(MuirGlacier + Eip2565, Eip2929, Eip2718, Eip2930);
 }
 
-impl UpgradeActivation<Mainnet> for Berlin {
-    fn block() -> u64 {
-        12_244_000
-    }
-}
-
-impl UpgradeActivation<Rinkeby> for Berlin {
-    fn block() -> u64 {
-        8_290_928
-    }
-}
-
-impl UpgradeActivation<Goerli> for Berlin {
-    fn block() -> u64 {
-        4_460_644
-    }
-}
-
-impl UpgradeActivation<Ropsten> for Berlin {
-    fn block() -> u64 {
-        9_812_189
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::eips::{eip2::Eip2, eip7::Eip7};
-    use asm::instruction::Add;
+    use asm2::instruction::Add;
     use chains::{Mainnet, Ropsten};
 
     #[test]
@@ -8,9 +8,9 @@ use crate::{
     },
     execution::ExecutionUpgrade,
     forks::spurious_dragon::SpuriousDragon,
-    network::{NetworkUpgrade, UpgradeActivation},
+    network::NetworkUpgrade,
 };
-use chains::{Mainnet, Ropsten};
+use chains::{Chain, Mainnet, Ropsten};
 
 /// Byzantium (Metropolis/Byzantium, Metropolis part 1) network upgrade.
 pub struct Byzantium;
@@ -27,25 +27,20 @@ impl ExecutionUpgrade for Byzantium {
     );
 }
 
-impl NetworkUpgrade for Byzantium {}
-
-impl UpgradeActivation<Mainnet> for Byzantium {
-    fn block() -> u64 {
-        4_370_000
-    }
-}
-
-impl UpgradeActivation<Ropsten> for Byzantium {
-    fn block() -> u64 {
-        1_700_000
-    }
+impl NetworkUpgrade for Byzantium {
+    const ACTIVATIONS: &'static [(u64, u64)] = &[
+        (Mainnet::CHAIN_ID, 4_370_000),
+        (Ropsten::CHAIN_ID, 1_700_000),
+    ];
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::eips::{eip2::Eip2, eip7::Eip7};
-    use asm::instruction::{Add, DelegateCall, ReturnDataCopy, ReturnDataSize, Revert, StaticCall};
+    use asm2::instruction::{
+        Add, DelegateCall, ReturnDataCopy, ReturnDataSize, Revert, StaticCall,
+    };
     use chains::{Mainnet, Morden, Ropsten};
 
     #[test]
@@ -28,7 +28,7 @@ impl ExecutionUpgrade for Cancun {
 mod tests {
     use super::*;
     use crate::eips::eip2::Eip2;
-    use asm::instruction::{Add, BlobBaseFee, MCopy, TLoad, TStore};
+    use asm2::instruction::{Add, BlobBaseFee, MCopy, TLoad, TStore};
 
     #[test]
     fn eip_support() {
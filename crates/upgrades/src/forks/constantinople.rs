@@ -1,6 +1,6 @@
 //! Constantinople network upgrade.
 
-use chains::{Kovan, Mainnet, Rinkeby, Ropsten};
+use chains::{Chain, Kovan, Mainnet, Rinkeby, Ropsten};
 
 use crate::{
     eip::macros::eip_set,
@@ -9,7 +9,7 @@ use crate::{
     },
     execution::ExecutionUpgrade,
     forks::byzantium::Byzantium,
-    network::{NetworkUpgrade, UpgradeActivation},
+    network::NetworkUpgrade,
 };
 
 /// Constantinople (Metropolis/Constantinople, Metropolis part 2) network upgrade.
@@ -19,37 +19,20 @@ impl ExecutionUpgrade for Constantinople {
     type EipSet = eip_set!(Byzantium + Eip145, Eip1014, Eip1052, Eip1234, Eip1283);
 }
 
-impl NetworkUpgrade for Constantinople {}
-
-impl UpgradeActivation<Mainnet> for Constantinople {
-    fn block() -> u64 {
-        7_280_000
-    }
-}
-
-impl UpgradeActivation<Ropsten> for Constantinople {
-    fn block() -> u64 {
-        4_230_000
-    }
-}
-
-impl UpgradeActivation<Kovan> for Constantinople {
-    fn block() -> u64 {
-        9_200_000
-    }
-}
-
-impl UpgradeActivation<Rinkeby> for Constantinople {
-    fn block() -> u64 {
-        3_660_663
-    }
+impl NetworkUpgrade for Constantinople {
+    const ACTIVATIONS: &'static [(u64, u64)] = &[
+        (Mainnet::CHAIN_ID, 7_280_000),
+        (Ropsten::CHAIN_ID, 4_230_000),
+        (Kovan::CHAIN_ID, 9_200_000),
+        (Rinkeby::CHAIN_ID, 3_660_663),
+    ];
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::eips::{eip2::Eip2, eip7::Eip7};
-    use asm::instruction::{Add, Create2, ExtCodeHash, Sar, Shl, Shr};
+    use asm2::instruction::{Add, Create2, ExtCodeHash, Sar, Shl, Shr};
     use chains::{Kovan, Mainnet, Rinkeby, Ropsten};
 
     #[test]
@@ -18,7 +18,7 @@ impl NetworkUpgrade for Frontier {}
 mod tests {
     use super::*;
     use crate::eips::eip2::Eip2;
-    use asm::instruction::{DelegateCall, Invalid, Stop};
+    use asm2::instruction::{DelegateCall, Invalid, Stop};
     use chains::Mainnet;
 
     #[test]
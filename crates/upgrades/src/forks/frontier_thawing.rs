@@ -1,11 +1,7 @@
 //! Frontier Thawing network update.
 
-use crate::{
-    execution::ExecutionUpgrade,
-    forks::frontier::Frontier,
-    network::{NetworkUpgrade, UpgradeActivation},
-};
-use chains::Mainnet;
+use crate::{execution::ExecutionUpgrade, forks::frontier::Frontier, network::NetworkUpgrade};
+use chains::{Chain, Mainnet};
 
 /// Frontier Thawing network update.
 pub struct FrontierThawing;
@@ -14,19 +10,15 @@ impl ExecutionUpgrade for FrontierThawing {
     type EipSet = <Frontier as ExecutionUpgrade>::EipSet;
 }
 
-impl NetworkUpgrade for FrontierThawing {}
-
-impl UpgradeActivation<Mainnet> for FrontierThawing {
-    fn block() -> u64 {
-        200_000
-    }
+impl NetworkUpgrade for FrontierThawing {
+    const ACTIVATIONS: &'static [(u64, u64)] = &[(Mainnet::CHAIN_ID, 200_000)];
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::eips::eip2::Eip2;
-    use asm::instruction::{DelegateCall, Invalid, Stop};
+    use asm2::instruction::{DelegateCall, Invalid, Stop};
     use chains::{Mainnet, Ropsten};
 
     #[test]
@@ -1,35 +1,28 @@
 //! Gray Glacier network upgrade.
 
-use chains::Mainnet;
+use chains::{Chain, Mainnet};
 
 use crate::{
-    eip::macros::eip_set,
-    eips::eip5133::Eip5133,
-    execution::ExecutionUpgrade,
-    forks::arrow_glacier::ArrowGlacier,
-    network::{NetworkUpgrade, UpgradeActivation},
+    eip::macros::eip_set, eips::eip5133::Eip5133, execution::ExecutionUpgrade,
+    forks::arrow_glacier::ArrowGlacier, network::NetworkUpgrade,
 };
 
 /// Gray Glacier network upgrade.
 pub struct GrayGlacier;
 
-impl NetworkUpgrade for GrayGlacier {}
+impl NetworkUpgrade for GrayGlacier {
+    const ACTIVATIONS: &'static [(u64, u64)] = &[(Mainnet::CHAIN_ID, 15_050_000)];
+}
 
 impl ExecutionUpgrade for GrayGlacier {
     type EipSet = eip_set!(ArrowGlacier + Eip5133);
 }
 
-impl UpgradeActivation<Mainnet> for GrayGlacier {
-    fn block() -> u64 {
-        15_050_000
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::eips::eip2::Eip2;
-    use asm::instruction::Add;
+    use asm2::instruction::Add;
     use chains::Mainnet;
 
     #[test]
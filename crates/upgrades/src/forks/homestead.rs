@@ -5,9 +5,9 @@ use crate::{
     eips::{eip2::Eip2, eip7::Eip7, eip8::Eip8},
     execution::ExecutionUpgrade,
     forks::frontier_thawing::FrontierThawing,
-    network::{NetworkUpgrade, UpgradeActivation},
+    network::NetworkUpgrade,
 };
-use chains::{Mainnet, Morden};
+use chains::{Chain, Mainnet, Morden};
 
 /// Homestead network update.
 pub struct Homestead;
@@ -16,25 +16,16 @@ impl ExecutionUpgrade for Homestead {
     type EipSet = eip_set!(FrontierThawing + Eip2, Eip7, Eip8);
 }
 
-impl NetworkUpgrade for Homestead {}
-
-impl UpgradeActivation<Mainnet> for Homestead {
-    fn block() -> u64 {
-        1_150_000
-    }
-}
-
-impl UpgradeActivation<Morden> for Homestead {
-    fn block() -> u64 {
-        494_000
-    }
+impl NetworkUpgrade for Homestead {
+    const ACTIVATIONS: &'static [(u64, u64)] =
+        &[(Mainnet::CHAIN_ID, 1_150_000), (Morden::CHAIN_ID, 494_000)];
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::eips::eip150::Eip150;
-    use asm::instruction::{Add, DelegateCall};
+    use asm2::instruction::{Add, DelegateCall};
     use chains::{Mainnet, Morden, Ropsten};
 
     #[test]
@@ -1,6 +1,6 @@
 //! Istanbul network upgrade.
 
-use chains::{Goerli, Kovan, Mainnet, Rinkeby, Ropsten};
+use chains::{Chain, Goerli, Kovan, Mainnet, Rinkeby, Ropsten};
 
 use crate::{
     eip::macros::eip_set,
@@ -10,7 +10,7 @@ use crate::{
     },
     execution::ExecutionUpgrade,
     forks::petersburg::Petersburg,
-    network::{NetworkUpgrade, UpgradeActivation},
+    network::NetworkUpgrade,
 };
 
 /// Istanbul network upgrade.
@@ -27,43 +27,21 @@ impl ExecutionUpgrade for Istanbul {
     );
 }
 
-impl NetworkUpgrade for Istanbul {}
-
-impl UpgradeActivation<Mainnet> for Istanbul {
-    fn block() -> u64 {
-        9_069_000
-    }
-}
-
-impl UpgradeActivation<Ropsten> for Istanbul {
-    fn block() -> u64 {
-        6_485_000
-    }
-}
-
-impl UpgradeActivation<Kovan> for Istanbul {
-    fn block() -> u64 {
-        14_111_141
-    }
-}
-
-impl UpgradeActivation<Rinkeby> for Istanbul {
-    fn block() -> u64 {
-        5_435_345
-    }
-}
-
-impl UpgradeActivation<Goerli> for Istanbul {
-    fn block() -> u64 {
-        1_561_651
-    }
+impl NetworkUpgrade for Istanbul {
+    const ACTIVATIONS: &'static [(u64, u64)] = &[
+        (Mainnet::CHAIN_ID, 9_069_000),
+        (Ropsten::CHAIN_ID, 6_485_000),
+        (Kovan::CHAIN_ID, 14_111_141),
+        (Rinkeby::CHAIN_ID, 5_435_345),
+        (Goerli::CHAIN_ID, 1_561_651),
+    ];
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::eips::{eip2::Eip2, eip7::Eip7};
-    use asm::instruction::{Add, ChainId, SelfBalance};
+    use asm2::instruction::{Add, ChainId, SelfBalance};
     use chains::{Kovan, Mainnet, Rinkeby, Ropsten};
 
     #[test]
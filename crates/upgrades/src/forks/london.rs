@@ -1,6 +1,6 @@
 //! London network upgrade.
 
-use chains::{Goerli, Kovan, Mainnet, Rinkeby, Ropsten};
+use chains::{Chain, Goerli, Kovan, Mainnet, Rinkeby, Ropsten};
 
 use crate::{
     eip::macros::eip_set,
@@ -9,53 +9,31 @@ use crate::{
     },
     execution::ExecutionUpgrade,
     forks::berlin::Berlin,
-    network::{NetworkUpgrade, UpgradeActivation},
+    network::NetworkUpgrade,
 };
 
 /// London network upgrade.
 pub struct London;
 
-impl NetworkUpgrade for London {}
+impl NetworkUpgrade for London {
+    const ACTIVATIONS: &'static [(u64, u64)] = &[
+        (Mainnet::CHAIN_ID, 12_965_000),
+        (Kovan::CHAIN_ID, 26_741_100),
+        (Rinkeby::CHAIN_ID, 8_897_988),
+        (Goerli::CHAIN_ID, 5_062_605),
+        (Ropsten::CHAIN_ID, 10_499_401),
+    ];
+}
 
 impl ExecutionUpgrade for London {
     type EipSet = eip_set!(Berlin + Eip1559, Eip3198, Eip3529, Eip3541, Eip3554);
 }
 
-impl UpgradeActivation<Mainnet> for London {
-    fn block() -> u64 {
-        12_965_000
-    }
-}
-
-impl UpgradeActivation<Kovan> for London {
-    fn block() -> u64 {
-        26_741_100
-    }
-}
-
-impl UpgradeActivation<Rinkeby> for London {
-    fn block() -> u64 {
-        8_897_988
-    }
-}
-
-impl UpgradeActivation<Goerli> for London {
-    fn block() -> u64 {
-        5_062_605
-    }
-}
-
-impl UpgradeActivation<Ropsten> for London {
-    fn block() -> u64 {
-        10_499_401
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::eips::{eip2::Eip2, eip7::Eip7};
-    use asm::instruction::{Add, BaseFee};
+    use asm2::instruction::{Add, BaseFee};
     use chains::{Mainnet, Ropsten};
 
     #[test]
@@ -18,3 +18,138 @@ pub mod prague;
 pub mod shanghai;
 pub mod spurious_dragon;
 pub mod tangerine_whistle;
+
+use asm2::Mnemonic;
+use core::fmt::Display;
+
+use crate::execution::ExecutionUpgrade;
+
+/// Defines the [`Fork`] enum, dispatching its methods to the corresponding [`ExecutionUpgrade`].
+macro_rules! define_forks {
+    ($($variant: ident = $upgrade: ty, $name: literal;)+) => {
+        /// A mainnet execution upgrade that can be picked at runtime.
+        ///
+        /// Variants are ordered by their activation on mainnet.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum Fork {
+            $(
+                #[doc = concat!("[`", stringify!($upgrade), "`].")]
+                $variant,
+            )+
+        }
+
+        impl Fork {
+            /// All forks, in the order they were activated on mainnet.
+            pub const ALL: &'static [Self] = &[$(Self::$variant),+];
+
+            /// Returns the human readable name of this fork.
+            ///
+            /// # Example
+            /// ```
+            /// # use oculars_upgrades::forks::Fork;
+            /// assert_eq!(Fork::SpuriousDragon.name(), "Spurious Dragon");
+            /// ```
+            #[must_use]
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)+
+                }
+            }
+
+            /// Returns whether this fork supports the instruction with a specific mnemonic.
+            ///
+            /// See [`ExecutionUpgrade::supports_mnemonic`].
+            #[must_use]
+            pub fn supports_mnemonic(self, mnemonic: Mnemonic) -> bool {
+                match self {
+                    $(Self::$variant => <$upgrade>::supports_mnemonic(mnemonic),)+
+                }
+            }
+
+            /// Returns the number of the EIP in this fork that introduced the instruction with a
+            /// specific mnemonic.
+            ///
+            /// See [`ExecutionUpgrade::introducing_eip`].
+            #[must_use]
+            pub fn introducing_eip(self, mnemonic: Mnemonic) -> Option<u32> {
+                match self {
+                    $(Self::$variant => <$upgrade>::introducing_eip(mnemonic),)+
+                }
+            }
+
+            /// Returns the name a mnemonic had in this fork.
+            ///
+            /// See [`ExecutionUpgrade::mnemonic_name`].
+            #[must_use]
+            pub fn mnemonic_name(self, mnemonic: Mnemonic) -> &'static str {
+                match self {
+                    $(Self::$variant => <$upgrade>::mnemonic_name(mnemonic),)+
+                }
+            }
+        }
+    };
+}
+
+define_forks! {
+    Frontier = frontier::Frontier, "Frontier";
+    FrontierThawing = frontier_thawing::FrontierThawing, "Frontier Thawing";
+    Homestead = homestead::Homestead, "Homestead";
+    TangerineWhistle = tangerine_whistle::TangerineWhistle, "Tangerine Whistle";
+    SpuriousDragon = spurious_dragon::SpuriousDragon, "Spurious Dragon";
+    Byzantium = byzantium::Byzantium, "Byzantium";
+    Constantinople = constantinople::Constantinople, "Constantinople";
+    Petersburg = petersburg::Petersburg, "Petersburg";
+    Istanbul = istanbul::Istanbul, "Istanbul";
+    MuirGlacier = muir_glacier::MuirGlacier, "Muir Glacier";
+    Berlin = berlin::Berlin, "Berlin";
+    London = london::London, "London";
+    ArrowGlacier = arrow_glacier::ArrowGlacier, "Arrow Glacier";
+    GrayGlacier = gray_glacier::GrayGlacier, "Gray Glacier";
+    Paris = paris::Paris, "Paris";
+    Shanghai = shanghai::Shanghai, "Shanghai";
+    Cancun = cancun::Cancun, "Cancun";
+    Prague = prague::Prague, "Prague";
+}
+
+impl Display for Fork {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forks_are_ordered() {
+        assert!(Fork::ALL.is_sorted());
+        assert_eq!(Fork::ALL.first(), Some(&Fork::Frontier));
+        assert_eq!(Fork::ALL.last(), Some(&Fork::Prague));
+    }
+
+    #[test]
+    fn fork_dispatch() {
+        assert!(!Fork::Paris.supports_mnemonic(Mnemonic::PUSH0));
+        assert!(Fork::Shanghai.supports_mnemonic(Mnemonic::PUSH0));
+
+        assert_eq!(Fork::Cancun.introducing_eip(Mnemonic::MCOPY), Some(5656));
+        assert_eq!(
+            Fork::Byzantium.mnemonic_name(Mnemonic::PREVRANDAO),
+            "DIFFICULTY"
+        );
+        assert_eq!(Fork::MuirGlacier.name(), "Muir Glacier");
+    }
+
+    #[test]
+    fn renamed_mnemonics() {
+        for &mnemonic in Mnemonic::VARIANTS {
+            let frontier = Fork::Frontier.mnemonic_name(mnemonic);
+            assert_eq!(frontier != mnemonic.name(), !mnemonic.aliases().is_empty());
+            assert_eq!(
+                Fork::ALL.last().unwrap().mnemonic_name(mnemonic),
+                mnemonic.name()
+            );
+        }
+    }
+}
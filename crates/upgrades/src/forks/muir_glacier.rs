@@ -1,13 +1,10 @@
 //! Muir Glacier network upgrade.
 
-use chains::{Mainnet, Ropsten};
+use chains::{Chain, Mainnet, Ropsten};
 
 use crate::{
-    eip::macros::eip_set,
-    eips::eip2384::Eip2384,
-    execution::ExecutionUpgrade,
-    forks::istanbul::Istanbul,
-    network::{NetworkUpgrade, UpgradeActivation},
+    eip::macros::eip_set, eips::eip2384::Eip2384, execution::ExecutionUpgrade,
+    forks::istanbul::Istanbul, network::NetworkUpgrade,
 };
 
 /// Muir Glacier network upgrade.
@@ -17,25 +14,18 @@ impl ExecutionUpgrade for MuirGlacier {
     type EipSet = eip_set!(Istanbul + Eip2384);
 }
 
-impl NetworkUpgrade for MuirGlacier {}
-
-impl UpgradeActivation<Mainnet> for MuirGlacier {
-    fn block() -> u64 {
-        9_200_000
-    }
-}
-
-impl UpgradeActivation<Ropsten> for MuirGlacier {
-    fn block() -> u64 {
-        7_117_117
-    }
+impl NetworkUpgrade for MuirGlacier {
+    const ACTIVATIONS: &'static [(u64, u64)] = &[
+        (Mainnet::CHAIN_ID, 9_200_000),
+        (Ropsten::CHAIN_ID, 7_117_117),
+    ];
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::eips::{eip2::Eip2, eip7::Eip7};
-    use asm::instruction::{Add, ChainId, SelfBalance};
+    use asm2::instruction::{Add, ChainId, SelfBalance};
     use chains::{Mainnet, Ropsten};
 
     #[test]
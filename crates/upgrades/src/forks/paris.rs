@@ -17,8 +17,14 @@ impl ExecutionUpgrade for Paris {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::eips::eip2::Eip2;
-    use asm::instruction::Add;
+    use crate::{
+        eips::eip2::Eip2,
+        forks::{
+            berlin::Berlin, frontier_thawing::FrontierThawing, gray_glacier::GrayGlacier,
+            homestead::Homestead,
+        },
+    };
+    use asm2::{Mnemonic, instruction::Add};
 
     #[test]
     fn eip_support() {
@@ -32,4 +38,25 @@ mod tests {
     fn instruction_support() {
         assert!(Paris::supports_instruction::<Add>());
     }
+
+    #[test]
+    fn mnemonic_names() {
+        assert_eq!(
+            GrayGlacier::mnemonic_name(Mnemonic::PREVRANDAO),
+            "DIFFICULTY"
+        );
+        assert_eq!(Paris::mnemonic_name(Mnemonic::PREVRANDAO), "PREVRANDAO");
+
+        assert_eq!(GrayGlacier::mnemonic_name(Mnemonic::KECCAK256), "KECCAK256");
+        assert_eq!(Berlin::mnemonic_name(Mnemonic::KECCAK256), "SHA3");
+        assert_eq!(Paris::mnemonic_name(Mnemonic::SELFDESTRUCT), "SELFDESTRUCT");
+        assert_eq!(
+            Homestead::mnemonic_name(Mnemonic::SELFDESTRUCT),
+            "SELFDESTRUCT"
+        );
+        assert_eq!(
+            FrontierThawing::mnemonic_name(Mnemonic::SELFDESTRUCT),
+            "SUICIDE"
+        );
+    }
 }
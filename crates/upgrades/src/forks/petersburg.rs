@@ -1,13 +1,11 @@
 //! Petersburg network upgrade.
 
-use chains::Chain;
-
 use crate::{
     eip::macros::eip_set,
     eips::{eip145::Eip145, eip1014::Eip1014, eip1052::Eip1052, eip1234::Eip1234},
     execution::ExecutionUpgrade,
     forks::{byzantium::Byzantium, constantinople::Constantinople},
-    network::{NetworkUpgrade, UpgradeActivation},
+    network::NetworkUpgrade,
 };
 
 /// Petersburg (St. Peretsfork, Peter's Fork, Constantinople Fix) network upgrade.
@@ -17,19 +15,16 @@ impl ExecutionUpgrade for Petersburg {
     type EipSet = eip_set!(Byzantium + Eip145, Eip1014, Eip1052, Eip1234);
 }
 
-impl NetworkUpgrade for Petersburg {}
-
-impl<C: Chain> UpgradeActivation<C> for Petersburg {
-    fn block() -> u64 {
-        <Constantinople as UpgradeActivation<C>>::block()
-    }
+// Petersburg was activated together with Constantinople on every chain.
+impl NetworkUpgrade for Petersburg {
+    const ACTIVATIONS: &'static [(u64, u64)] = Constantinople::ACTIVATIONS;
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::eips::{eip2::Eip2, eip7::Eip7, eip1283::Eip1283};
-    use asm::instruction::{Add, Create2, ExtCodeHash, Sar, Shl, Shr};
+    use asm2::instruction::{Add, Create2, ExtCodeHash, Sar, Shl, Shr};
     use chains::{Kovan, Mainnet, Rinkeby, Ropsten};
 
     #[test]
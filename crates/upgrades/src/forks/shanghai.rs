@@ -18,7 +18,7 @@ impl ExecutionUpgrade for Shanghai {
 mod tests {
     use super::*;
     use crate::eips::eip2::Eip2;
-    use asm::instruction::{Add, Push};
+    use asm2::instruction::{Add, Push};
 
     #[test]
     fn eip_support() {
@@ -5,9 +5,9 @@ use crate::{
     eips::{eip155::Eip155, eip160::Eip160, eip161::Eip161, eip170::Eip170},
     execution::ExecutionUpgrade,
     forks::tangerine_whistle::TangerineWhistle,
-    network::{NetworkUpgrade, UpgradeActivation},
+    network::NetworkUpgrade,
 };
-use chains::{Mainnet, Morden};
+use chains::{Chain, Mainnet, Morden};
 
 /// Spurious Dragon network update.
 pub struct SpuriousDragon;
@@ -16,25 +16,18 @@ impl ExecutionUpgrade for SpuriousDragon {
     type EipSet = eip_set!(TangerineWhistle + Eip155, Eip160, Eip161, Eip170);
 }
 
-impl NetworkUpgrade for SpuriousDragon {}
-
-impl UpgradeActivation<Mainnet> for SpuriousDragon {
-    fn block() -> u64 {
-        2_675_000
-    }
-}
-
-impl UpgradeActivation<Morden> for SpuriousDragon {
-    fn block() -> u64 {
-        1_885_000
-    }
+impl NetworkUpgrade for SpuriousDragon {
+    const ACTIVATIONS: &'static [(u64, u64)] = &[
+        (Mainnet::CHAIN_ID, 2_675_000),
+        (Morden::CHAIN_ID, 1_885_000),
+    ];
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::eips::{eip2::Eip2, eip7::Eip7};
-    use asm::instruction::{Add, DelegateCall};
+    use asm2::instruction::{Add, DelegateCall};
     use chains::{Mainnet, Morden, Ropsten};
 
     #[test]
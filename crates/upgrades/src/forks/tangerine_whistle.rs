@@ -1,12 +1,10 @@
 //! Tangerine Whistle network update.
 
 use crate::{
-    eips::eip150::Eip150,
-    execution::ExecutionUpgrade,
-    forks::homestead::Homestead,
-    network::{NetworkUpgrade, UpgradeActivation},
+    eips::eip150::Eip150, execution::ExecutionUpgrade, forks::homestead::Homestead,
+    network::NetworkUpgrade,
 };
-use chains::Mainnet;
+use chains::{Chain, Mainnet};
 
 /// Tangerine Whistle network update.
 pub struct TangerineWhistle;
@@ -15,19 +13,15 @@ impl ExecutionUpgrade for TangerineWhistle {
     type EipSet = (Eip150, <Homestead as ExecutionUpgrade>::EipSet);
 }
 
-impl NetworkUpgrade for TangerineWhistle {}
-
-impl UpgradeActivation<Mainnet> for TangerineWhistle {
-    fn block() -> u64 {
-        2_463_000
-    }
+impl NetworkUpgrade for TangerineWhistle {
+    const ACTIVATIONS: &'static [(u64, u64)] = &[(Mainnet::CHAIN_ID, 2_463_000)];
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::eips::eip2::Eip2;
-    use asm::instruction::{Add, DelegateCall};
+    use asm2::instruction::{Add, DelegateCall};
     use chains::{Mainnet, Morden, Ropsten};
 
     #[test]
@@ -0,0 +1,87 @@
+//! Warm and cold access costs (EIP-2929).
+
+use crate::gas::GasSchedule;
+
+/// Cost of reading a warm account or storage slot.
+pub const WARM_STORAGE_READ: u64 = 100;
+
+/// Cost of accessing a cold storage slot.
+pub const COLD_SLOAD: u64 = 2100;
+
+/// Cost of accessing a cold account.
+pub const COLD_ACCOUNT_ACCESS: u64 = 2600;
+
+/// Whether an account or storage slot was already accessed during the transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Access {
+    /// Accessed before (or part of the transaction's access list).
+    Warm,
+    /// Accessed for the first time.
+    Cold,
+}
+
+impl GasSchedule {
+    /// Returns the cost of accessing an account (`BALANCE`, `EXTCODESIZE`, `EXTCODECOPY`,
+    /// `EXTCODEHASH` and the `CALL` family) on top of the static cost.
+    ///
+    /// Accesses are only priced differently once [access lists](GasSchedule::access_lists) are
+    /// enabled, before that the static cost covers the whole access.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::gas::{Access, GasSchedule};
+    /// # use oculars_upgrades::forks::{berlin::Berlin, istanbul::Istanbul};
+    /// assert_eq!(GasSchedule::new::<Istanbul>().account_access_cost(Access::Cold), 0);
+    /// assert_eq!(GasSchedule::new::<Berlin>().account_access_cost(Access::Cold), 2500);
+    /// assert_eq!(GasSchedule::new::<Berlin>().account_access_cost(Access::Warm), 0);
+    /// ```
+    #[must_use]
+    pub const fn account_access_cost(&self, access: Access) -> u64 {
+        match (self.access_lists, access) {
+            (true, Access::Cold) => COLD_ACCOUNT_ACCESS - WARM_STORAGE_READ,
+            _ => 0,
+        }
+    }
+
+    /// Returns the cost of accessing a storage slot with `SLOAD` on top of the static cost.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::gas::{Access, GasSchedule};
+    /// # use oculars_upgrades::forks::berlin::Berlin;
+    /// assert_eq!(GasSchedule::new::<Berlin>().storage_access_cost(Access::Cold), 2000);
+    /// ```
+    #[must_use]
+    pub const fn storage_access_cost(&self, access: Access) -> u64 {
+        match (self.access_lists, access) {
+            (true, Access::Cold) => COLD_SLOAD - WARM_STORAGE_READ,
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forks::{cancun::Cancun, frontier::Frontier};
+    use asm2::Mnemonic;
+
+    #[test]
+    fn access_costs() {
+        let frontier = GasSchedule::new::<Frontier>();
+        assert_eq!(frontier.account_access_cost(Access::Cold), 0);
+        assert_eq!(frontier.storage_access_cost(Access::Cold), 0);
+
+        let cancun = GasSchedule::new::<Cancun>();
+        assert_eq!(
+            cancun.static_cost(Mnemonic::BALANCE).unwrap()
+                + cancun.account_access_cost(Access::Cold),
+            COLD_ACCOUNT_ACCESS
+        );
+        assert_eq!(
+            cancun.static_cost(Mnemonic::SLOAD).unwrap() + cancun.storage_access_cost(Access::Cold),
+            COLD_SLOAD
+        );
+        assert_eq!(cancun.storage_access_cost(Access::Warm), 0);
+    }
+}
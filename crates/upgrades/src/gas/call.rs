@@ -0,0 +1,161 @@
+//! `CALL` family costs.
+
+use asm2::Mnemonic;
+
+use crate::gas::{Access, GasSchedule};
+
+/// Cost of transferring value with `CALL` or `CALLCODE`.
+pub const CALL_VALUE: u64 = 9000;
+
+/// Cost of creating a new account by transferring value to it.
+pub const CALL_NEW_ACCOUNT: u64 = 25000;
+
+/// Gas given to the callee for free when value is transferred.
+pub const CALL_STIPEND: u64 = 2300;
+
+impl GasSchedule {
+    /// Returns the cost of a call on top of the static cost and memory expansion.
+    ///
+    /// `new_account` is whether the target account does not exist yet, or is empty once state
+    /// clearing (EIP-161) is active. Only `CALL` can create an account and only `CALL` and
+    /// `CALLCODE` transfer value, the respective flags are ignored for other mnemonics.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::gas::{Access, GasSchedule};
+    /// # use oculars_upgrades::forks::{homestead::Homestead, berlin::Berlin};
+    /// # use asm2::Mnemonic;
+    /// // creating an account without transferring value
+    /// let homestead = GasSchedule::new::<Homestead>();
+    /// assert_eq!(homestead.call_cost(Mnemonic::CALL, false, true, Access::Cold), 25000);
+    ///
+    /// let berlin = GasSchedule::new::<Berlin>();
+    /// assert_eq!(berlin.call_cost(Mnemonic::CALL, false, true, Access::Cold), 2500);
+    /// assert_eq!(berlin.call_cost(Mnemonic::CALL, true, true, Access::Warm), 34000);
+    /// ```
+    #[must_use]
+    pub const fn call_cost(
+        &self,
+        mnemonic: Mnemonic,
+        transfers_value: bool,
+        new_account: bool,
+        access: Access,
+    ) -> u64 {
+        let transfers_value =
+            transfers_value && matches!(mnemonic, Mnemonic::CALL | Mnemonic::CALLCODE);
+        let creates_account = matches!(mnemonic, Mnemonic::CALL)
+            && new_account
+            && (transfers_value || !self.state_clearing);
+
+        let mut cost = self.account_access_cost(access);
+
+        if transfers_value {
+            cost += CALL_VALUE;
+        }
+
+        if creates_account {
+            cost += CALL_NEW_ACCOUNT;
+        }
+
+        cost
+    }
+
+    /// Returns the gas forwarded to the callee, excluding the [stipend](CALL_STIPEND), when
+    /// `requested` gas is passed to a call and `available` gas is left after paying for it.
+    ///
+    /// Since EIP-150 at most all but one 64th of the available gas is forwarded, before that the
+    /// call fails if more gas is requested than available.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::gas::GasSchedule;
+    /// # use oculars_upgrades::forks::{homestead::Homestead, tangerine_whistle::TangerineWhistle};
+    /// assert_eq!(GasSchedule::new::<Homestead>().call_gas(6400, 6400), Some(6400));
+    /// assert_eq!(GasSchedule::new::<Homestead>().call_gas(u64::MAX, 6400), None);
+    /// assert_eq!(GasSchedule::new::<TangerineWhistle>().call_gas(u64::MAX, 6400), Some(6300));
+    /// ```
+    #[must_use]
+    pub const fn call_gas(&self, requested: u64, available: u64) -> Option<u64> {
+        if self.all_but_one_64th {
+            let limit = available - available / 64;
+            Some(if requested < limit { requested } else { limit })
+        } else if requested <= available {
+            Some(requested)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forks::{
+        cancun::Cancun, frontier::Frontier, spurious_dragon::SpuriousDragon,
+        tangerine_whistle::TangerineWhistle,
+    };
+
+    #[test]
+    fn value_and_new_account_surcharges() {
+        let frontier = GasSchedule::new::<Frontier>();
+        assert_eq!(
+            frontier.call_cost(Mnemonic::CALL, false, false, Access::Cold),
+            0
+        );
+        assert_eq!(
+            frontier.call_cost(Mnemonic::CALL, true, false, Access::Cold),
+            9000
+        );
+        assert_eq!(
+            frontier.call_cost(Mnemonic::CALLCODE, true, true, Access::Cold),
+            9000
+        );
+        assert_eq!(
+            frontier.call_cost(Mnemonic::CALL, true, true, Access::Cold),
+            34000
+        );
+
+        let tangerine_whistle = GasSchedule::new::<TangerineWhistle>();
+        assert_eq!(
+            tangerine_whistle.call_cost(Mnemonic::CALL, false, true, Access::Cold),
+            25000
+        );
+
+        let spurious_dragon = GasSchedule::new::<SpuriousDragon>();
+        assert_eq!(
+            spurious_dragon.call_cost(Mnemonic::CALL, false, true, Access::Cold),
+            0
+        );
+        assert_eq!(
+            spurious_dragon.call_cost(Mnemonic::CALL, true, true, Access::Cold),
+            34000
+        );
+
+        let cancun = GasSchedule::new::<Cancun>();
+        assert_eq!(
+            cancun.call_cost(Mnemonic::DELEGATECALL, true, true, Access::Cold),
+            2500
+        );
+        assert_eq!(
+            cancun.call_cost(Mnemonic::STATICCALL, false, false, Access::Warm),
+            0
+        );
+        assert_eq!(
+            cancun.call_cost(Mnemonic::CALL, true, true, Access::Cold),
+            36500
+        );
+    }
+
+    #[test]
+    fn forwarded_gas() {
+        let frontier = GasSchedule::new::<Frontier>();
+        assert_eq!(frontier.call_gas(0, 0), Some(0));
+        assert_eq!(frontier.call_gas(100, 99), None);
+
+        let cancun = GasSchedule::new::<Cancun>();
+        assert_eq!(cancun.call_gas(100, 1000), Some(100));
+        assert_eq!(cancun.call_gas(1000, 1000), Some(985));
+        assert_eq!(cancun.call_gas(u64::MAX, 63), Some(63));
+        assert_eq!(cancun.call_gas(u64::MAX, 64), Some(63));
+    }
+}
@@ -0,0 +1,93 @@
+//! Costs that depend on the size of an instruction's operands.
+
+use crate::gas::{GasSchedule, memory::words};
+
+/// Returns the cost of hashing `size` bytes with `KECCAK256`, excluding the static cost and memory
+/// expansion.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::gas::dynamic::keccak256_cost;
+/// assert_eq!(keccak256_cost(0), 0);
+/// assert_eq!(keccak256_cost(64), 12);
+/// ```
+#[must_use]
+pub const fn keccak256_cost(size: u64) -> u64 {
+    /// Cost of every hashed word.
+    const KECCAK256_WORD: u64 = 6;
+
+    KECCAK256_WORD.saturating_mul(words(size))
+}
+
+/// Returns the cost of logging `size` bytes of data with `LOGx`, excluding the static cost
+/// (which includes the topics) and memory expansion.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::gas::dynamic::log_data_cost;
+/// assert_eq!(log_data_cost(32), 256);
+/// ```
+#[must_use]
+pub const fn log_data_cost(size: u64) -> u64 {
+    /// Cost of every logged byte.
+    const LOG_DATA: u64 = 8;
+
+    LOG_DATA.saturating_mul(size)
+}
+
+/// Returns the amount of significant bytes of a big-endian value.
+const fn significant_bytes(value: &[u8]) -> u64 {
+    let mut index = 0;
+
+    while index < value.len() && value[index] == 0 {
+        index += 1;
+    }
+
+    (value.len() - index) as u64
+}
+
+impl GasSchedule {
+    /// Returns the cost of raising to the power of the big-endian `exponent` with `EXP`, excluding
+    /// the static cost.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::gas::GasSchedule;
+    /// # use oculars_upgrades::forks::{frontier::Frontier, spurious_dragon::SpuriousDragon};
+    /// assert_eq!(GasSchedule::new::<Frontier>().exp_cost(&[0x01, 0x00]), 20);
+    /// assert_eq!(GasSchedule::new::<SpuriousDragon>().exp_cost(&[0x00, 0x01]), 50);
+    /// ```
+    #[must_use]
+    pub const fn exp_cost(&self, exponent: &[u8]) -> u64 {
+        self.exp_byte.saturating_mul(significant_bytes(exponent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forks::{homestead::Homestead, london::London};
+
+    #[test]
+    fn exp_costs() {
+        let homestead = GasSchedule::new::<Homestead>();
+        let london = GasSchedule::new::<London>();
+
+        assert_eq!(homestead.exp_cost(&[]), 0);
+        assert_eq!(homestead.exp_cost(&[0; 32]), 0);
+        assert_eq!(homestead.exp_cost(&[0xFF; 32]), 320);
+        assert_eq!(london.exp_cost(&[0xFF; 32]), 1600);
+
+        let mut exponent = [0; 32];
+        exponent[31] = 0x02;
+        assert_eq!(london.exp_cost(&exponent), 50);
+    }
+
+    #[test]
+    fn data_costs() {
+        assert_eq!(keccak256_cost(1), 6);
+        assert_eq!(keccak256_cost(33), 12);
+        assert_eq!(log_data_cost(0), 0);
+        assert_eq!(log_data_cost(3), 24);
+    }
+}
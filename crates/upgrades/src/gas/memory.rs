@@ -0,0 +1,114 @@
+//! Memory expansion costs.
+
+/// Size of a memory word in bytes.
+pub const WORD_SIZE: u64 = 32;
+
+/// Cost of every word of memory.
+pub const MEMORY_WORD: u64 = 3;
+
+/// Divisor of the quadratic part of the memory cost.
+pub const QUADRATIC_DIVISOR: u64 = 512;
+
+/// Returns the amount of words required to hold `size` bytes.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::gas::memory::words;
+/// assert_eq!(words(0), 0);
+/// assert_eq!(words(1), 1);
+/// assert_eq!(words(32), 1);
+/// assert_eq!(words(33), 2);
+/// ```
+#[must_use]
+pub const fn words(size: u64) -> u64 {
+    size.div_ceil(WORD_SIZE)
+}
+
+/// Returns the total cost of a memory holding `words` words.
+///
+/// The cost saturates at [`u64::MAX`], which no transaction can pay for.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::gas::memory::memory_cost;
+/// assert_eq!(memory_cost(1), 3);
+/// assert_eq!(memory_cost(1024), 5120);
+/// ```
+#[must_use]
+pub const fn memory_cost(words: u64) -> u64 {
+    MEMORY_WORD
+        .saturating_mul(words)
+        .saturating_add(words.saturating_mul(words) / QUADRATIC_DIVISOR)
+}
+
+/// Returns the cost of accessing `size` bytes at `offset` in a memory currently holding
+/// `current_words` words.
+///
+/// Accessing zero bytes never expands memory, regardless of the offset.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::gas::memory::memory_expansion_cost;
+/// // `MSTORE` to an empty memory
+/// assert_eq!(memory_expansion_cost(0, 0, 32), 3);
+/// // `MSTORE` to an already expanded region
+/// assert_eq!(memory_expansion_cost(2, 32, 32), 0);
+/// // expanding memory from 1 to 1024 words
+/// assert_eq!(memory_expansion_cost(1, 0, 32 * 1024), 5117);
+/// ```
+#[must_use]
+pub const fn memory_expansion_cost(current_words: u64, offset: u64, size: u64) -> u64 {
+    if size == 0 {
+        return 0;
+    }
+
+    let new_words = words(offset.saturating_add(size));
+
+    if new_words <= current_words {
+        0
+    } else {
+        memory_cost(new_words) - memory_cost(current_words)
+    }
+}
+
+/// Returns the cost of copying `size` bytes (`CALLDATACOPY`, `CODECOPY`, `EXTCODECOPY`,
+/// `RETURNDATACOPY` and `MCOPY`), excluding memory expansion.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::gas::memory::copy_cost;
+/// assert_eq!(copy_cost(0), 0);
+/// assert_eq!(copy_cost(33), 6);
+/// ```
+#[must_use]
+pub const fn copy_cost(size: u64) -> u64 {
+    /// Cost of every copied word.
+    const COPY_WORD: u64 = 3;
+
+    COPY_WORD.saturating_mul(words(size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_costs() {
+        assert_eq!(memory_cost(0), 0);
+        assert_eq!(memory_cost(22), 66);
+        assert_eq!(memory_cost(23), 70);
+        assert_eq!(memory_cost(u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn expansion_costs() {
+        assert_eq!(memory_expansion_cost(0, 0, 0), 0);
+        assert_eq!(memory_expansion_cost(0, u64::MAX, 0), 0);
+        assert_eq!(memory_expansion_cost(0, 0, 1), 3);
+        assert_eq!(memory_expansion_cost(0, 31, 1), 3);
+        assert_eq!(memory_expansion_cost(0, 32, 1), 6);
+        assert_eq!(memory_expansion_cost(1, 0, 32), 0);
+        assert_eq!(memory_expansion_cost(22, 0, 23 * 32), 4);
+        assert!(memory_expansion_cost(0, u64::MAX, 1) > u64::MAX / QUADRATIC_DIVISOR);
+    }
+}
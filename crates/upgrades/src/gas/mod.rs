@@ -0,0 +1,12 @@
+//! EVM gas costs.
+
+pub mod access;
+pub mod call;
+pub mod dynamic;
+pub mod memory;
+pub mod schedule;
+pub mod sstore;
+
+pub use access::Access;
+pub use schedule::GasSchedule;
+pub use sstore::{SstoreCost, SstoreRules};
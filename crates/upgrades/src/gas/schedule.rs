@@ -0,0 +1,406 @@
+//! Static gas costs of instructions.
+
+use asm2::Mnemonic;
+
+use crate::{
+    eips::{
+        eip150::Eip150, eip160::Eip160, eip161::Eip161, eip1283::Eip1283, eip1884::Eip1884,
+        eip2200::Eip2200, eip2929::Eip2929, eip3529::Eip3529,
+    },
+    execution::ExecutionUpgrade,
+    gas::sstore::SstoreRules,
+};
+
+/// Gas costs and metering rules of an execution upgrade.
+///
+/// Only the costs and rules that changed between execution upgrades are stored, everything else is
+/// constant and handled by [`GasSchedule::static_cost`] and the dynamic cost functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GasSchedule {
+    /// Static cost of `BALANCE`.
+    pub balance: u64,
+    /// Static cost of `EXTCODESIZE` and `EXTCODECOPY`.
+    pub ext_code: u64,
+    /// Static cost of `EXTCODEHASH`.
+    pub ext_code_hash: u64,
+    /// Static cost of `SLOAD`.
+    pub sload: u64,
+    /// Static cost of `CALL`, `CALLCODE`, `DELEGATECALL` and `STATICCALL`.
+    pub call: u64,
+    /// Static cost of `SELFDESTRUCT`.
+    pub self_destruct: u64,
+    /// Cost of every byte of the `EXP` exponent.
+    pub exp_byte: u64,
+    /// Rules used to price `SSTORE`.
+    pub sstore: SstoreRules,
+    /// Whether accounts and storage slots are priced as warm or cold (EIP-2929).
+    pub access_lists: bool,
+    /// Whether calls forward at most all but one 64th of the available gas (EIP-150).
+    pub all_but_one_64th: bool,
+    /// Whether the new account surcharge only applies to value transfers to empty accounts
+    /// (EIP-161).
+    pub state_clearing: bool,
+}
+
+impl GasSchedule {
+    /// Gas costs at the genesis of Ethereum.
+    pub const FRONTIER: Self = Self {
+        balance: 20,
+        ext_code: 20,
+        ext_code_hash: 400,
+        sload: 50,
+        call: 40,
+        self_destruct: 0,
+        exp_byte: 10,
+        sstore: SstoreRules::Legacy,
+        access_lists: false,
+        all_but_one_64th: false,
+        state_clearing: false,
+    };
+
+    /// Creates the gas schedule of an execution upgrade based on the EIPs it includes.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::{gas::GasSchedule, forks::{frontier::Frontier, istanbul::Istanbul}};
+    /// assert_eq!(GasSchedule::new::<Frontier>().sload, 50);
+    /// assert_eq!(GasSchedule::new::<Istanbul>().sload, 800);
+    /// ```
+    #[must_use]
+    pub fn new<E: ExecutionUpgrade>() -> Self {
+        let mut schedule = Self::FRONTIER;
+
+        if E::includes::<Eip150>() {
+            schedule.balance = 400;
+            schedule.ext_code = 700;
+            schedule.sload = 200;
+            schedule.call = 700;
+            schedule.self_destruct = 5000;
+            schedule.all_but_one_64th = true;
+        }
+
+        if E::includes::<Eip160>() {
+            schedule.exp_byte = 50;
+        }
+
+        if E::includes::<Eip161>() {
+            schedule.state_clearing = true;
+        }
+
+        if E::includes::<Eip1283>() {
+            schedule.sstore = SstoreRules::Eip1283;
+        }
+
+        if E::includes::<Eip1884>() {
+            schedule.balance = 700;
+            schedule.ext_code_hash = 700;
+            schedule.sload = 800;
+        }
+
+        if E::includes::<Eip2200>() {
+            schedule.sstore = SstoreRules::Eip2200;
+        }
+
+        // Account and storage accesses are split into a warm (static) and a cold (dynamic) part.
+        if E::includes::<Eip2929>() {
+            schedule.balance = 100;
+            schedule.ext_code = 100;
+            schedule.ext_code_hash = 100;
+            schedule.sload = 100;
+            schedule.call = 100;
+            schedule.sstore = SstoreRules::Eip2929;
+            schedule.access_lists = true;
+        }
+
+        if E::includes::<Eip3529>() {
+            schedule.sstore = SstoreRules::Eip3529;
+        }
+
+        schedule
+    }
+
+    /// Returns the static (base) cost of executing a mnemonic.
+    ///
+    /// The static cost does not include any costs that depend on the instruction's operands or the
+    /// state of the execution (memory expansion, copied words, cold accesses, etc.). Instructions
+    /// whose cost is entirely dynamic (like `SSTORE`) have a static cost of zero. The cost is
+    /// returned regardless of whether the execution upgrade supports the instruction, and is
+    /// [`None`] for mnemonics this schedule does not price, like EOF instructions.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::gas::GasSchedule;
+    /// # use asm2::Mnemonic;
+    /// let schedule = GasSchedule::FRONTIER;
+    /// assert_eq!(schedule.static_cost(Mnemonic::ADD), Some(3));
+    /// assert_eq!(schedule.static_cost(Mnemonic::SLOAD), Some(50));
+    /// assert_eq!(schedule.static_cost(Mnemonic::LOG2), Some(1125));
+    /// ```
+    #[must_use]
+    #[expect(clippy::too_many_lines, reason = "one arm per priced mnemonic")]
+    pub const fn static_cost(&self, mnemonic: Mnemonic) -> Option<u64> {
+        /// Cost of an instruction that does nothing.
+        const ZERO: u64 = 0;
+        /// Cost of `JUMPDEST`.
+        const JUMPDEST: u64 = 1;
+        /// Cost of instructions that read the execution environment.
+        const BASE: u64 = 2;
+        /// Cost of the cheapest stack and memory operations.
+        const VERY_LOW: u64 = 3;
+        /// Cost of cheap arithmetic operations.
+        const LOW: u64 = 5;
+        /// Cost of moderately expensive operations.
+        const MID: u64 = 8;
+        /// Cost of expensive operations.
+        const HIGH: u64 = 10;
+        /// Cost of accessing transient storage.
+        const WARM_STORAGE_READ: u64 = 100;
+        /// Cost of a `LOG` instruction.
+        const LOG: u64 = 375;
+        /// Cost of every `LOG` topic.
+        const LOG_TOPIC: u64 = 375;
+        /// Cost of creating a contract.
+        const CREATE: u64 = 32000;
+
+        let cost = match mnemonic {
+            Mnemonic::STOP
+            | Mnemonic::RETURN
+            | Mnemonic::REVERT
+            | Mnemonic::INVALID
+            | Mnemonic::SSTORE => ZERO,
+            Mnemonic::JUMPDEST => JUMPDEST,
+            Mnemonic::ADDRESS
+            | Mnemonic::ORIGIN
+            | Mnemonic::CALLER
+            | Mnemonic::CALLVALUE
+            | Mnemonic::CALLDATASIZE
+            | Mnemonic::CODESIZE
+            | Mnemonic::GASPRICE
+            | Mnemonic::RETURNDATASIZE
+            | Mnemonic::COINBASE
+            | Mnemonic::TIMESTAMP
+            | Mnemonic::NUMBER
+            | Mnemonic::PREVRANDAO
+            | Mnemonic::GASLIMIT
+            | Mnemonic::CHAINID
+            | Mnemonic::BASEFEE
+            | Mnemonic::BLOBBASEFEE
+            | Mnemonic::POP
+            | Mnemonic::PC
+            | Mnemonic::MSIZE
+            | Mnemonic::GAS
+            | Mnemonic::PUSH0 => BASE,
+            Mnemonic::MUL
+            | Mnemonic::DIV
+            | Mnemonic::SDIV
+            | Mnemonic::MOD
+            | Mnemonic::SMOD
+            | Mnemonic::SIGNEXTEND
+            | Mnemonic::SELFBALANCE => LOW,
+            Mnemonic::ADDMOD | Mnemonic::MULMOD | Mnemonic::JUMP => MID,
+            Mnemonic::EXP | Mnemonic::JUMPI => HIGH,
+            Mnemonic::KECCAK256 => 30,
+            Mnemonic::BLOCKHASH => 20,
+            Mnemonic::BALANCE => self.balance,
+            Mnemonic::EXTCODESIZE | Mnemonic::EXTCODECOPY => self.ext_code,
+            Mnemonic::EXTCODEHASH => self.ext_code_hash,
+            Mnemonic::SLOAD => self.sload,
+            Mnemonic::TLOAD | Mnemonic::TSTORE => WARM_STORAGE_READ,
+            Mnemonic::LOG0 => LOG,
+            Mnemonic::LOG1 => LOG + LOG_TOPIC,
+            Mnemonic::LOG2 => LOG + 2 * LOG_TOPIC,
+            Mnemonic::LOG3 => LOG + 3 * LOG_TOPIC,
+            Mnemonic::LOG4 => LOG + 4 * LOG_TOPIC,
+            Mnemonic::CREATE | Mnemonic::CREATE2 => CREATE,
+            Mnemonic::CALL | Mnemonic::CALLCODE | Mnemonic::DELEGATECALL | Mnemonic::STATICCALL => {
+                self.call
+            }
+            Mnemonic::SELFDESTRUCT => self.self_destruct,
+            Mnemonic::ADD
+            | Mnemonic::SUB
+            | Mnemonic::LT
+            | Mnemonic::GT
+            | Mnemonic::SLT
+            | Mnemonic::SGT
+            | Mnemonic::EQ
+            | Mnemonic::ISZERO
+            | Mnemonic::AND
+            | Mnemonic::OR
+            | Mnemonic::XOR
+            | Mnemonic::NOT
+            | Mnemonic::BYTE
+            | Mnemonic::SHL
+            | Mnemonic::SHR
+            | Mnemonic::SAR
+            | Mnemonic::CALLDATALOAD
+            | Mnemonic::CALLDATACOPY
+            | Mnemonic::CODECOPY
+            | Mnemonic::RETURNDATACOPY
+            | Mnemonic::BLOBHASH
+            | Mnemonic::MLOAD
+            | Mnemonic::MSTORE
+            | Mnemonic::MSTORE8
+            | Mnemonic::MCOPY
+            | Mnemonic::PUSH1
+            | Mnemonic::PUSH2
+            | Mnemonic::PUSH3
+            | Mnemonic::PUSH4
+            | Mnemonic::PUSH5
+            | Mnemonic::PUSH6
+            | Mnemonic::PUSH7
+            | Mnemonic::PUSH8
+            | Mnemonic::PUSH9
+            | Mnemonic::PUSH10
+            | Mnemonic::PUSH11
+            | Mnemonic::PUSH12
+            | Mnemonic::PUSH13
+            | Mnemonic::PUSH14
+            | Mnemonic::PUSH15
+            | Mnemonic::PUSH16
+            | Mnemonic::PUSH17
+            | Mnemonic::PUSH18
+            | Mnemonic::PUSH19
+            | Mnemonic::PUSH20
+            | Mnemonic::PUSH21
+            | Mnemonic::PUSH22
+            | Mnemonic::PUSH23
+            | Mnemonic::PUSH24
+            | Mnemonic::PUSH25
+            | Mnemonic::PUSH26
+            | Mnemonic::PUSH27
+            | Mnemonic::PUSH28
+            | Mnemonic::PUSH29
+            | Mnemonic::PUSH30
+            | Mnemonic::PUSH31
+            | Mnemonic::PUSH32
+            | Mnemonic::DUP1
+            | Mnemonic::DUP2
+            | Mnemonic::DUP3
+            | Mnemonic::DUP4
+            | Mnemonic::DUP5
+            | Mnemonic::DUP6
+            | Mnemonic::DUP7
+            | Mnemonic::DUP8
+            | Mnemonic::DUP9
+            | Mnemonic::DUP10
+            | Mnemonic::DUP11
+            | Mnemonic::DUP12
+            | Mnemonic::DUP13
+            | Mnemonic::DUP14
+            | Mnemonic::DUP15
+            | Mnemonic::DUP16
+            | Mnemonic::SWAP1
+            | Mnemonic::SWAP2
+            | Mnemonic::SWAP3
+            | Mnemonic::SWAP4
+            | Mnemonic::SWAP5
+            | Mnemonic::SWAP6
+            | Mnemonic::SWAP7
+            | Mnemonic::SWAP8
+            | Mnemonic::SWAP9
+            | Mnemonic::SWAP10
+            | Mnemonic::SWAP11
+            | Mnemonic::SWAP12
+            | Mnemonic::SWAP13
+            | Mnemonic::SWAP14
+            | Mnemonic::SWAP15
+            | Mnemonic::SWAP16 => VERY_LOW,
+            // EOF instructions have no legacy gas schedule.
+            _ => return None,
+        };
+
+        Some(cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forks::{
+        berlin::Berlin, byzantium::Byzantium, cancun::Cancun, constantinople::Constantinople,
+        frontier::Frontier, homestead::Homestead, istanbul::Istanbul, london::London,
+        spurious_dragon::SpuriousDragon, tangerine_whistle::TangerineWhistle,
+    };
+
+    fn cost<E: ExecutionUpgrade>(mnemonic: Mnemonic) -> u64 {
+        GasSchedule::new::<E>().static_cost(mnemonic).unwrap()
+    }
+
+    #[test]
+    fn sload_cost() {
+        assert_eq!(cost::<Frontier>(Mnemonic::SLOAD), 50);
+        assert_eq!(cost::<Homestead>(Mnemonic::SLOAD), 50);
+        assert_eq!(cost::<TangerineWhistle>(Mnemonic::SLOAD), 200);
+        assert_eq!(cost::<Constantinople>(Mnemonic::SLOAD), 200);
+        assert_eq!(cost::<Istanbul>(Mnemonic::SLOAD), 800);
+        assert_eq!(cost::<Berlin>(Mnemonic::SLOAD), 100);
+        assert_eq!(cost::<Cancun>(Mnemonic::SLOAD), 100);
+    }
+
+    #[test]
+    fn account_access_cost() {
+        assert_eq!(cost::<Frontier>(Mnemonic::BALANCE), 20);
+        assert_eq!(cost::<TangerineWhistle>(Mnemonic::BALANCE), 400);
+        assert_eq!(cost::<Istanbul>(Mnemonic::BALANCE), 700);
+        assert_eq!(cost::<Berlin>(Mnemonic::BALANCE), 100);
+
+        assert_eq!(cost::<Frontier>(Mnemonic::EXTCODESIZE), 20);
+        assert_eq!(cost::<TangerineWhistle>(Mnemonic::EXTCODECOPY), 700);
+        assert_eq!(cost::<Berlin>(Mnemonic::EXTCODESIZE), 100);
+
+        assert_eq!(cost::<Constantinople>(Mnemonic::EXTCODEHASH), 400);
+        assert_eq!(cost::<Istanbul>(Mnemonic::EXTCODEHASH), 700);
+        assert_eq!(cost::<London>(Mnemonic::EXTCODEHASH), 100);
+    }
+
+    #[test]
+    fn call_cost() {
+        assert_eq!(cost::<Frontier>(Mnemonic::CALL), 40);
+        assert_eq!(cost::<Homestead>(Mnemonic::DELEGATECALL), 40);
+        assert_eq!(cost::<TangerineWhistle>(Mnemonic::CALLCODE), 700);
+        assert_eq!(cost::<Byzantium>(Mnemonic::STATICCALL), 700);
+        assert_eq!(cost::<Berlin>(Mnemonic::CALL), 100);
+    }
+
+    #[test]
+    fn self_destruct_and_exp_cost() {
+        assert_eq!(cost::<Frontier>(Mnemonic::SELFDESTRUCT), 0);
+        assert_eq!(cost::<TangerineWhistle>(Mnemonic::SELFDESTRUCT), 5000);
+        assert_eq!(cost::<Cancun>(Mnemonic::SELFDESTRUCT), 5000);
+
+        assert_eq!(cost::<Frontier>(Mnemonic::EXP), 10);
+        assert_eq!(GasSchedule::new::<TangerineWhistle>().exp_byte, 10);
+        assert_eq!(GasSchedule::new::<SpuriousDragon>().exp_byte, 50);
+    }
+
+    #[test]
+    fn constant_costs() {
+        assert_eq!(cost::<Cancun>(Mnemonic::STOP), 0);
+        assert_eq!(cost::<Cancun>(Mnemonic::JUMPDEST), 1);
+        assert_eq!(cost::<Cancun>(Mnemonic::PUSH0), 2);
+        assert_eq!(cost::<Cancun>(Mnemonic::PUSH32), 3);
+        assert_eq!(cost::<Cancun>(Mnemonic::DUP16), 3);
+        assert_eq!(cost::<Cancun>(Mnemonic::MSTORE), 3);
+        assert_eq!(cost::<Cancun>(Mnemonic::SELFBALANCE), 5);
+        assert_eq!(cost::<Cancun>(Mnemonic::MULMOD), 8);
+        assert_eq!(cost::<Cancun>(Mnemonic::JUMPI), 10);
+        assert_eq!(cost::<Cancun>(Mnemonic::KECCAK256), 30);
+        assert_eq!(cost::<Cancun>(Mnemonic::TSTORE), 100);
+        assert_eq!(cost::<Cancun>(Mnemonic::LOG0), 375);
+        assert_eq!(cost::<Cancun>(Mnemonic::LOG4), 1875);
+        assert_eq!(cost::<Cancun>(Mnemonic::CREATE2), 32000);
+    }
+
+    #[test]
+    fn legacy_mnemonics_are_priced() {
+        let schedule = GasSchedule::new::<Cancun>();
+
+        for &mnemonic in Mnemonic::VARIANTS {
+            assert_eq!(
+                schedule.static_cost(mnemonic).is_some(),
+                !mnemonic.is_eof(),
+                "{mnemonic}"
+            );
+        }
+    }
+}
@@ -0,0 +1,323 @@
+//! `SSTORE` costs and refunds.
+
+use crate::gas::{
+    GasSchedule,
+    access::{Access, COLD_SLOAD, WARM_STORAGE_READ},
+};
+
+/// Cost of setting a zero storage slot to a non-zero value.
+pub const SSTORE_SET: u64 = 20000;
+
+/// Cost of changing a non-zero storage slot before EIP-2929.
+pub const SSTORE_RESET: u64 = 5000;
+
+/// Refund for clearing a storage slot before EIP-3529.
+pub const SSTORE_CLEARS_SCHEDULE: u64 = 15000;
+
+/// Minimum amount of gas that has to be left to execute `SSTORE` since EIP-2200.
+pub const SSTORE_SENTRY: u64 = 2300;
+
+/// Rules used to price `SSTORE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SstoreRules {
+    /// Only the current and the new value are considered.
+    Legacy,
+    /// EIP-1283: Net gas metering for SSTORE without dirty maps.
+    Eip1283,
+    /// EIP-2200: Structured Definitions for Net Gas Metering.
+    Eip2200,
+    /// EIP-2929: Gas cost increases for state access opcodes.
+    Eip2929,
+    /// EIP-3529: Reduction in refunds.
+    Eip3529,
+}
+
+impl SstoreRules {
+    /// Returns whether `SSTORE` fails if no more than [`SSTORE_SENTRY`] gas is left.
+    #[must_use]
+    pub const fn has_sentry(&self) -> bool {
+        matches!(self, Self::Eip2200 | Self::Eip2929 | Self::Eip3529)
+    }
+
+    /// Returns the no-op/dirty cost, the reset cost and the clearing refund of net gas metering,
+    /// or [`None`] for the legacy rules.
+    const fn net_metering(self) -> Option<(u64, u64, u64)> {
+        match self {
+            Self::Legacy => None,
+            Self::Eip1283 => Some((200, SSTORE_RESET, SSTORE_CLEARS_SCHEDULE)),
+            Self::Eip2200 => Some((800, SSTORE_RESET, SSTORE_CLEARS_SCHEDULE)),
+            Self::Eip2929 => Some((
+                WARM_STORAGE_READ,
+                SSTORE_RESET - COLD_SLOAD,
+                SSTORE_CLEARS_SCHEDULE,
+            )),
+            Self::Eip3529 => Some((
+                WARM_STORAGE_READ,
+                SSTORE_RESET - COLD_SLOAD,
+                // `SSTORE_RESET_GAS + ACCESS_LIST_STORAGE_KEY_COST`
+                SSTORE_RESET - COLD_SLOAD + 1900,
+            )),
+        }
+    }
+}
+
+/// Cost and refund of a single `SSTORE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SstoreCost {
+    /// Gas charged for the `SSTORE`.
+    pub gas: u64,
+    /// Change of the refund counter, which can be negative when a previously granted refund no
+    /// longer applies.
+    pub refund: i64,
+}
+
+impl GasSchedule {
+    /// Returns the cost and refund of storing `new` in a storage slot, whose value was `original`
+    /// at the start of the transaction and is `current` right before the `SSTORE`.
+    ///
+    /// Values are compared against their [`Default`], which has to be the zero value. The
+    /// original value and the access are ignored by rules that do not take them into account.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::gas::{Access, GasSchedule, SstoreCost};
+    /// # use oculars_upgrades::forks::{byzantium::Byzantium, london::London};
+    /// let byzantium = GasSchedule::new::<Byzantium>();
+    /// assert_eq!(
+    ///     byzantium.sstore_cost(&0, &0, &1, Access::Warm),
+    ///     SstoreCost { gas: 20000, refund: 0 }
+    /// );
+    ///
+    /// let london = GasSchedule::new::<London>();
+    /// assert_eq!(
+    ///     london.sstore_cost(&1, &1, &0, Access::Cold),
+    ///     SstoreCost { gas: 5000, refund: 4800 }
+    /// );
+    /// ```
+    #[must_use]
+    pub fn sstore_cost<T: Eq + Default>(
+        &self,
+        original: &T,
+        current: &T,
+        new: &T,
+        access: Access,
+    ) -> SstoreCost {
+        let zero = T::default();
+
+        let Some((dirty, reset, clear_refund)) = self.sstore.net_metering() else {
+            return if *current == zero && *new != zero {
+                SstoreCost {
+                    gas: SSTORE_SET,
+                    refund: 0,
+                }
+            } else if *current != zero && *new == zero {
+                SstoreCost {
+                    gas: SSTORE_RESET,
+                    refund: SSTORE_CLEARS_SCHEDULE.cast_signed(),
+                }
+            } else {
+                SstoreCost {
+                    gas: SSTORE_RESET,
+                    refund: 0,
+                }
+            };
+        };
+
+        let mut cost = SstoreCost {
+            gas: if self.access_lists && access == Access::Cold {
+                COLD_SLOAD
+            } else {
+                0
+            },
+            refund: 0,
+        };
+        let clear_refund = clear_refund.cast_signed();
+
+        if current == new {
+            cost.gas += dirty;
+        } else if original == current {
+            if *original == zero {
+                cost.gas += SSTORE_SET;
+            } else {
+                cost.gas += reset;
+
+                if *new == zero {
+                    cost.refund += clear_refund;
+                }
+            }
+        } else {
+            cost.gas += dirty;
+
+            if *original != zero {
+                if *current == zero {
+                    cost.refund -= clear_refund;
+                } else if *new == zero {
+                    cost.refund += clear_refund;
+                }
+            }
+
+            if original == new {
+                let restored = if *original == zero { SSTORE_SET } else { reset };
+                cost.refund += (restored - dirty).cast_signed();
+            }
+        }
+
+        cost
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        execution::ExecutionUpgrade,
+        forks::{
+            berlin::Berlin, constantinople::Constantinople, istanbul::Istanbul, london::London,
+            petersburg::Petersburg,
+        },
+    };
+
+    /// Executes `PUSH1 value PUSH1 0 SSTORE` for every value on a warm slot and returns the used
+    /// gas and refund, like the test cases of the EIPs.
+    fn execute<E: ExecutionUpgrade>(original: u8, values: &[u8]) -> (u64, i64) {
+        let schedule = GasSchedule::new::<E>();
+        let mut current = original;
+        let (mut gas, mut refund) = (0, 0);
+
+        for value in values {
+            let cost = schedule.sstore_cost(&original, &current, value, Access::Warm);
+            gas += 2 * 3 + cost.gas;
+            refund += cost.refund;
+            current = *value;
+        }
+
+        (gas, refund)
+    }
+
+    #[test]
+    fn rule_selection() {
+        assert_eq!(GasSchedule::new::<Petersburg>().sstore, SstoreRules::Legacy);
+        assert_eq!(
+            GasSchedule::new::<Constantinople>().sstore,
+            SstoreRules::Eip1283
+        );
+        assert_eq!(GasSchedule::new::<Istanbul>().sstore, SstoreRules::Eip2200);
+        assert_eq!(GasSchedule::new::<Berlin>().sstore, SstoreRules::Eip2929);
+        assert_eq!(GasSchedule::new::<London>().sstore, SstoreRules::Eip3529);
+
+        assert!(!SstoreRules::Eip1283.has_sentry());
+        assert!(SstoreRules::Eip2200.has_sentry());
+    }
+
+    #[test]
+    fn legacy() {
+        assert_eq!(execute::<Petersburg>(0, &[0, 0]), (10012, 0));
+        assert_eq!(execute::<Petersburg>(0, &[1, 0]), (25012, 15000));
+        assert_eq!(execute::<Petersburg>(1, &[0, 1]), (25012, 15000));
+        assert_eq!(execute::<Petersburg>(1, &[2, 3]), (10012, 0));
+    }
+
+    #[test]
+    fn eip1283() {
+        let cases: &[(u8, &[u8], u64, i64)] = &[
+            (0, &[0, 0], 412, 0),
+            (0, &[0, 1], 20212, 0),
+            (0, &[1, 0], 20212, 19800),
+            (0, &[1, 2], 20212, 0),
+            (0, &[1, 1], 20212, 0),
+            (1, &[0, 0], 5212, 15000),
+            (1, &[0, 1], 5212, 4800),
+            (1, &[0, 2], 5212, 0),
+            (1, &[2, 0], 5212, 15000),
+            (1, &[2, 3], 5212, 0),
+            (1, &[2, 1], 5212, 4800),
+            (1, &[2, 2], 5212, 0),
+            (1, &[1, 0], 5212, 15000),
+            (1, &[1, 2], 5212, 0),
+            (1, &[1, 1], 412, 0),
+            (0, &[1, 0, 1], 40218, 19800),
+            (1, &[0, 1, 0], 10218, 19800),
+        ];
+
+        for (original, values, gas, refund) in cases {
+            assert_eq!(
+                execute::<Constantinople>(*original, values),
+                (*gas, *refund),
+                "original {original}, values {values:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn eip2200() {
+        let cases: &[(u8, &[u8], u64, i64)] = &[
+            (0, &[0, 0], 1612, 0),
+            (0, &[0, 1], 20812, 0),
+            (0, &[1, 0], 20812, 19200),
+            (0, &[1, 2], 20812, 0),
+            (0, &[1, 1], 20812, 0),
+            (1, &[0, 0], 5812, 15000),
+            (1, &[0, 1], 5812, 4200),
+            (1, &[0, 2], 5812, 0),
+            (1, &[2, 0], 5812, 15000),
+            (1, &[2, 3], 5812, 0),
+            (1, &[2, 1], 5812, 4200),
+            (1, &[2, 2], 5812, 0),
+            (1, &[1, 0], 5812, 15000),
+            (1, &[1, 2], 5812, 0),
+            (1, &[1, 1], 1612, 0),
+            (0, &[1, 0, 1], 40818, 19200),
+            (1, &[0, 1, 0], 10818, 19200),
+        ];
+
+        for (original, values, gas, refund) in cases {
+            assert_eq!(
+                execute::<Istanbul>(*original, values),
+                (*gas, *refund),
+                "original {original}, values {values:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn eip3529() {
+        let cases: &[(u8, &[u8], u64, i64)] = &[
+            (0, &[0, 0], 212, 0),
+            (0, &[0, 1], 20112, 0),
+            (0, &[1, 0], 20112, 19900),
+            (0, &[1, 2], 20112, 0),
+            (0, &[1, 1], 20112, 0),
+            (1, &[0, 0], 3012, 4800),
+            (1, &[0, 1], 3012, 2800),
+            (1, &[0, 2], 3012, 0),
+            (1, &[2, 0], 3012, 4800),
+            (1, &[2, 3], 3012, 0),
+            (1, &[2, 1], 3012, 2800),
+            (1, &[2, 2], 3012, 0),
+            (1, &[1, 0], 3012, 4800),
+            (1, &[1, 2], 3012, 0),
+            (1, &[1, 1], 212, 0),
+            (0, &[1, 0, 1], 40118, 19900),
+            (1, &[0, 1, 0], 5918, 7600),
+        ];
+
+        for (original, values, gas, refund) in cases {
+            assert_eq!(
+                execute::<London>(*original, values),
+                (*gas, *refund),
+                "original {original}, values {values:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn cold_access() {
+        let berlin = GasSchedule::new::<Berlin>();
+        assert_eq!(berlin.sstore_cost(&0, &0, &0, Access::Cold).gas, 2200);
+        assert_eq!(berlin.sstore_cost(&1, &1, &2, Access::Cold).gas, 5000);
+        assert_eq!(berlin.sstore_cost(&1, &1, &0, Access::Cold).refund, 15000);
+
+        let istanbul = GasSchedule::new::<Istanbul>();
+        assert_eq!(istanbul.sstore_cost(&1, &1, &2, Access::Cold).gas, 5000);
+    }
+}
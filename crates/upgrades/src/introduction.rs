@@ -0,0 +1,118 @@
+//! Where instructions were introduced.
+
+use asm2::Mnemonic;
+use core::fmt::Display;
+
+use crate::{eip::Eip, eips::genesis::Genesis, forks::Fork};
+
+/// The mainnet fork and EIP that introduced an instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Introduction {
+    /// The first mainnet fork that supported the instruction.
+    pub fork: Fork,
+    /// The EIP that introduced the instruction, [`None`] if it has been available since genesis.
+    pub eip: Option<u32>,
+}
+
+impl Introduction {
+    /// Returns where the instruction with a specific mnemonic was introduced, [`None`] if no
+    /// mainnet fork supports it.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::{introduction::Introduction, forks::Fork};
+    /// # use asm2::Mnemonic;
+    /// let shl = Introduction::of(Mnemonic::SHL).unwrap();
+    /// assert_eq!(shl.fork, Fork::Constantinople);
+    /// assert_eq!(shl.eip, Some(145));
+    /// assert_eq!(shl.to_string(), "Constantinople (EIP-145)");
+    ///
+    /// assert_eq!(Introduction::of(Mnemonic::ADD).unwrap().to_string(), "Frontier");
+    /// ```
+    #[must_use]
+    pub fn of(mnemonic: Mnemonic) -> Option<Self> {
+        let fork = Fork::ALL
+            .iter()
+            .copied()
+            .find(|fork| fork.supports_mnemonic(mnemonic))?;
+
+        Some(Self {
+            fork,
+            eip: fork
+                .introducing_eip(mnemonic)
+                .filter(|&number| number != Genesis::NUMBER),
+        })
+    }
+
+    /// Returns where each instruction was introduced, in opcode order.
+    ///
+    /// Instructions that are not supported by any mainnet fork are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::{introduction::Introduction, forks::Fork};
+    /// # use asm2::Mnemonic;
+    /// let (mnemonic, introduction) = Introduction::table()
+    ///     .find(|(_, introduction)| introduction.fork == Fork::Shanghai)
+    ///     .unwrap();
+    /// assert_eq!(mnemonic, Mnemonic::PUSH0);
+    /// assert_eq!(introduction.eip, Some(3855));
+    /// ```
+    pub fn table() -> impl Iterator<Item = (Mnemonic, Self)> {
+        Mnemonic::VARIANTS
+            .iter()
+            .filter_map(|&mnemonic| Some((mnemonic, Self::of(mnemonic)?)))
+    }
+}
+
+impl Display for Introduction {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.eip {
+            Some(eip) => write!(f, "{} (EIP-{eip})", self.fork),
+            None => write!(f, "{}", self.fork),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn introductions() {
+        let introduction = |mnemonic| Introduction::of(mnemonic).unwrap();
+
+        assert_eq!(
+            introduction(Mnemonic::DELEGATECALL),
+            Introduction {
+                fork: Fork::Homestead,
+                eip: Some(7)
+            }
+        );
+        assert_eq!(introduction(Mnemonic::SELFBALANCE).eip, Some(1884));
+        assert_eq!(introduction(Mnemonic::BASEFEE).fork, Fork::London);
+        assert_eq!(introduction(Mnemonic::BLOBHASH).fork, Fork::Cancun);
+        assert_eq!(introduction(Mnemonic::STOP).eip, None);
+    }
+
+    #[test]
+    fn table_covers_every_supported_mnemonic() {
+        for (mnemonic, introduction) in Introduction::table() {
+            assert!(introduction.fork.supports_mnemonic(mnemonic));
+            assert!(
+                Fork::ALL
+                    .iter()
+                    .filter(|fork| **fork < introduction.fork)
+                    .all(|fork| !fork.supports_mnemonic(mnemonic))
+            );
+        }
+
+        assert_eq!(
+            Introduction::table().count(),
+            Mnemonic::VARIANTS
+                .iter()
+                .filter(|mnemonic| Fork::Prague.supports_mnemonic(**mnemonic))
+                .count()
+        );
+    }
+}
@@ -1,4 +1,4 @@
-#![feature(min_specialization)]
+#![no_std]
 
 //! Ethereum EIPs and network upgrades.
 
@@ -6,4 +6,6 @@ pub mod eip;
 pub mod eips;
 pub mod execution;
 pub mod forks;
+pub mod gas;
+pub mod introduction;
 pub mod network;
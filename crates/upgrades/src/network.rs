@@ -4,6 +4,11 @@ use chains::Chain;
 
 /// Ethereum network upgrade (hard fork).
 pub trait NetworkUpgrade {
+    /// Blocks at which this upgrade gets activated, by chain id.
+    ///
+    /// Unless listed, a network upgrade gets activated on a chain at block 0.
+    const ACTIVATIONS: &'static [(u64, u64)] = &[];
+
     /// Block at which this upgrade gets activated.
     ///
     /// # Example
@@ -15,24 +20,10 @@ pub trait NetworkUpgrade {
     /// ```
     #[must_use]
     #[inline]
-    fn activation_block<C: Chain>() -> u64
-    where
-        Self: UpgradeActivation<C>,
-    {
-        Self::block()
-    }
-}
-
-/// Identifies at what time an upgrade was activated.
-pub trait UpgradeActivation<C: Chain> {
-    /// Returns the activation block.
-    #[must_use]
-    fn block() -> u64;
-}
-
-// Unless explicitly specified, a network upgrade gets actived on a chain at block 0.
-impl<C: Chain, U: NetworkUpgrade> UpgradeActivation<C> for U {
-    default fn block() -> u64 {
-        0
+    fn activation_block<C: Chain>() -> u64 {
+        Self::ACTIVATIONS
+            .iter()
+            .find(|(chain_id, _)| *chain_id == C::CHAIN_ID)
+            .map_or(0, |(_, block)| *block)
     }
 }